@@ -0,0 +1,54 @@
+use num;
+use channel::FreeChannelScalar;
+use linalg::Matrix3;
+use lms::Cat16;
+use cam::{AppearanceModel, CamModel};
+
+/// The CAM16 color appearance model (Li et al. 2017).
+pub type Cam16<T> = AppearanceModel<T, Cat16>;
+
+impl<T> CamModel<T> for Cat16
+    where T: FreeChannelScalar
+{
+    /// CAM16 compresses the adapted CAT16 responses directly.
+    fn compression_transform() -> Matrix3<T> {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        Matrix3::new([c(1.0), c(0.0), c(0.0), c(0.0), c(1.0), c(0.0), c(0.0), c(0.0), c(1.0)])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use angle::Deg;
+    use cam::{Surround, ViewingConditions};
+    use xyz::Xyz;
+
+    #[test]
+    fn test_appearance() {
+        let conditions = ViewingConditions::new(Xyz::from_channels(0.9505, 1.0, 1.0888),
+                                                318.31,
+                                                20.0,
+                                                Surround::Average);
+        let model = Cam16::new(conditions);
+
+        let c1 = Xyz::from_channels(0.1901, 0.2, 0.2178);
+        let t1 = model.appearance(&c1);
+        assert_relative_eq!(t1.lightness, 41.731208, epsilon=1e-6);
+        assert_relative_eq!(t1.chroma, 0.103356, epsilon=1e-6);
+        assert_relative_eq!(t1.hue, Deg(217.067960), epsilon=1e-6);
+        assert_relative_eq!(t1.brightness, 195.371709, epsilon=1e-6);
+        assert_relative_eq!(t1.colorfulness, 0.107437, epsilon=1e-6);
+        assert_relative_eq!(t1.saturation, 2.345015, epsilon=1e-6);
+        assert_relative_eq!(t1.hue_quadrature, 275.594986, epsilon=1e-6);
+        assert_relative_eq!(model.to_xyz(&t1), c1, epsilon=1e-9);
+
+        let c2 = Xyz::from_channels(0.5706, 0.4306, 0.3196);
+        let t2 = model.appearance(&c2);
+        assert_relative_eq!(t2.lightness, 65.680378, epsilon=1e-6);
+        assert_relative_eq!(t2.chroma, 50.411852, epsilon=1e-6);
+        assert_relative_eq!(t2.hue, Deg(17.645945), epsilon=1e-6);
+        assert_relative_eq!(t2.hue_quadrature, 397.399444, epsilon=1e-6);
+        assert_relative_eq!(model.to_xyz(&t2), c2, epsilon=1e-9);
+    }
+}
@@ -0,0 +1,114 @@
+use num;
+use channel::FreeChannelScalar;
+use linalg::Matrix3;
+use lms::{LmsModel, CieCam2002};
+use cam::{AppearanceModel, CamModel};
+
+/// The CIECAM02 color appearance model (CIE 159:2004).
+pub type CieCam02<T> = AppearanceModel<T, CieCam2002>;
+
+impl<T> CamModel<T> for CieCam2002
+    where T: FreeChannelScalar
+{
+    /// CIECAM02 compresses the responses of the Hunt-Pointer-Estevez cone space.
+    fn compression_transform() -> Matrix3<T> {
+        let hunt_pointer_estevez = Matrix3::new([num::cast(0.38971).unwrap(),
+                                                 num::cast(0.68898).unwrap(),
+                                                 num::cast(-0.07868).unwrap(),
+                                                 num::cast(-0.22981).unwrap(),
+                                                 num::cast(1.18340).unwrap(),
+                                                 num::cast(0.04641).unwrap(),
+                                                 num::cast(0.0).unwrap(),
+                                                 num::cast(0.0).unwrap(),
+                                                 num::cast(1.0).unwrap()]);
+        let cat02_inverse = Self::forward_transform().inverse().unwrap();
+        hunt_pointer_estevez * cat02_inverse
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use angle::Deg;
+    use cam::{Surround, ViewingConditions};
+    use white_point::{NamedWhitePoint, D65};
+    use xyz::Xyz;
+
+    #[test]
+    fn test_cie_159_example() {
+        let white = Xyz::from_channels(0.9888, 0.90, 0.3203);
+        let model = CieCam02::new(ViewingConditions::new(white, 200.0, 18.0, Surround::Average));
+        let color = Xyz::from_channels(0.1931, 0.2393, 0.1014);
+        let appearance = model.appearance(&color);
+
+        assert_relative_eq!(model.luminance_adaptation(), 1.0, epsilon=1e-4);
+        assert_relative_eq!(appearance.lightness, 48.0314, epsilon=1e-4);
+        assert_relative_eq!(appearance.chroma, 38.7789, epsilon=1e-4);
+        assert_relative_eq!(appearance.hue, Deg(191.0452), epsilon=1e-4);
+        assert_relative_eq!(appearance.brightness, 183.1240, epsilon=1e-4);
+        assert_relative_eq!(appearance.colorfulness, 38.7789, epsilon=1e-4);
+        assert_relative_eq!(appearance.saturation, 46.0177, epsilon=1e-4);
+        assert_relative_eq!(appearance.hue_quadrature, 240.8884, epsilon=1e-4);
+        assert_relative_eq!(model.to_xyz(&appearance), color, epsilon=1e-9);
+    }
+
+    #[test]
+    fn test_appearance() {
+        let conditions = ViewingConditions::new(Xyz::from_channels(0.9505, 1.0, 1.0888),
+                                                318.31,
+                                                20.0,
+                                                Surround::Average);
+        let model = CieCam02::new(conditions);
+
+        let c1 = Xyz::from_channels(0.1901, 0.2, 0.2178);
+        let t1 = model.appearance(&c1);
+        assert_relative_eq!(t1.lightness, 41.731091, epsilon=1e-6);
+        assert_relative_eq!(t1.chroma, 0.104708, epsilon=1e-6);
+        assert_relative_eq!(t1.hue, Deg(219.048433), epsilon=1e-6);
+        assert_relative_eq!(t1.brightness, 195.371326, epsilon=1e-6);
+        assert_relative_eq!(t1.colorfulness, 0.108842, epsilon=1e-6);
+        assert_relative_eq!(t1.saturation, 2.360305, epsilon=1e-6);
+        assert_relative_eq!(t1.hue_quadrature, 278.060736, epsilon=1e-6);
+        assert_relative_eq!(model.to_xyz(&t1), c1, epsilon=1e-9);
+
+        let c2 = Xyz::from_channels(0.5706, 0.4306, 0.3196);
+        let t2 = model.appearance(&c2);
+        assert_relative_eq!(t2.lightness, 66.249897, epsilon=1e-6);
+        assert_relative_eq!(t2.chroma, 49.263555, epsilon=1e-6);
+        assert_relative_eq!(t2.hue, Deg(19.799757), epsilon=1e-6);
+        assert_relative_eq!(t2.hue_quadrature, 399.642552, epsilon=1e-6);
+        assert_relative_eq!(model.to_xyz(&t2), c2, epsilon=1e-9);
+
+        let white = model.appearance(&Xyz::from_channels(0.9505, 1.0, 1.0888));
+        assert_relative_eq!(white.lightness, 100.0, epsilon=1e-9);
+        let black = model.appearance(&Xyz::from_channels(0.0, 0.0, 0.0));
+        assert_relative_eq!(black.lightness, 0.0, epsilon=1e-9);
+        assert_relative_eq!(black.chroma, 0.0, epsilon=1e-9);
+    }
+
+    #[test]
+    fn test_surround() {
+        let white = D65::get_xyz();
+        let color = Xyz::from_channels(0.3, 0.22, 0.5);
+        let appearances: Vec<_> = [Surround::Average, Surround::Dim, Surround::Dark]
+            .iter()
+            .map(|&surround| {
+                let conditions = ViewingConditions::new(white, 60.0, 20.0, surround);
+                CieCam02::new(conditions).appearance(&color)
+            })
+            .collect();
+
+        // A darker surround makes the same stimulus look lighter.
+        assert!(appearances[0].lightness < appearances[1].lightness);
+        assert!(appearances[1].lightness < appearances[2].lightness);
+
+        let conditions = ViewingConditions::new(white, 60.0, 20.0, Surround::Dim)
+            .with_discount_illuminant(true);
+        let model = CieCam02::new(conditions);
+        assert!(model.viewing_conditions().discount_illuminant());
+        let appearance = model.appearance(&color);
+        assert_relative_eq!(model.to_xyz(&appearance), color, epsilon=1e-12);
+        assert_relative_eq!(model.xyz_from_jch(0.0, 10.0, Deg(30.0)),
+                            Xyz::from_channels(0.0, 0.0, 0.0));
+    }
+}
@@ -0,0 +1,139 @@
+//! Color appearance models.
+//!
+//! A color appearance model predicts how a stimulus looks, not just whether two stimuli
+//! match. Given the `ViewingConditions` a color is seen under, an `AppearanceModel` computes
+//! the perceptual correlates of the color as a `ColorAppearance`: lightness, brightness,
+//! chroma, colorfulness, saturation and hue. The same `Xyz` stimulus gives different
+//! correlates under different conditions, and inverting the model under a second set of
+//! conditions finds the stimulus that looks the same there.
+//!
+//! The models share the structure of CIECAM02: a chromatic adaptation in a cone space
+//! selected by the `Model` parameter, a nonlinear response compression, and the opponent
+//! dimensions the correlates are computed from. CIECAM02 and CAM16 are provided, along
+//! with the `UniformSpace`s built on their lightness, colorfulness and hue.
+
+use num;
+use angle::Deg;
+use channel::FreeChannelScalar;
+use linalg::Matrix3;
+use lms::LmsModel;
+use xyz::Xyz;
+
+pub mod model;
+pub mod ciecam02;
+pub mod cam16;
+pub mod ucs;
+
+pub use self::model::AppearanceModel;
+pub use self::ciecam02::CieCam02;
+pub use self::cam16::Cam16;
+pub use self::ucs::{UniformSpace, UniformAppearance, Lcd, Scd, Ucs};
+
+/// The relative luminance of the area surrounding the viewing field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Surround {
+    /// Surface colors viewed in a lit room.
+    Average,
+    /// Television or a monitor in a dim room.
+    Dim,
+    /// A projector in a dark room.
+    Dark,
+}
+
+impl Surround {
+    /// The factor `F` for the degree of adaptation, the impact of the surround `c`, and the
+    /// chromatic induction factor `Nc`.
+    pub fn factors<T: FreeChannelScalar>(&self) -> (T, T, T) {
+        let (f, c, nc) = match *self {
+            Surround::Average => (1.0, 0.69, 1.0),
+            Surround::Dim => (0.9, 0.59, 0.9),
+            Surround::Dark => (0.8, 0.525, 0.8),
+        };
+        (num::cast(f).unwrap(), num::cast(c).unwrap(), num::cast(nc).unwrap())
+    }
+}
+
+/// The conditions a color is viewed under.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewingConditions<T> {
+    white_point: Xyz<T>,
+    adapting_luminance: T,
+    background_luminance: T,
+    surround: Surround,
+    discount_illuminant: bool,
+}
+
+impl<T> ViewingConditions<T>
+    where T: FreeChannelScalar
+{
+    /// Construct viewing conditions.
+    ///
+    /// Colors and `white_point` use a scale where Y = 1 is a perfect reflecting diffuser, so
+    /// a paper white may have a Y below 1. `adapting_luminance` is the luminance of the
+    /// adapting field `La` in cd/m², usually taken as 20% of the luminance of a white object.
+    /// `background_luminance` is the luminance factor `Yb` of the background in percent,
+    /// usually 20.
+    pub fn new(white_point: Xyz<T>,
+               adapting_luminance: T,
+               background_luminance: T,
+               surround: Surround)
+               -> Self {
+        ViewingConditions {
+            white_point,
+            adapting_luminance,
+            background_luminance,
+            surround,
+            discount_illuminant: false,
+        }
+    }
+
+    /// Whether the observer fully discounts the illuminant, as they do for surface colors
+    /// in a scene, instead of adapting to it partially.
+    pub fn with_discount_illuminant(mut self, discount: bool) -> Self {
+        self.discount_illuminant = discount;
+        self
+    }
+
+    pub fn white_point(&self) -> &Xyz<T> {
+        &self.white_point
+    }
+    pub fn adapting_luminance(&self) -> T {
+        self.adapting_luminance
+    }
+    pub fn background_luminance(&self) -> T {
+        self.background_luminance
+    }
+    pub fn surround(&self) -> Surround {
+        self.surround
+    }
+    pub fn discount_illuminant(&self) -> bool {
+        self.discount_illuminant
+    }
+}
+
+/// The perceptual correlates of a color under a set of viewing conditions.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ColorAppearance<T> {
+    /// Lightness `J`, with 100 for the white point.
+    pub lightness: T,
+    /// Chroma `C`.
+    pub chroma: T,
+    /// Hue angle `h`.
+    pub hue: Deg<T>,
+    /// Brightness `Q`.
+    pub brightness: T,
+    /// Colorfulness `M`.
+    pub colorfulness: T,
+    /// Saturation `s`.
+    pub saturation: T,
+    /// Hue quadrature `H`, with 0, 100, 200 and 300 for the unique red, yellow, green and
+    /// blue hues.
+    pub hue_quadrature: T,
+}
+
+/// A cone space a color appearance model adapts colors in.
+pub trait CamModel<T>: LmsModel<T> {
+    /// The transform from adapted cone responses into the space where the nonlinear
+    /// response compression is applied.
+    fn compression_transform() -> Matrix3<T>;
+}
@@ -0,0 +1,243 @@
+use std::marker::PhantomData;
+use num;
+use angle::{Angle, Deg, Rad, FromAngle};
+use channel::FreeChannelScalar;
+use linalg::Matrix3;
+use xyz::Xyz;
+use cam::{CamModel, ColorAppearance, ViewingConditions};
+
+/// A CIECAM02-style color appearance model evaluated under fixed viewing conditions.
+///
+/// Everything that depends only on the viewing conditions is computed when the model is
+/// constructed, so converting many colors under the same conditions is cheap.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AppearanceModel<T, Model> {
+    conditions: ViewingConditions<T>,
+    // From Xyz through the adapted cone responses to the space that is compressed, and back.
+    forward: Matrix3<T>,
+    inverse: Matrix3<T>,
+    luminance_adaptation: T,
+    background_ratio: T,
+    exponent: T,
+    induction: T,
+    surround_impact: T,
+    chromatic_induction: T,
+    achromatic_white: T,
+    model: PhantomData<Model>,
+}
+
+impl<T, Model> AppearanceModel<T, Model>
+    where T: FreeChannelScalar,
+          Model: CamModel<T>
+{
+    pub fn new(conditions: ViewingConditions<T>) -> Self {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        let one = T::one();
+        let factors: (T, T, T) = conditions.surround().factors();
+        let (f, surround_impact, chromatic_induction) = factors;
+
+        let white = Self::to_percent(conditions.white_point());
+
+        let la = conditions.adapting_luminance();
+        let k = one / (c(5.0) * la + one);
+        let k4 = k.powi(4);
+        let luminance_adaptation = c(0.2) * k4 * c(5.0) * la +
+                                   c(0.1) * (one - k4).powi(2) * (c(5.0) * la).cbrt();
+
+        let background_ratio = conditions.background_luminance() / white.1;
+        let exponent = c(1.48) + background_ratio.sqrt();
+        let induction = c(0.725) * (one / background_ratio).powf(c(0.2));
+
+        let degree = if conditions.discount_illuminant() {
+            one
+        } else {
+            let d = f * (one - (-(la + c(42.0)) / c(92.0)).exp() / c(3.6));
+            d.max(T::zero()).min(one)
+        };
+        let (rw, gw, bw) = Model::forward_transform().transform_vector(white);
+        let gain = |w: T| degree * white.1 / w + one - degree;
+        let zero = T::zero();
+        let gains = Matrix3::new([gain(rw), zero, zero,
+                                  zero, gain(gw), zero,
+                                  zero, zero, gain(bw)]);
+        let forward = Model::compression_transform() * gains * Model::forward_transform();
+        let inverse = Self::inverse(forward);
+
+        let mut model = AppearanceModel {
+            conditions,
+            forward,
+            inverse,
+            luminance_adaptation,
+            background_ratio,
+            exponent,
+            induction,
+            surround_impact,
+            chromatic_induction,
+            achromatic_white: T::zero(),
+            model: PhantomData,
+        };
+        let white_response = model.compressed_response(white);
+        model.achromatic_white = model.achromatic(white_response);
+        model
+    }
+
+    pub fn viewing_conditions(&self) -> &ViewingConditions<T> {
+        &self.conditions
+    }
+
+    /// The luminance level adaptation factor `FL`.
+    pub fn luminance_adaptation(&self) -> T {
+        self.luminance_adaptation
+    }
+
+    /// Compute the appearance correlates of `color`.
+    pub fn appearance(&self, color: &Xyz<T>) -> ColorAppearance<T> {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        let (ra, ga, ba) = self.compressed_response(Self::to_percent(color));
+
+        let a = ra - c(12.0) * ga / c(11.0) + ba / c(11.0);
+        let b = (ra + ga - c(2.0) * ba) / c(9.0);
+        let hue = Angle::normalize(Deg::from_angle(Rad::atan2(b, a)));
+
+        let lightness = c(100.0) *
+                        (self.achromatic((ra, ga, ba)) / self.achromatic_white)
+                            .powf(self.surround_impact * self.exponent);
+        let fl4 = self.luminance_adaptation.powf(c(0.25));
+        let brightness = c(4.0) / self.surround_impact * (lightness / c(100.0)).sqrt() *
+                         (self.achromatic_white + c(4.0)) * fl4;
+
+        let t = c(50000.0 / 13.0) * self.chromatic_induction * self.induction *
+                eccentricity(hue) * a.hypot(b) /
+                (ra + ga + c(21.0 / 20.0) * ba);
+        let chroma = t.powf(c(0.9)) * (lightness / c(100.0)).sqrt() * self.chroma_factor();
+        let colorfulness = chroma * fl4;
+        let saturation = if brightness > T::zero() {
+            c(100.0) * (colorfulness / brightness).sqrt()
+        } else {
+            T::zero()
+        };
+
+        ColorAppearance {
+            lightness,
+            chroma,
+            hue,
+            brightness,
+            colorfulness,
+            saturation,
+            hue_quadrature: hue_quadrature(hue),
+        }
+    }
+
+    /// Find the stimulus with the lightness, chroma and hue of `appearance`.
+    pub fn to_xyz(&self, appearance: &ColorAppearance<T>) -> Xyz<T> {
+        self.xyz_from_jch(appearance.lightness, appearance.chroma, appearance.hue)
+    }
+
+    /// Find the stimulus with lightness `J`, chroma `C` and hue angle `h`.
+    pub fn xyz_from_jch(&self, lightness: T, chroma: T, hue: Deg<T>) -> Xyz<T> {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        let (zero, one) = (T::zero(), T::one());
+        if lightness <= zero {
+            return Xyz::from_channels(zero, zero, zero);
+        }
+
+        let t = (chroma / ((lightness / c(100.0)).sqrt() * self.chroma_factor()))
+            .powf(one / c(0.9));
+        let achromatic = self.achromatic_white *
+                         (lightness / c(100.0))
+                             .powf(one / (self.surround_impact * self.exponent));
+        let p2 = achromatic / self.induction + c(0.305);
+        let p3 = c(21.0 / 20.0);
+
+        let (a, b) = if t == zero {
+            (zero, zero)
+        } else {
+            let p1 = c(50000.0 / 13.0) * self.chromatic_induction * self.induction *
+                     eccentricity(hue) / t;
+            let (sin, cos) = (hue.sin(), hue.cos());
+            let numerator = p2 * (c(2.0) + p3) * c(460.0 / 1403.0);
+            if sin.abs() >= cos.abs() {
+                let b = numerator /
+                        (p1 / sin + (c(2.0) + p3) * c(220.0 / 1403.0) * (cos / sin) -
+                         c(27.0 / 1403.0) + p3 * c(6300.0 / 1403.0));
+                (b * cos / sin, b)
+            } else {
+                let a = numerator /
+                        (p1 / cos + (c(2.0) + p3) * c(220.0 / 1403.0) -
+                         (c(27.0 / 1403.0) - p3 * c(6300.0 / 1403.0)) * (sin / cos));
+                (a, a * sin / cos)
+            }
+        };
+
+        let ra = (c(460.0) * p2 + c(451.0) * a + c(288.0) * b) / c(1403.0);
+        let ga = (c(460.0) * p2 - c(891.0) * a - c(261.0) * b) / c(1403.0);
+        let ba = (c(460.0) * p2 - c(220.0) * a - c(6300.0) * b) / c(1403.0);
+        let response = (self.decompress(ra), self.decompress(ga), self.decompress(ba));
+
+        let (x, y, z) = self.inverse.transform_vector(response);
+        let hundred = c(100.0);
+        Xyz::from_channels(x / hundred, y / hundred, z / hundred)
+    }
+
+    // The model's constants assume Y = 100 for a perfect reflecting diffuser.
+    fn to_percent(color: &Xyz<T>) -> (T, T, T) {
+        let hundred: T = num::cast(100.0).unwrap();
+        (color.x() * hundred, color.y() * hundred, color.z() * hundred)
+    }
+
+    // Adapt a color at the Y = 100 scale and compress its cone responses.
+    fn compressed_response(&self, xyz: (T, T, T)) -> (T, T, T) {
+        let (r, g, b) = self.forward.transform_vector(xyz);
+        (self.compress(r), self.compress(g), self.compress(b))
+    }
+
+    fn compress(&self, channel: T) -> T {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        let x = (self.luminance_adaptation * channel.abs() / c(100.0)).powf(c(0.42));
+        channel.signum() * c(400.0) * x / (c(27.13) + x) + c(0.1)
+    }
+
+    fn decompress(&self, channel: T) -> T {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        let x = (channel - c(0.1)).abs();
+        (channel - c(0.1)).signum() * c(100.0) / self.luminance_adaptation *
+        (c(27.13) * x / (c(400.0) - x)).powf(c(1.0 / 0.42))
+    }
+
+    fn achromatic(&self, response: (T, T, T)) -> T {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        (c(2.0) * response.0 + response.1 + response.2 / c(20.0) - c(0.305)) * self.induction
+    }
+
+    fn chroma_factor(&self) -> T {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        (c(1.64) - c(0.29).powf(self.background_ratio)).powf(c(0.73))
+    }
+
+    // The published inverse matrices are rounded, so invert the forward ones instead.
+    fn inverse(transform: Matrix3<T>) -> Matrix3<T> {
+        transform.inverse().expect("Singular cone response matrix, the model must be invertible")
+    }
+}
+
+/// The eccentricity factor `e_t` for a hue angle.
+fn eccentricity<T: FreeChannelScalar>(hue: Deg<T>) -> T {
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    c(0.25) * ((Rad::from_angle(hue).scalar() + c(2.0)).cos() + c(3.8))
+}
+
+/// The hue quadrature `H` of a hue angle, interpolated between the unique hues.
+fn hue_quadrature<T: FreeChannelScalar>(hue: Deg<T>) -> T {
+    const UNIQUE_HUES: [(f64, f64, f64); 5] = [(20.14, 0.8, 0.0),
+                                               (90.0, 0.7, 100.0),
+                                               (164.25, 1.0, 200.0),
+                                               (237.53, 1.2, 300.0),
+                                               (380.14, 0.8, 400.0)];
+    let h: f64 = num::cast(hue.scalar()).unwrap();
+    let h = if h < UNIQUE_HUES[0].0 { h + 360.0 } else { h };
+    let i = UNIQUE_HUES.iter().rposition(|&(hue_i, _, _)| hue_i <= h).unwrap().min(3);
+    let (h_i, e_i, quad_i) = UNIQUE_HUES[i];
+    let (h_next, e_next, _) = UNIQUE_HUES[i + 1];
+    let from = (h - h_i) / e_i;
+    num::cast(quad_i + 100.0 * from / (from + (h_next - h) / e_next)).unwrap()
+}
@@ -0,0 +1,173 @@
+use std::marker::PhantomData;
+use num;
+use angle::{Angle, Deg, Rad, FromAngle};
+use channel::FreeChannelScalar;
+use difference::ColorDifference;
+use xyz::Xyz;
+use cam::{AppearanceModel, CamModel, ColorAppearance};
+
+/// A uniform color space derived from a color appearance model (Luo et al. 2006).
+///
+/// The same coefficients define CAM02-UCS and CAM16-UCS, depending on the model the
+/// correlates come from.
+pub trait UniformSpace: Clone + PartialEq {
+    /// The lightness weight `K_L` and the coefficients `c1` and `c2`.
+    fn coefficients<T: FreeChannelScalar>() -> (T, T, T);
+}
+
+/// The uniform space for large color differences.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Lcd;
+/// The uniform space for small color differences.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Scd;
+/// The uniform space for differences of any size.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ucs;
+
+impl UniformSpace for Lcd {
+    fn coefficients<T: FreeChannelScalar>() -> (T, T, T) {
+        (num::cast(0.77).unwrap(), num::cast(0.007).unwrap(), num::cast(0.0053).unwrap())
+    }
+}
+
+impl UniformSpace for Scd {
+    fn coefficients<T: FreeChannelScalar>() -> (T, T, T) {
+        (num::cast(1.24).unwrap(), num::cast(0.007).unwrap(), num::cast(0.0363).unwrap())
+    }
+}
+
+impl UniformSpace for Ucs {
+    fn coefficients<T: FreeChannelScalar>() -> (T, T, T) {
+        (num::cast(1.0).unwrap(), num::cast(0.007).unwrap(), num::cast(0.0228).unwrap())
+    }
+}
+
+/// A color in the J'a'b' coordinates of the uniform space `Space`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UniformAppearance<T, Space> {
+    /// Lightness `J'`.
+    pub lightness: T,
+    /// The red-green coordinate `a'`.
+    pub a: T,
+    /// The yellow-blue coordinate `b'`.
+    pub b: T,
+    space: PhantomData<Space>,
+}
+
+impl<T, Space> UniformAppearance<T, Space>
+    where T: FreeChannelScalar,
+          Space: UniformSpace
+{
+    pub fn new(lightness: T, a: T, b: T) -> Self {
+        UniformAppearance {
+            lightness,
+            a,
+            b,
+            space: PhantomData,
+        }
+    }
+
+    /// Map the lightness, colorfulness and hue of `appearance` into the space.
+    pub fn from_appearance(appearance: &ColorAppearance<T>) -> Self {
+        let one = T::one();
+        let (_, c1, c2): (T, T, T) = Space::coefficients();
+        let j = appearance.lightness;
+        let m = (one + c2 * appearance.colorfulness).ln() / c2;
+        let (sin, cos) = appearance.hue.sin_cos();
+        UniformAppearance::new((one + num::cast::<_, T>(100.0).unwrap() * c1) * j / (one + c1 * j),
+                               m * cos,
+                               m * sin)
+    }
+
+    /// The lightness `J` of the appearance model.
+    pub fn model_lightness(&self) -> T {
+        let one = T::one();
+        let (_, c1, _): (T, T, T) = Space::coefficients();
+        self.lightness / (one + num::cast::<_, T>(100.0).unwrap() * c1 - c1 * self.lightness)
+    }
+
+    /// The colorfulness `M` of the appearance model.
+    pub fn colorfulness(&self) -> T {
+        let (_, _, c2): (T, T, T) = Space::coefficients();
+        ((c2 * self.a.hypot(self.b)).exp() - T::one()) / c2
+    }
+
+    /// The hue angle `h`.
+    pub fn hue(&self) -> Deg<T> {
+        Angle::normalize(Deg::from_angle(Rad::atan2(self.b, self.a)))
+    }
+}
+
+impl<T, Space> ColorDifference<T> for UniformAppearance<T, Space>
+    where T: FreeChannelScalar,
+          Space: UniformSpace
+{
+    /// Get ΔE' with the lightness weight of the space.
+    fn delta_e(&self, other: &Self) -> T {
+        let (kl, _, _): (T, T, T) = Space::coefficients();
+        let dl = (self.lightness - other.lightness) / kl;
+        let (da, db) = (self.a - other.a, self.b - other.b);
+        (dl * dl + da * da + db * db).sqrt()
+    }
+}
+
+impl<T, Model> AppearanceModel<T, Model>
+    where T: FreeChannelScalar,
+          Model: CamModel<T>
+{
+    /// Compute the coordinates of `color` in the uniform space `Space`.
+    pub fn uniform_appearance<Space>(&self, color: &Xyz<T>) -> UniformAppearance<T, Space>
+        where Space: UniformSpace
+    {
+        UniformAppearance::from_appearance(&self.appearance(color))
+    }
+
+    /// Find the stimulus with the coordinates of `uniform`.
+    pub fn xyz_from_uniform<Space>(&self, uniform: &UniformAppearance<T, Space>) -> Xyz<T>
+        where Space: UniformSpace
+    {
+        let fl4 = self.luminance_adaptation().powf(num::cast(0.25).unwrap());
+        self.xyz_from_jch(uniform.model_lightness(),
+                          uniform.colorfulness() / fl4,
+                          uniform.hue())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cam::{Cam16, Surround, ViewingConditions};
+
+    #[test]
+    fn test_cam16_ucs() {
+        let conditions = ViewingConditions::new(Xyz::from_channels(0.9505, 1.0, 1.0888),
+                                                318.31,
+                                                20.0,
+                                                Surround::Average);
+        let model = Cam16::new(conditions);
+        let c1 = Xyz::from_channels(0.1901, 0.2, 0.2178);
+        let c2 = Xyz::from_channels(0.5706, 0.4306, 0.3196);
+
+        let u1 = model.uniform_appearance::<Ucs>(&c1);
+        assert_relative_eq!(u1.lightness, 54.904450, epsilon=1e-6);
+        assert_relative_eq!(u1.a, -0.085621, epsilon=1e-6);
+        assert_relative_eq!(u1.b, -0.064680, epsilon=1e-6);
+        assert_relative_eq!(model.xyz_from_uniform(&u1), c1, epsilon=1e-9);
+
+        let u2 = model.uniform_appearance::<Ucs>(&c2);
+        assert_relative_eq!(u2.lightness, 76.489587, epsilon=1e-6);
+        assert_relative_eq!(u2.a, 32.854935, epsilon=1e-6);
+        assert_relative_eq!(u2.b, 10.451204, epsilon=1e-6);
+        assert_relative_eq!(model.xyz_from_uniform(&u2), c2, epsilon=1e-9);
+        assert_relative_eq!(u1.delta_e(&u2), 40.762510, epsilon=1e-6);
+
+        let lcd = (model.uniform_appearance::<Lcd>(&c1), model.uniform_appearance::<Lcd>(&c2));
+        assert_relative_eq!(lcd.0.delta_e(&lcd.1), 54.162813, epsilon=1e-6);
+        assert_relative_eq!(model.xyz_from_uniform(&lcd.1), c2, epsilon=1e-9);
+
+        let scd = (model.uniform_appearance::<Scd>(&c1), model.uniform_appearance::<Scd>(&c2));
+        assert_relative_eq!(scd.0.delta_e(&scd.1), 34.212485, epsilon=1e-6);
+        assert_relative_eq!(model.xyz_from_uniform(&scd.0), c1, epsilon=1e-9);
+    }
+}
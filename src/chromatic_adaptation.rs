@@ -0,0 +1,208 @@
+//! Chromatic adaptation transforms between white points.
+//!
+//! A chromatic adaptation transform (CAT) maps a color seen under one illuminant
+//! to the color that appears the same under another. All of the transforms here
+//! follow the von Kries model: `Xyz` is taken into a cone-like `Lms` space defined by
+//! an `LmsModel`, each channel is scaled by the ratio of the two white points, and the
+//! result is taken back to `Xyz`. The three steps are fused into a single `Matrix3`
+//! when the adaptation is constructed, so applying it costs one matrix-vector product.
+
+use std::marker::PhantomData;
+use num;
+use channel::{FreeChannelScalar, PosNormalChannelScalar};
+use color::Color;
+use linalg::Matrix3;
+use lms::{LmsModel, Bradford, CieCam2002, Cat16, VonKries, Sharp, XyzScaling};
+use white_point::NamedWhitePoint;
+use xyz::Xyz;
+
+/// A von Kries-style adaptation from a source white point to a destination white point.
+///
+/// The `Model` parameter selects the cone response space used for the adaptation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChromaticAdaptation<T, Model> {
+    transform: Matrix3<T>,
+    model: PhantomData<Model>,
+}
+
+/// An adaptation using the Hunt-Pointer-Estevez cone space.
+pub type VonKriesAdaptation<T> = ChromaticAdaptation<T, VonKries>;
+/// An adaptation using the Bradford cone space.
+pub type BradfordAdaptation<T> = ChromaticAdaptation<T, Bradford>;
+/// An adaptation using the CIECAM02 (CAT02) cone space.
+pub type Cat02Adaptation<T> = ChromaticAdaptation<T, CieCam2002>;
+/// An adaptation using the CAM16 (CAT16) cone space.
+pub type Cat16Adaptation<T> = ChromaticAdaptation<T, Cat16>;
+/// An adaptation using the spectrally sharpened cone space of Süsstrunk et al.
+pub type SharpAdaptation<T> = ChromaticAdaptation<T, Sharp>;
+/// An adaptation that scales the `Xyz` channels directly.
+pub type XyzScalingAdaptation<T> = ChromaticAdaptation<T, XyzScaling>;
+
+impl<T, Model> ChromaticAdaptation<T, Model>
+    where T: FreeChannelScalar,
+          Model: LmsModel<T>
+{
+    /// Construct a complete adaptation from `source` to `dest`.
+    pub fn new(source: &Xyz<T>, dest: &Xyz<T>) -> Self {
+        Self::with_degree(source, dest, num::cast(1.0).unwrap())
+    }
+
+    /// Construct an adaptation from `source` to `dest` with a degree of adaptation.
+    ///
+    /// A `degree` of 1.0 is a complete adaptation to `dest`, while 0.0 leaves
+    /// colors unchanged. Values in between blend the two linearly in the cone space.
+    pub fn with_degree(source: &Xyz<T>, dest: &Xyz<T>, degree: T) -> Self {
+        let one: T = num::cast(1.0).unwrap();
+        let zero: T = num::cast(0.0).unwrap();
+
+        let forward = Model::forward_transform();
+        let inverse = forward.inverse()
+            .expect("Singular cone response matrix, the LMS model must be invertible");
+
+        let (sl, sm, ss) = forward.transform_vector(source.to_tuple());
+        let (dl, dm, ds) = forward.transform_vector(dest.to_tuple());
+
+        let gain_l = degree * (dl / sl) + (one - degree);
+        let gain_m = degree * (dm / sm) + (one - degree);
+        let gain_s = degree * (ds / ss) + (one - degree);
+
+        let gain = Matrix3::new([gain_l, zero, zero, zero, gain_m, zero, zero, zero, gain_s]);
+
+        ChromaticAdaptation {
+            transform: inverse * gain * forward,
+            model: PhantomData,
+        }
+    }
+
+    /// Construct a complete adaptation between two named white points.
+    pub fn from_named<Source, Dest>() -> Self
+        where T: PosNormalChannelScalar,
+              Source: NamedWhitePoint<T>,
+              Dest: NamedWhitePoint<T>
+    {
+        Self::new(&Source::get_xyz(), &Dest::get_xyz())
+    }
+
+    /// Construct an adaptation between two named white points with a degree of adaptation.
+    pub fn from_named_with_degree<Source, Dest>(degree: T) -> Self
+        where T: PosNormalChannelScalar,
+              Source: NamedWhitePoint<T>,
+              Dest: NamedWhitePoint<T>
+    {
+        Self::with_degree(&Source::get_xyz(), &Dest::get_xyz(), degree)
+    }
+
+    /// Get the combined `Xyz` to `Xyz` transformation matrix.
+    pub fn transform(&self) -> &Matrix3<T> {
+        &self.transform
+    }
+
+    /// Construct the adaptation going in the opposite direction.
+    pub fn inverse(&self) -> Self {
+        ChromaticAdaptation {
+            transform: self.transform
+                .inverse()
+                .expect("Singular adaptation matrix, white points must be non-zero"),
+            model: PhantomData,
+        }
+    }
+
+    /// Adapt a single color.
+    pub fn adapt(&self, color: &Xyz<T>) -> Xyz<T> {
+        let (x, y, z) = self.transform.transform_vector(color.to_tuple());
+        Xyz::from_channels(x, y, z)
+    }
+
+    /// Adapt every color in a slice in place.
+    pub fn adapt_slice(&self, colors: &mut [Xyz<T>]) {
+        for color in colors.iter_mut() {
+            *color = self.adapt(color);
+        }
+    }
+}
+
+/// Compute the CIECAM02 degree of adaptation.
+///
+/// `surround_factor` is the `F` term of the viewing surround (1.0 for average, 0.9 for dim
+/// and 0.8 for dark), and `adapting_luminance` is the luminance of the adapting field in
+/// cd/m². The result is clamped to [0, 1].
+pub fn degree_of_adaptation<T>(surround_factor: T, adapting_luminance: T) -> T
+    where T: num::Float
+{
+    let zero: T = num::cast(0.0).unwrap();
+    let one: T = num::cast(1.0).unwrap();
+    let exponent = (-adapting_luminance - num::cast(42.0).unwrap()) / num::cast(92.0).unwrap();
+    let degree = surround_factor * (one - exponent.exp() / num::cast(3.6).unwrap());
+
+    degree.max(zero).min(one)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use color::Lerp;
+    use white_point::*;
+    use xyz::Xyz;
+
+    #[test]
+    fn test_bradford() {
+        let cat = BradfordAdaptation::<f64>::from_named::<D65, D50>();
+        assert_relative_eq!(*cat.transform(),
+                            Matrix3::new([1.0478112, 0.0228866, -0.0501270, 0.0295424, 0.9904844,
+                                          -0.0170491, -0.0092345, 0.0150436, 0.7521316]),
+                            epsilon = 1e-6);
+        assert_relative_eq!(cat.adapt(&D65::get_xyz()), D50::get_xyz(), epsilon = 1e-6);
+
+        let inv = cat.inverse();
+        assert_relative_eq!(inv.adapt(&D50::get_xyz()), D65::get_xyz(), epsilon = 1e-6);
+        let c1 = Xyz::from_channels(0.3, 0.5, 0.7);
+        assert_relative_eq!(inv.adapt(&cat.adapt(&c1)), c1, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_von_kries() {
+        let cat = VonKriesAdaptation::<f64>::from_named::<D65, D50>();
+        assert_relative_eq!(*cat.transform(),
+                            Matrix3::new([1.0160803, 0.0552297, -0.0521326, 0.0060666, 0.9955661,
+                                          -0.0012235, 0.0000000, 0.0000000, 0.7578869]),
+                            epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_xyz_scaling() {
+        let cat = XyzScalingAdaptation::<f64>::from_named::<D65, D50>();
+        assert_relative_eq!(*cat.transform(),
+                            Matrix3::new([1.0144665, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0,
+                                          0.7578869]),
+                            epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_white_point_mapping() {
+        let src = A::get_xyz();
+        let dst = D65::get_xyz();
+        assert_relative_eq!(Cat02Adaptation::new(&src, &dst).adapt(&src), dst, epsilon = 1e-6);
+        assert_relative_eq!(Cat16Adaptation::new(&src, &dst).adapt(&src), dst, epsilon = 1e-6);
+        assert_relative_eq!(SharpAdaptation::new(&src, &dst).adapt(&src), dst, epsilon = 1e-6);
+
+        let mut colors = [src, Xyz::from_channels(0.0, 0.0, 0.0)];
+        Cat02Adaptation::new(&src, &dst).adapt_slice(&mut colors);
+        assert_relative_eq!(colors[0], dst, epsilon = 1e-6);
+        assert_relative_eq!(colors[1], Xyz::from_channels(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_degree() {
+        let none = Cat02Adaptation::<f64>::from_named_with_degree::<D65, A>(0.0);
+        assert_relative_eq!(*none.transform(), Matrix3::identity(), epsilon = 1e-6);
+
+        let half = Cat02Adaptation::<f64>::from_named_with_degree::<D65, A>(0.5);
+        let full = Cat02Adaptation::<f64>::from_named::<D65, A>();
+        let c1 = half.adapt(&D65::get_xyz());
+        let c2 = full.adapt(&D65::get_xyz());
+        assert_relative_eq!(c1, D65::get_xyz().lerp(&c2, 0.5), epsilon = 1e-6);
+
+        assert_relative_eq!(degree_of_adaptation(1.0, 318.31), 0.994468, epsilon = 1e-6);
+        assert_relative_eq!(degree_of_adaptation(0.8, 20.0), 0.686731, epsilon = 1e-6);
+    }
+}
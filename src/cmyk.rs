@@ -0,0 +1,448 @@
+//! Defines the subtractive `Cmyk` model and its separation from `Rgb`.
+
+use std::fmt;
+use std::mem;
+use std::slice;
+use num;
+use approx;
+use channel::{PosNormalBoundedChannel, ColorChannel, PosNormalChannelScalar, ChannelFormatCast,
+              ChannelCast};
+use color;
+use color::{Color, HomogeneousColor, FromTuple};
+use convert::FromColor;
+use alpha::Alpha;
+use rgb::Rgb;
+
+pub struct CmykTag;
+
+/// A subtractive color of cyan, magenta, yellow and black ink coverage.
+///
+/// Each channel is the fraction of the paper covered by that ink, so `(0, 0, 0, 0)` is
+/// bare paper. `Cmyk` is device-dependent: the conversions here treat the inks as ideal
+/// complements of `Rgb` and do not model any particular press.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub struct Cmyk<T> {
+    pub cyan: PosNormalBoundedChannel<T>,
+    pub magenta: PosNormalBoundedChannel<T>,
+    pub yellow: PosNormalBoundedChannel<T>,
+    pub black: PosNormalBoundedChannel<T>,
+}
+
+pub type Cmyka<T> = Alpha<T, Cmyk<T>>;
+
+/// How much black ink to generate from the gray component of a color.
+///
+/// The gray component is the smallest of the cyan, magenta and yellow coverages, the part
+/// of the color that all three inks print together. A curve maps it to a black coverage,
+/// which is always kept between 0 and the gray component itself.
+#[derive(Copy, Clone, Debug)]
+pub enum BlackCurve<T> {
+    /// Generate no black below `start`, then ramp linearly so that `strength` of the gray
+    /// component is replaced by black at full coverage.
+    Linear { start: T, strength: T },
+    /// Generate black with a user supplied function of the gray component.
+    Custom(fn(T) -> T),
+}
+
+impl<T> BlackCurve<T>
+    where T: PosNormalChannelScalar + num::Float
+{
+    /// Replace the whole gray component with black.
+    pub fn full() -> Self {
+        BlackCurve::Linear {
+            start: T::zero(),
+            strength: T::one(),
+        }
+    }
+
+    /// The black coverage to print for a gray component of `gray`.
+    pub fn black(&self, gray: T) -> T {
+        let black = match *self {
+            BlackCurve::Linear { start, strength } => {
+                if gray <= start {
+                    T::zero()
+                } else {
+                    strength * (gray - start) / (T::one() - start)
+                }
+            }
+            BlackCurve::Custom(curve) => curve(gray),
+        };
+        black.max(T::zero()).min(gray)
+    }
+}
+
+/// The strategy used to separate an `Rgb` color into the four inks.
+#[derive(Copy, Clone, Debug)]
+pub enum Separation<T> {
+    /// The textbook conversion: black takes the darkest channel and the other inks are
+    /// rescaled to the remaining range. This is what `FromColor` uses.
+    Naive,
+    /// Under-color removal: black is generated only for near-neutral colors, and the same
+    /// amount is removed from cyan, magenta and yellow. Saturated colors keep their
+    /// three-ink build.
+    UnderColorRemoval { curve: BlackCurve<T>, ink_limit: T },
+    /// Gray-component replacement: black replaces the gray component of every color,
+    /// saturated or not.
+    GrayComponentReplacement { curve: BlackCurve<T>, ink_limit: T },
+}
+
+impl<T> Cmyk<T>
+    where T: PosNormalChannelScalar
+{
+    pub fn from_channels(cyan: T, magenta: T, yellow: T, black: T) -> Self {
+        Cmyk {
+            cyan: PosNormalBoundedChannel::new(cyan),
+            magenta: PosNormalBoundedChannel::new(magenta),
+            yellow: PosNormalBoundedChannel::new(yellow),
+            black: PosNormalBoundedChannel::new(black),
+        }
+    }
+
+    impl_color_color_cast_square!(Cmyk {cyan, magenta, yellow, black},
+        chan_traits={PosNormalChannelScalar});
+
+    pub fn cyan(&self) -> T {
+        self.cyan.0.clone()
+    }
+    pub fn magenta(&self) -> T {
+        self.magenta.0.clone()
+    }
+    pub fn yellow(&self) -> T {
+        self.yellow.0.clone()
+    }
+    pub fn black(&self) -> T {
+        self.black.0.clone()
+    }
+    pub fn cyan_mut(&mut self) -> &mut T {
+        &mut self.cyan.0
+    }
+    pub fn magenta_mut(&mut self) -> &mut T {
+        &mut self.magenta.0
+    }
+    pub fn yellow_mut(&mut self) -> &mut T {
+        &mut self.yellow.0
+    }
+    pub fn black_mut(&mut self) -> &mut T {
+        &mut self.black.0
+    }
+    pub fn set_cyan(&mut self, val: T) {
+        self.cyan.0 = val;
+    }
+    pub fn set_magenta(&mut self, val: T) {
+        self.magenta.0 = val;
+    }
+    pub fn set_yellow(&mut self, val: T) {
+        self.yellow.0 = val;
+    }
+    pub fn set_black(&mut self, val: T) {
+        self.black.0 = val;
+    }
+}
+
+impl<T> Cmyk<T>
+    where T: PosNormalChannelScalar + num::Float
+{
+    /// The sum of all four coverages, where 4 means 400% ink.
+    pub fn total_ink(&self) -> T {
+        self.cyan() + self.magenta() + self.yellow() + self.black()
+    }
+
+    /// Separate `from` into inks using `separation`.
+    pub fn from_rgb_and_separation(from: &Rgb<T>, separation: &Separation<T>) -> Self {
+        let (c, m, y) = (T::one() - from.red(), T::one() - from.green(), T::one() - from.blue());
+        let gray = c.min(m).min(y);
+
+        let (ink_limit, black) = match *separation {
+            Separation::Naive => {
+                if gray >= T::one() {
+                    return Cmyk::from_channels(T::zero(), T::zero(), T::zero(), T::one());
+                }
+                let scale = T::one() - gray;
+                return Cmyk::from_channels((c - gray) / scale,
+                                           (m - gray) / scale,
+                                           (y - gray) / scale,
+                                           gray);
+            }
+            Separation::UnderColorRemoval { ref curve, ink_limit } => {
+                // Scale black down as the color moves away from neutral.
+                let neutrality = T::one() - (c.max(m).max(y) - gray);
+                (ink_limit, curve.black(gray) * neutrality)
+            }
+            Separation::GrayComponentReplacement { ref curve, ink_limit } => {
+                (ink_limit, curve.black(gray))
+            }
+        };
+
+        // Limit black before removing it, so that the colored inks keep what it can't cover.
+        let black = black.min(ink_limit.max(T::zero()));
+        let (mut c, mut m, mut y) = (c - black, m - black, y - black);
+        let colored = c + m + y;
+        if colored + black > ink_limit {
+            let scale = if colored > T::zero() {
+                ((ink_limit - black) / colored).max(T::zero())
+            } else {
+                T::zero()
+            };
+            c = c * scale;
+            m = m * scale;
+            y = y * scale;
+        }
+        Cmyk::from_channels(c, m, y, black)
+    }
+
+    /// Recombine the inks into `Rgb`, inverting `separation`.
+    ///
+    /// This is exact for colors produced by `from_rgb_and_separation` with the same
+    /// separation, unless the ink limit had to remove ink.
+    pub fn to_rgb(&self, separation: &Separation<T>) -> Rgb<T> {
+        match *separation {
+            Separation::Naive => {
+                let paper = T::one() - self.black();
+                Rgb::from_channels((T::one() - self.cyan()) * paper,
+                                   (T::one() - self.magenta()) * paper,
+                                   (T::one() - self.yellow()) * paper)
+            }
+            Separation::UnderColorRemoval { .. } |
+            Separation::GrayComponentReplacement { .. } => {
+                let channel = |ink: T| T::one() - (ink + self.black()).min(T::one());
+                Rgb::from_channels(channel(self.cyan()),
+                                   channel(self.magenta()),
+                                   channel(self.yellow()))
+            }
+        }
+    }
+}
+
+impl<T> Color for Cmyk<T>
+    where T: PosNormalChannelScalar
+{
+    type Tag = CmykTag;
+    type ChannelsTuple = (T, T, T, T);
+
+    #[inline]
+    fn num_channels() -> u32 {
+        4
+    }
+
+    fn to_tuple(self) -> Self::ChannelsTuple {
+        (self.cyan.0, self.magenta.0, self.yellow.0, self.black.0)
+    }
+}
+
+impl<T> FromTuple for Cmyk<T>
+    where T: PosNormalChannelScalar
+{
+    fn from_tuple(values: Self::ChannelsTuple) -> Self {
+        Cmyk::from_channels(values.0, values.1, values.2, values.3)
+    }
+}
+
+impl<T> HomogeneousColor for Cmyk<T>
+    where T: PosNormalChannelScalar
+{
+    type ChannelFormat = T;
+
+    impl_color_homogeneous_color_square!(Cmyk<T> {cyan, magenta, yellow, black},
+        chan=PosNormalBoundedChannel);
+}
+
+impl<T> color::Color4 for Cmyk<T> where T: PosNormalChannelScalar {}
+
+impl<T> color::Invert for Cmyk<T>
+    where T: PosNormalChannelScalar
+{
+    impl_color_invert!(Cmyk {cyan, magenta, yellow, black});
+}
+
+impl<T> color::Bounded for Cmyk<T>
+    where T: PosNormalChannelScalar
+{
+    impl_color_bounded!(Cmyk {cyan, magenta, yellow, black});
+}
+
+impl<T> color::Lerp for Cmyk<T>
+    where T: PosNormalChannelScalar + color::Lerp
+{
+    type Position = <T as color::Lerp>::Position;
+    impl_color_lerp_square!(Cmyk {cyan, magenta, yellow, black});
+}
+
+impl<T> color::Flatten for Cmyk<T>
+    where T: PosNormalChannelScalar
+{
+    type ScalarFormat = T;
+
+    impl_color_as_slice!(T);
+    impl_color_from_slice_square!(Cmyk<T> {cyan:PosNormalBoundedChannel - 0,
+        magenta:PosNormalBoundedChannel - 1, yellow:PosNormalBoundedChannel - 2,
+        black:PosNormalBoundedChannel - 3});
+}
+
+impl<T> approx::ApproxEq for Cmyk<T>
+    where T: PosNormalChannelScalar + approx::ApproxEq,
+          T::Epsilon: Clone
+{
+    impl_approx_eq!({cyan, magenta, yellow, black});
+}
+
+impl<T> Default for Cmyk<T>
+    where T: PosNormalChannelScalar + num::Zero
+{
+    impl_color_default!(Cmyk {cyan:PosNormalBoundedChannel,
+        magenta:PosNormalBoundedChannel,
+        yellow:PosNormalBoundedChannel,
+        black:PosNormalBoundedChannel});
+}
+
+impl<T> fmt::Display for Cmyk<T>
+    where T: PosNormalChannelScalar + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Cmyk({}, {}, {}, {})", self.cyan, self.magenta, self.yellow, self.black)
+    }
+}
+
+impl<T> FromColor<Rgb<T>> for Cmyk<T>
+    where T: PosNormalChannelScalar + num::Float
+{
+    fn from_color(from: &Rgb<T>) -> Self {
+        Cmyk::from_rgb_and_separation(from, &Separation::Naive)
+    }
+}
+
+impl<T> FromColor<Cmyk<T>> for Rgb<T>
+    where T: PosNormalChannelScalar + num::Float
+{
+    fn from_color(from: &Cmyk<T>) -> Self {
+        from.to_rgb(&Separation::Naive)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use color::*;
+
+    #[test]
+    fn test_construct() {
+        let c1 = Cmyk::from_channels(0.1, 0.2, 0.3, 0.4);
+        assert_relative_eq!(c1.cyan(), 0.1);
+        assert_relative_eq!(c1.magenta(), 0.2);
+        assert_relative_eq!(c1.yellow(), 0.3);
+        assert_relative_eq!(c1.black(), 0.4);
+        assert_eq!(c1.to_tuple(), (0.1, 0.2, 0.3, 0.4));
+        assert_eq!(Cmyk::from_tuple(c1.to_tuple()), c1);
+        assert_eq!(c1.as_slice(), &[0.1, 0.2, 0.3, 0.4]);
+        assert_eq!(Cmyk::from_slice(c1.as_slice()), c1);
+
+        let c2 = Cmyk::from_channels(10u8, 20, 30, 255);
+        assert_eq!(c2.color_cast::<f32>().color_cast(), c2);
+        assert_eq!(Cmyk::broadcast(0.5), Cmyk::from_channels(0.5, 0.5, 0.5, 0.5));
+    }
+
+    #[test]
+    fn test_lerp_invert() {
+        let c1 = Cmyk::from_channels(0.0, 0.2, 0.5, 1.0);
+        let c2 = Cmyk::from_channels(1.0, 0.4, 0.5, 0.0);
+        assert_relative_eq!(c1.lerp(&c2, 0.5), Cmyk::from_channels(0.5, 0.3, 0.5, 0.5));
+        assert_relative_eq!(c1.invert(), Cmyk::from_channels(1.0, 0.8, 0.5, 0.0));
+        assert!(Cmyk::from_channels(0.0, 1.2, 0.5, 0.0).normalize().is_normalized());
+    }
+
+    #[test]
+    fn test_alpha() {
+        let c1 = Cmyka::from_color_and_alpha(Cmyk::from_channels(0.1, 0.2, 0.3, 0.4), 0.5);
+        assert_eq!(Cmyka::<f64>::num_channels(), 5);
+        assert_eq!(c1.as_slice(), &[0.1, 0.2, 0.3, 0.4, 0.5]);
+        assert_eq!(c1.to_tuple(), ((0.1, 0.2, 0.3, 0.4), 0.5));
+    }
+
+    #[test]
+    fn test_naive() {
+        let c1 = Rgb::from_channels(0.2, 0.6, 0.4);
+        let t1 = Cmyk::from_color(&c1);
+        assert_relative_eq!(t1, Cmyk::from_channels(0.666667, 0.0, 0.333333, 0.4),
+            epsilon=1e-6);
+        assert_relative_eq!(Rgb::from_color(&t1), c1, epsilon=1e-9);
+
+        let black = Cmyk::from_color(&Rgb::from_channels(0.0, 0.0, 0.0));
+        assert_eq!(black, Cmyk::from_channels(0.0, 0.0, 0.0, 1.0));
+        assert_eq!(Cmyk::from_color(&Rgb::from_channels(1.0, 1.0, 1.0)),
+                   Cmyk::from_channels(0.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_gray_component_replacement() {
+        let c1 = Rgb::from_channels(0.2, 0.6, 0.4);
+        let full = Separation::GrayComponentReplacement {
+            curve: BlackCurve::full(),
+            ink_limit: 4.0,
+        };
+        let t1 = Cmyk::from_rgb_and_separation(&c1, &full);
+        assert_relative_eq!(t1, Cmyk::from_channels(0.4, 0.0, 0.2, 0.4), epsilon=1e-9);
+        assert_relative_eq!(t1.to_rgb(&full), c1, epsilon=1e-9);
+
+        let light = Separation::GrayComponentReplacement {
+            curve: BlackCurve::Linear { start: 0.2, strength: 0.5 },
+            ink_limit: 4.0,
+        };
+        let t2 = Cmyk::from_rgb_and_separation(&c1, &light);
+        assert_relative_eq!(t2, Cmyk::from_channels(0.675, 0.275, 0.475, 0.125), epsilon=1e-9);
+        assert_relative_eq!(t2.to_rgb(&light), c1, epsilon=1e-9);
+
+        let none = Separation::GrayComponentReplacement {
+            curve: BlackCurve::Custom(|_| 0.0),
+            ink_limit: 4.0,
+        };
+        let t3 = Cmyk::from_rgb_and_separation(&c1, &none);
+        assert_relative_eq!(t3, Cmyk::from_channels(0.8, 0.4, 0.6, 0.0), epsilon=1e-9);
+    }
+
+    #[test]
+    fn test_under_color_removal() {
+        let ucr = Separation::UnderColorRemoval {
+            curve: BlackCurve::full(),
+            ink_limit: 4.0,
+        };
+        let gray = Rgb::from_channels(0.3, 0.3, 0.3);
+        let t1 = Cmyk::from_rgb_and_separation(&gray, &ucr);
+        assert_relative_eq!(t1, Cmyk::from_channels(0.0, 0.0, 0.0, 0.7), epsilon=1e-9);
+
+        // Saturated colors keep most of their three-ink build.
+        let c2 = Rgb::from_channels(0.1, 0.9, 0.5);
+        let t2 = Cmyk::from_rgb_and_separation(&c2, &ucr);
+        assert_relative_eq!(t2, Cmyk::from_channels(0.88, 0.08, 0.48, 0.02), epsilon=1e-9);
+        assert_relative_eq!(t2.to_rgb(&ucr), c2, epsilon=1e-9);
+    }
+
+    #[test]
+    fn test_ink_limit() {
+        let limited = Separation::GrayComponentReplacement {
+            curve: BlackCurve::Linear { start: 0.0, strength: 0.1 },
+            ink_limit: 2.6,
+        };
+        let c1 = Rgb::from_channels(0.0, 0.0, 0.05);
+        let t1 = Cmyk::from_rgb_and_separation(&c1, &limited);
+        assert_relative_eq!(t1.total_ink(), 2.6, epsilon=1e-9);
+        assert_relative_eq!(t1.black(), 0.095, epsilon=1e-9);
+
+        let c2 = Rgb::from_channels(0.5, 0.6, 0.4);
+        let t2 = Cmyk::from_rgb_and_separation(&c2, &limited);
+        assert_relative_eq!(t2.to_rgb(&limited), c2, epsilon=1e-9);
+    }
+
+    #[test]
+    fn test_ink_limit_below_one() {
+        let limited = Separation::GrayComponentReplacement {
+            curve: BlackCurve::full(),
+            ink_limit: 0.7,
+        };
+        let c1 = Rgb::from_channels(0.05, 0.1, 0.1);
+        let t1 = Cmyk::from_rgb_and_separation(&c1, &limited);
+        assert_relative_eq!(t1, Cmyk::from_channels(0.0, 0.0, 0.0, 0.7), epsilon=1e-9);
+        assert_relative_eq!(t1.total_ink(), 0.7, epsilon=1e-9);
+        assert_relative_eq!(t1.to_rgb(&limited), Rgb::from_channels(0.3, 0.3, 0.3),
+            epsilon=1e-9);
+    }
+}
@@ -0,0 +1,215 @@
+use num;
+use channel::{FreeChannelScalar, PosNormalChannelScalar};
+use chromatic_adaptation::ChromaticAdaptation;
+use color_space::color_space::{ColorSpace, ColorSpaceEncoding};
+use encoding::{ColorEncoding, EncodedColor, EncodableColor, LinearEncoding};
+use linalg::Matrix3;
+use lms::{LmsModel, Bradford};
+use rgb::Rgb;
+
+/// Converts `Rgb` colors directly from one color space to another.
+///
+/// The linear parts of the conversion, source RGB to `Xyz`, any chromatic
+/// adaptation and `Xyz` to destination RGB, are fused into a single matrix
+/// when the converter is built. Converting a color then only decodes it with the
+/// source encoding, applies the matrix and re-encodes it with the destination encoding.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColorSpaceConverter<T, ESrc, EDst> {
+    transform: Matrix3<T>,
+    source_encoding: ESrc,
+    dest_encoding: EDst,
+}
+
+impl<T, ESrc, EDst> ColorSpaceConverter<T, ESrc, EDst>
+    where T: num::Float + FreeChannelScalar + PosNormalChannelScalar,
+          ESrc: ColorEncoding,
+          EDst: ColorEncoding
+{
+    /// Build a converter, using a Bradford adaptation if the white points differ.
+    pub fn new<S, D>(source: &S, dest: &D) -> Self
+        where S: ColorSpace<T> + ColorSpaceEncoding<ESrc>,
+              D: ColorSpace<T> + ColorSpaceEncoding<EDst>
+    {
+        Self::with_adaptation::<S, D, Bradford>(source, dest)
+    }
+
+    /// Build a converter, using the `Model` cone space to adapt between white points.
+    ///
+    /// No adaptation is done if both spaces share the same white point.
+    pub fn with_adaptation<S, D, Model>(source: &S, dest: &D) -> Self
+        where S: ColorSpace<T> + ColorSpaceEncoding<ESrc>,
+              D: ColorSpace<T> + ColorSpaceEncoding<EDst>,
+              Model: LmsModel<T>
+    {
+        let to_xyz = *source.get_xyz_transform();
+        let from_xyz = *dest.get_inverse_xyz_transform();
+
+        let transform = if source.white_point() == dest.white_point() {
+            from_xyz * to_xyz
+        } else {
+            let adaptation = ChromaticAdaptation::<T, Model>::new(&source.white_point(),
+                                                                  &dest.white_point());
+            from_xyz * *adaptation.transform() * to_xyz
+        };
+
+        ColorSpaceConverter {
+            transform,
+            source_encoding: source.encoding().clone(),
+            dest_encoding: dest.encoding().clone(),
+        }
+    }
+
+    /// Get the fused linear RGB to linear RGB transformation matrix.
+    pub fn transform(&self) -> &Matrix3<T> {
+        &self.transform
+    }
+
+    pub fn source_encoding(&self) -> &ESrc {
+        &self.source_encoding
+    }
+
+    pub fn dest_encoding(&self) -> &EDst {
+        &self.dest_encoding
+    }
+
+    /// Convert a single color encoded in the source space into the destination space.
+    pub fn convert(&self, color: &Rgb<T>) -> Rgb<T> {
+        let linear = (self.source_encoding.decode_channel(color.red()),
+                      self.source_encoding.decode_channel(color.green()),
+                      self.source_encoding.decode_channel(color.blue()));
+        let (r, g, b) = self.transform.transform_vector(linear);
+
+        Rgb::from_channels(self.dest_encoding.encode_channel(r),
+                           self.dest_encoding.encode_channel(g),
+                           self.dest_encoding.encode_channel(b))
+    }
+
+    /// Convert a color carrying the source encoding into one carrying the destination encoding.
+    pub fn convert_encoded(&self, color: &EncodedColor<Rgb<T>, ESrc>) -> EncodedColor<Rgb<T>, EDst>
+        where Rgb<T>: EncodableColor
+    {
+        let linear = color.clone().decode().strip_encoding();
+        let (r, g, b) = self.transform
+            .transform_vector((linear.red(), linear.green(), linear.blue()));
+
+        Rgb::from_channels(r, g, b)
+            .with_encoding(LinearEncoding::new())
+            .encode(self.dest_encoding.clone())
+    }
+
+    /// Convert every color in a slice in place.
+    pub fn convert_slice(&self, colors: &mut [Rgb<T>]) {
+        for color in colors.iter_mut() {
+            *color = self.convert(color);
+        }
+    }
+
+    /// Convert every color in `source`, writing the results into `dest`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` and `dest` have different lengths.
+    pub fn convert_into(&self, source: &[Rgb<T>], dest: &mut [Rgb<T>]) {
+        assert_eq!(source.len(), dest.len());
+        for (from, to) in source.iter().zip(dest.iter_mut()) {
+            *to = self.convert(from);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use color::*;
+    use color_space::color_space::ColorToXyz;
+    use color_space::presets::*;
+    use chromatic_adaptation::BradfordAdaptation;
+    use encoding::SrgbEncoding;
+    use white_point::*;
+    use lms::CieCam2002;
+
+    #[test]
+    fn test_same_white_point() {
+        let srgb = sRgb::get_color_space();
+        let p3 = DisplayP3::get_color_space();
+        let conv = ColorSpaceConverter::new(&srgb, &p3);
+
+        let c1 = Rgb::from_channels(1.0, 0.0, 0.0);
+        assert_relative_eq!(conv.convert(&c1),
+                            Rgb::from_channels(0.917488, 0.200287, 0.138561),
+                            epsilon = 1e-4);
+
+        let c2 = Rgb::from_channels(0.25, 0.55, 0.89);
+        let t2 = conv.convert(&c2);
+        assert_relative_eq!(p3.color_to_xyz(&t2), srgb.color_to_xyz(&c2), epsilon = 1e-6);
+
+        let back = ColorSpaceConverter::new(&p3, &srgb);
+        assert_relative_eq!(back.convert(&t2), c2, epsilon = 1e-6);
+        assert_relative_eq!(conv.convert(&Rgb::broadcast(1.0)),
+                            Rgb::broadcast(1.0),
+                            epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_adapted() {
+        let srgb = sRgb::get_color_space();
+        let pro_photo = ProPhotoRgb::get_color_space();
+        let conv = ColorSpaceConverter::new(&srgb, &pro_photo);
+
+        assert_relative_eq!(conv.convert(&Rgb::broadcast(1.0)),
+                            Rgb::broadcast(1.0),
+                            epsilon = 1e-6);
+        assert_relative_eq!(conv.convert(&Rgb::broadcast(0.0)),
+                            Rgb::broadcast(0.0),
+                            epsilon = 1e-6);
+
+        let c1 = Rgb::from_channels(0.8, 0.3, 0.1);
+        let t1 = conv.convert(&c1);
+        let cat = BradfordAdaptation::from_named::<D65, D50>();
+        assert_relative_eq!(pro_photo.color_to_xyz(&t1),
+                            cat.adapt(&srgb.color_to_xyz(&c1)),
+                            epsilon = 1e-6);
+
+        let back = ColorSpaceConverter::new(&pro_photo, &srgb);
+        assert_relative_eq!(back.convert(&t1), c1, epsilon = 1e-6);
+
+        let cat02 = ColorSpaceConverter::with_adaptation::<_, _, CieCam2002>(&srgb, &pro_photo);
+        assert!(cat02.transform() != conv.transform());
+        assert_relative_eq!(cat02.convert(&Rgb::broadcast(1.0)),
+                            Rgb::broadcast(1.0),
+                            epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_encoded() {
+        let srgb = sRgb::get_color_space();
+        let linear = LinearSrgb::get_color_space();
+        let conv = ColorSpaceConverter::new(&srgb, &linear);
+        assert_relative_eq!(*conv.transform(), Matrix3::identity(), epsilon = 1e-6);
+
+        let c1 = Rgb::from_channels(0.5, 0.5, 0.5).with_encoding(SrgbEncoding::new());
+        let t1 = conv.convert_encoded(&c1);
+        assert_relative_eq!(t1, c1.clone().decode(), epsilon = 1e-6);
+        assert_eq!(*t1.encoding(), LinearEncoding::new());
+        assert_relative_eq!(ColorSpaceConverter::new(&linear, &srgb).convert_encoded(&t1),
+                            c1,
+                            epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_slice() {
+        let srgb = sRgb::get_color_space();
+        let rec2020 = Rec2020::get_color_space();
+        let conv = ColorSpaceConverter::new(&srgb, &rec2020);
+
+        let source = [Rgb::from_channels(0.1, 0.2, 0.3), Rgb::from_channels(0.9, 0.4, 0.0)];
+        let mut dest = [Rgb::broadcast(0.0); 2];
+        conv.convert_into(&source, &mut dest);
+        assert_relative_eq!(dest[0], conv.convert(&source[0]));
+        assert_relative_eq!(dest[1], conv.convert(&source[1]));
+
+        let mut colors = source;
+        conv.convert_slice(&mut colors);
+        assert_eq!(colors, dest);
+    }
+}
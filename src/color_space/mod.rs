@@ -1,6 +1,8 @@
 pub mod color_space;
 pub mod primary;
 pub mod presets;
+pub mod converter;
 
 pub use self::color_space::{LinearColorSpace, EncodedColorSpace};
 pub use self::primary::RgbPrimary;
+pub use self::converter::ColorSpaceConverter;
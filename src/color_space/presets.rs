@@ -3,30 +3,227 @@
 use num;
 use channel::{FreeChannelScalar, PosNormalChannelScalar};
 use color_space::{EncodedColorSpace, RgbPrimary};
-use encoding::{SrgbEncoding, ColorEncoding};
-use white_point::{NamedWhitePoint, D65};
+use encoding::{SrgbEncoding, LinearEncoding, GammaEncoding, Rec709Encoding,
+               ParametricCurveEncoding, ColorEncoding};
+use white_point::{NamedWhitePoint, D50, D65, Dci, Aces};
 
 pub trait NamedColorSpace<T> {
     type Encoding: ColorEncoding;
     fn get_color_space() -> EncodedColorSpace<T, Self::Encoding>;
 }
 
+macro_rules! impl_named_color_space {
+    ($name:ident, encoding=$enc:expr => $enc_ty:ty, white_point=$wp:ident,
+     red=($rx:expr, $ry:expr), green=($gx:expr, $gy:expr), blue=($bx:expr, $by:expr)) => {
+        impl<T> NamedColorSpace<T> for $name
+            where T: num::Float + FreeChannelScalar + PosNormalChannelScalar
+        {
+            type Encoding = $enc_ty;
+
+            fn get_color_space() -> EncodedColorSpace<T, $enc_ty> {
+                EncodedColorSpace::new(RgbPrimary::new(num::cast($rx).unwrap(),
+                                                       num::cast($ry).unwrap()),
+                                       RgbPrimary::new(num::cast($gx).unwrap(),
+                                                       num::cast($gy).unwrap()),
+                                       RgbPrimary::new(num::cast($bx).unwrap(),
+                                                       num::cast($by).unwrap()),
+                                       $wp::get_xyz(),
+                                       $enc)
+            }
+        }
+    }
+}
+
+/// IEC 61966-2-1 sRGB.
 #[derive(Clone, Debug, PartialEq)]
 pub struct sRgb;
+/// sRGB primaries without the transfer function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinearSrgb;
+/// Adobe RGB (1998).
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdobeRgb;
+/// Adobe RGB (1998) primaries without the transfer function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinearAdobeRgb;
+/// Apple Display P3: DCI-P3 primaries with a D65 white point and the sRGB transfer function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisplayP3;
+/// Display P3 primaries without the transfer function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinearDisplayP3;
+/// SMPTE RP 431-2 theatrical DCI-P3.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DciP3;
+/// ITU-R BT.709 HDTV, using the camera OETF.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rec709;
+/// ITU-R BT.2020 UHDTV, using the camera OETF.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rec2020;
+/// ITU-R BT.2020 primaries without the transfer function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinearRec2020;
+/// ProPhoto RGB (ROMM RGB).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProPhotoRgb;
+/// ProPhoto RGB primaries without the transfer function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinearProPhotoRgb;
+/// Apple RGB, the legacy Macintosh display space.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AppleRgb;
+/// Adobe Wide Gamut RGB.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WideGamutRgb;
+/// ACES2065-1, using the AP0 primaries.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcesAp0;
+/// ACEScg, using the AP1 primaries.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcesAp1;
+
+impl_named_color_space!(sRgb, encoding=SrgbEncoding::new() => SrgbEncoding, white_point=D65,
+    red=(0.6400, 0.3300), green=(0.3000, 0.6000), blue=(0.1500, 0.0600));
+impl_named_color_space!(LinearSrgb, encoding=LinearEncoding::new() => LinearEncoding,
+    white_point=D65,
+    red=(0.6400, 0.3300), green=(0.3000, 0.6000), blue=(0.1500, 0.0600));
+
+impl_named_color_space!(AdobeRgb,
+    encoding=GammaEncoding::new(num::cast(563.0 / 256.0).unwrap()) => GammaEncoding<T>,
+    white_point=D65,
+    red=(0.6400, 0.3300), green=(0.2100, 0.7100), blue=(0.1500, 0.0600));
+impl_named_color_space!(LinearAdobeRgb, encoding=LinearEncoding::new() => LinearEncoding,
+    white_point=D65,
+    red=(0.6400, 0.3300), green=(0.2100, 0.7100), blue=(0.1500, 0.0600));
+
+impl_named_color_space!(DisplayP3, encoding=SrgbEncoding::new() => SrgbEncoding,
+    white_point=D65,
+    red=(0.6800, 0.3200), green=(0.2650, 0.6900), blue=(0.1500, 0.0600));
+impl_named_color_space!(LinearDisplayP3, encoding=LinearEncoding::new() => LinearEncoding,
+    white_point=D65,
+    red=(0.6800, 0.3200), green=(0.2650, 0.6900), blue=(0.1500, 0.0600));
+impl_named_color_space!(DciP3,
+    encoding=GammaEncoding::new(num::cast(2.6).unwrap()) => GammaEncoding<T>,
+    white_point=Dci,
+    red=(0.6800, 0.3200), green=(0.2650, 0.6900), blue=(0.1500, 0.0600));
+
+impl_named_color_space!(Rec709, encoding=Rec709Encoding::new() => Rec709Encoding,
+    white_point=D65,
+    red=(0.6400, 0.3300), green=(0.3000, 0.6000), blue=(0.1500, 0.0600));
+impl_named_color_space!(Rec2020, encoding=Rec709Encoding::new() => Rec709Encoding,
+    white_point=D65,
+    red=(0.7080, 0.2920), green=(0.1700, 0.7970), blue=(0.1310, 0.0460));
+impl_named_color_space!(LinearRec2020, encoding=LinearEncoding::new() => LinearEncoding,
+    white_point=D65,
+    red=(0.7080, 0.2920), green=(0.1700, 0.7970), blue=(0.1310, 0.0460));
+
+impl_named_color_space!(ProPhotoRgb,
+    encoding=ParametricCurveEncoding::romm() => ParametricCurveEncoding<T>,
+    white_point=D50,
+    red=(0.7347, 0.2653), green=(0.1596, 0.8404), blue=(0.0366, 0.0001));
+impl_named_color_space!(LinearProPhotoRgb, encoding=LinearEncoding::new() => LinearEncoding,
+    white_point=D50,
+    red=(0.7347, 0.2653), green=(0.1596, 0.8404), blue=(0.0366, 0.0001));
+
+impl_named_color_space!(AppleRgb,
+    encoding=GammaEncoding::new(num::cast(1.8).unwrap()) => GammaEncoding<T>,
+    white_point=D65,
+    red=(0.6250, 0.3400), green=(0.2800, 0.5950), blue=(0.1550, 0.0700));
+impl_named_color_space!(WideGamutRgb,
+    encoding=GammaEncoding::new(num::cast(2.2).unwrap()) => GammaEncoding<T>,
+    white_point=D50,
+    red=(0.7350, 0.2650), green=(0.1150, 0.8260), blue=(0.1570, 0.0180));
+
+impl_named_color_space!(AcesAp0, encoding=LinearEncoding::new() => LinearEncoding,
+    white_point=Aces,
+    red=(0.7347, 0.2653), green=(0.0000, 1.0000), blue=(0.0001, -0.0770));
+impl_named_color_space!(AcesAp1, encoding=LinearEncoding::new() => LinearEncoding,
+    white_point=Aces,
+    red=(0.7130, 0.2930), green=(0.1650, 0.8300), blue=(0.1280, 0.0440));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use color::*;
+    use color_space::color_space::{ColorSpace, ColorToXyz};
+    use linalg::Matrix3;
+    use rgb::Rgb;
+    use white_point::*;
+
+    #[test]
+    fn test_transforms() {
+        let adobe = AdobeRgb::get_color_space();
+        assert_relative_eq!(*adobe.get_xyz_transform(),
+                            Matrix3::new([0.5767309, 0.1855540, 0.1881852, 0.2973769, 0.6273491,
+                                          0.0752741, 0.0270343, 0.0706872, 0.9911085]),
+                            epsilon = 1e-4);
+
+        let pro_photo = ProPhotoRgb::get_color_space();
+        assert_relative_eq!(*pro_photo.get_xyz_transform(),
+                            Matrix3::new([0.7976749, 0.1351917, 0.0313534, 0.2880402, 0.7118741,
+                                          0.0000857, 0.0000000, 0.0000000, 0.8252100]),
+                            epsilon = 1e-4);
+
+        let p3 = DisplayP3::get_color_space();
+        assert_relative_eq!(*p3.get_xyz_transform(),
+                            Matrix3::new([0.4866327, 0.2656632, 0.1981742, 0.2290036, 0.6917267,
+                                          0.0792697, 0.0000000, 0.0451126, 1.0437174]),
+                            epsilon = 1e-4);
+
+        let rec2020 = Rec2020::get_color_space();
+        assert_relative_eq!(*rec2020.get_xyz_transform(),
+                            Matrix3::new([0.6370102, 0.1446150, 0.1688448, 0.2627217, 0.6779893,
+                                          0.0592890, 0.0000000, 0.0280723, 1.0607577]),
+                            epsilon = 1e-4);
+
+        let ap0 = AcesAp0::get_color_space();
+        assert_relative_eq!(*ap0.get_xyz_transform(),
+                            Matrix3::new([0.9525524, 0.0000000, 0.0000937, 0.3439664, 0.7281661,
+                                          -0.0721325, 0.0000000, 0.0000000, 1.0088252]),
+                            epsilon = 1e-4);
+
+        let ap1 = AcesAp1::get_color_space();
+        assert_relative_eq!(*ap1.get_xyz_transform(),
+                            Matrix3::new([0.6624542, 0.1340042, 0.1561877, 0.2722287, 0.6740818,
+                                          0.0536895, -0.0055746, 0.0040607, 1.0103391]),
+                            epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_white_points() {
+        let white = Rgb::broadcast(1.0);
+        assert_relative_eq!(sRgb::get_color_space().color_to_xyz(&white),
+                            D65::get_xyz(),
+                            epsilon = 1e-6);
+        assert_relative_eq!(LinearDisplayP3::get_color_space().color_to_xyz(&white),
+                            D65::get_xyz(),
+                            epsilon = 1e-6);
+        assert_relative_eq!(DciP3::get_color_space().color_to_xyz(&white),
+                            Dci::get_xyz(),
+                            epsilon = 1e-6);
+        assert_relative_eq!(WideGamutRgb::get_color_space().color_to_xyz(&white),
+                            D50::get_xyz(),
+                            epsilon = 1e-6);
+        let apple: EncodedColorSpace<f64, _> = AppleRgb::get_color_space();
+        assert_relative_eq!(apple.white_point(), D65::get_xyz());
+        let ap1: EncodedColorSpace<f64, _> = AcesAp1::get_color_space();
+        assert_relative_eq!(ap1.white_point(), Aces::get_xyz());
+    }
+
+    #[test]
+    fn test_encodings() {
+        let c1 = Rgb::from_channels(0.5, 0.5, 0.5);
+        let t1 = Rec709::get_color_space().color_to_xyz(&c1);
+        assert_relative_eq!(t1.y(), 0.2595894, epsilon = 1e-6);
+
+        let t2 = AdobeRgb::get_color_space().color_to_xyz(&c1);
+        assert_relative_eq!(t2.y(), 0.2177555, epsilon = 1e-6);
+
+        let t3 = LinearRec2020::get_color_space().color_to_xyz(&c1);
+        assert_relative_eq!(t3.y(), 0.5, epsilon = 1e-6);
 
-impl<T> NamedColorSpace<T> for sRgb
-    where T: num::Float + FreeChannelScalar + PosNormalChannelScalar
-{
-    type Encoding = SrgbEncoding;
-
-    fn get_color_space() -> EncodedColorSpace<T, SrgbEncoding> {
-        EncodedColorSpace::new(RgbPrimary::new(num::cast(0.6400).unwrap(),
-                                               num::cast(0.3300).unwrap()),
-                               RgbPrimary::new(num::cast(0.300).unwrap(),
-                                               num::cast(0.600).unwrap()),
-                               RgbPrimary::new(num::cast(0.150).unwrap(),
-                                               num::cast(0.060).unwrap()),
-                               D65::get_xyz(),
-                               SrgbEncoding::new())
+        let t4 = ProPhotoRgb::get_color_space().color_to_xyz(&Rgb::broadcast(0.02));
+        assert_relative_eq!(t4.y(), 0.00125, epsilon = 1e-6);
     }
 }
@@ -0,0 +1,99 @@
+//! Reading and writing colors as CSS Color Level 4 strings.
+//!
+//! `parse_css_color` accepts named colors, `transparent`, hex colors and the `rgb()`,
+//! `rgba()`, `hsl()`, `hsla()`, `hwb()`, `lab()`, `lch()`, `oklab()`, `oklch()` and
+//! `color(display-p3 ...)` functions, in both the legacy comma-separated syntax where CSS
+//! allows it and the modern space-separated syntax with an optional `/ alpha`. The result
+//! keeps the model the string was written in; colors with an explicit alpha are wrapped in
+//! `Alpha`.
+//!
+//! In the other direction, the `ToCss` trait writes colors as hex, legacy or modern
+//! functional notation according to `CssOptions`, and `CssColorSpace` writes `color()` for
+//! the predefined wide-gamut RGB spaces.
+
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+use angle::Deg;
+use alpha::Alpha;
+use channel::{FreeChannelScalar, PosNormalChannelScalar};
+use rgb::{Rgb, Rgba};
+use hsl::{Hsl, Hsla};
+use hwb::{Hwb, Hwba};
+use lab::Lab;
+use lchab::Lchab;
+use oklab::Oklab;
+use oklch::Oklch;
+
+mod parse;
+mod serialize;
+
+pub use self::parse::parse_css_color;
+pub use self::serialize::{CssColorSpace, CssOptions, CssSyntax, ToCss};
+
+/// A color parsed from a CSS string, in the model it was written in.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CssColor<T> {
+    /// A hex color or an `rgb()` color, with channels in [0, 1].
+    Rgb(Rgb<T>),
+    Rgba(Rgba<T>),
+    /// An `hsl()` color, with saturation and lightness in [0, 1].
+    Hsl(Hsl<T>),
+    Hsla(Hsla<T, Deg<T>>),
+    /// An `hwb()` color, with whiteness and blackness in [0, 1].
+    Hwb(Hwb<T>),
+    Hwba(Hwba<T, Deg<T>>),
+    /// A `lab()` color, with lightness in [0, 100].
+    Lab(Lab<T>),
+    Laba(Alpha<T, Lab<T>>),
+    /// An `lch()` color, with lightness in [0, 100].
+    Lchab(Lchab<T>),
+    Lchaba(Alpha<T, Lchab<T>>),
+    /// An `oklab()` color, with lightness in [0, 1].
+    Oklab(Oklab<T>),
+    Oklaba(Alpha<T, Oklab<T>>),
+    /// An `oklch()` color, with lightness in [0, 1].
+    Oklch(Oklch<T>),
+    Oklcha(Alpha<T, Oklch<T>>),
+    /// The encoded channels of a `color(display-p3 ...)` color.
+    DisplayP3(Rgb<T>),
+    DisplayP3a(Rgba<T>),
+}
+
+impl<T> FromStr for CssColor<T>
+    where T: FreeChannelScalar + PosNormalChannelScalar
+{
+    type Err = CssParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_css_color(s)
+    }
+}
+
+/// The reason a CSS color string could not be parsed, and the byte offset where it was found.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CssParseError {
+    position: usize,
+    reason: &'static str,
+}
+
+impl CssParseError {
+    pub fn new(position: usize, reason: &'static str) -> Self {
+        CssParseError { position, reason }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+    pub fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl fmt::Display for CssParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid CSS color at position {}: {}", self.position, self.reason)
+    }
+}
+
+impl error::Error for CssParseError {}
@@ -0,0 +1,710 @@
+use std::f64::consts;
+use std::str;
+use num;
+use angle::Deg;
+use alpha::Alpha;
+use color::{Color, FromTuple};
+use channel::{FreeChannelScalar, PosNormalChannelScalar};
+use rgb::Rgb;
+use hsl::Hsl;
+use hwb::Hwb;
+use named;
+use css::{CssColor, CssParseError};
+
+type ParseResult<V> = Result<V, CssParseError>;
+
+/// A single channel or alpha value inside a color function.
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Number(f64),
+    Percentage(f64),
+    Dimension(f64, String),
+    None,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Component {
+    value: Value,
+    position: usize,
+}
+
+impl Component {
+    fn error(&self, reason: &'static str) -> CssParseError {
+        CssParseError::new(self.position, reason)
+    }
+
+    /// Read a number or a percentage, where 100% is `full`. `none` is zero.
+    fn scaled(&self, full: f64) -> ParseResult<f64> {
+        match self.value {
+            Value::Number(val) => Ok(val),
+            Value::Percentage(val) => Ok(val / 100.0 * full),
+            Value::None => Ok(0.0),
+            Value::Dimension(..) => Err(self.error("unexpected unit")),
+        }
+    }
+
+    /// Read a percentage as a fraction, as the legacy `hsl()` syntax requires.
+    fn percentage(&self) -> ParseResult<f64> {
+        match self.value {
+            Value::Percentage(val) => Ok(val / 100.0),
+            _ => Err(self.error("expected a percentage")),
+        }
+    }
+
+    /// Read a hue in degrees, normalized to [0, 360).
+    fn hue(&self) -> ParseResult<f64> {
+        let degrees = match self.value {
+            Value::Number(val) => val,
+            Value::None => 0.0,
+            Value::Dimension(val, ref unit) => {
+                match unit.as_str() {
+                    "deg" => val,
+                    "grad" => val * 0.9,
+                    "rad" => val * 180.0 / consts::PI,
+                    "turn" => val * 360.0,
+                    _ => return Err(self.error("unknown angle unit")),
+                }
+            }
+            Value::Percentage(_) => return Err(self.error("expected a hue")),
+        };
+        Ok(((degrees % 360.0) + 360.0) % 360.0)
+    }
+}
+
+/// The contents of the parentheses of a color function.
+struct Arguments {
+    channels: Vec<Component>,
+    alpha: Option<Component>,
+    legacy: bool,
+}
+
+impl Arguments {
+    fn alpha(&self) -> ParseResult<Option<f64>> {
+        match self.alpha {
+            Some(ref alpha) => Ok(Some(clamp(alpha.scaled(1.0)?, 0.0, 1.0))),
+            None => Ok(None),
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Parser {
+            input: input.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).cloned()
+    }
+
+    fn error(&self, reason: &'static str) -> CssParseError {
+        CssParseError::new(self.pos, reason)
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+
+    fn expect(&mut self, byte: u8, reason: &'static str) -> ParseResult<()> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(reason))
+        }
+    }
+
+    /// Read an identifier, lowercased since CSS keywords are case-insensitive.
+    fn ident(&mut self) -> String {
+        let start = self.pos;
+        if !self.peek().is_some_and(|c| c.is_ascii_alphabetic() || c == b'-') {
+            return String::new();
+        }
+        while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == b'-') {
+            self.pos += 1;
+        }
+        String::from_utf8_lossy(&self.input[start..self.pos]).to_ascii_lowercase()
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    fn number(&mut self) -> ParseResult<f64> {
+        let start = self.pos;
+        if let Some(b'+') | Some(b'-') = self.peek() {
+            self.pos += 1;
+        }
+        let mut digits = self.digits();
+        if self.peek() == Some(b'.') {
+            self.pos += 1;
+            digits += self.digits();
+        }
+        if digits == 0 {
+            return Err(CssParseError::new(start, "invalid number"));
+        }
+
+        // Only treat an `e` as an exponent if digits follow, so units such as `em` still end
+        // the number.
+        if let Some(b'e') | Some(b'E') = self.peek() {
+            let mantissa_end = self.pos;
+            self.pos += 1;
+            if let Some(b'+') | Some(b'-') = self.peek() {
+                self.pos += 1;
+            }
+            if self.digits() == 0 {
+                self.pos = mantissa_end;
+            }
+        }
+
+        str::from_utf8(&self.input[start..self.pos])
+            .ok()
+            .and_then(|text| text.parse().ok())
+            .ok_or_else(|| CssParseError::new(start, "invalid number"))
+    }
+
+    fn component(&mut self) -> ParseResult<Component> {
+        let position = self.pos;
+        let value = match self.peek() {
+            Some(c) if c.is_ascii_alphabetic() => {
+                if self.ident() != "none" {
+                    return Err(CssParseError::new(position, "unexpected identifier"));
+                }
+                Value::None
+            }
+            Some(c) if c.is_ascii_digit() || c == b'.' || c == b'+' || c == b'-' => {
+                let val = self.number()?;
+                match self.peek() {
+                    Some(b'%') => {
+                        self.pos += 1;
+                        Value::Percentage(val)
+                    }
+                    Some(c) if c.is_ascii_alphabetic() => Value::Dimension(val, self.ident()),
+                    _ => Value::Number(val),
+                }
+            }
+            Some(_) => return Err(self.error("expected a number")),
+            None => return Err(self.error("unexpected end of input")),
+        };
+        Ok(Component { value, position })
+    }
+
+    /// Skip the whitespace after a component, requiring it unless a delimiter follows.
+    fn separator(&mut self) -> ParseResult<()> {
+        if self.skip_whitespace() {
+            return Ok(());
+        }
+        match self.peek() {
+            Some(b')') | Some(b'/') | Some(b',') | None => Ok(()),
+            _ => Err(self.error("expected whitespace between values")),
+        }
+    }
+
+    fn arguments(&mut self, allow_legacy: bool) -> ParseResult<Arguments> {
+        self.skip_whitespace();
+        let mut channels = vec![self.component()?];
+        self.separator()?;
+
+        let mut alpha = None;
+        let legacy = self.peek() == Some(b',');
+        if legacy {
+            if !allow_legacy {
+                return Err(self.error("comma-separated syntax is not allowed here"));
+            }
+            while self.peek() == Some(b',') {
+                self.pos += 1;
+                self.skip_whitespace();
+                channels.push(self.component()?);
+                self.separator()?;
+            }
+            if channels.len() == 4 {
+                alpha = channels.pop();
+            }
+            if let Some(none) = channels.iter().chain(&alpha).find(|c| c.value == Value::None) {
+                return Err(none.error("'none' is not allowed in comma-separated syntax"));
+            }
+        } else {
+            while let Some(c) = self.peek() {
+                if c == b')' || c == b'/' || c == b',' {
+                    break;
+                }
+                channels.push(self.component()?);
+                self.separator()?;
+            }
+            if self.peek() == Some(b'/') {
+                self.pos += 1;
+                self.skip_whitespace();
+                alpha = Some(self.component()?);
+                self.skip_whitespace();
+            }
+        }
+
+        if channels.len() > 3 {
+            return Err(channels[3].error("too many values"));
+        }
+        if self.peek() == Some(b',') {
+            return Err(self.error("cannot mix commas and spaces between values"));
+        }
+        if channels.len() < 3 {
+            return Err(self.error("expected three values"));
+        }
+        self.expect(b')', "expected ')'")?;
+
+        Ok(Arguments {
+            channels,
+            alpha,
+            legacy,
+        })
+    }
+
+    fn hex<T>(&mut self) -> ParseResult<CssColor<T>>
+        where T: FreeChannelScalar + PosNormalChannelScalar
+    {
+        let start = self.pos;
+        self.pos += 1;
+        let mut digits = Vec::new();
+        while let Some(c) = self.peek() {
+            if !c.is_ascii_alphanumeric() {
+                break;
+            }
+            match (c as char).to_digit(16) {
+                Some(digit) => digits.push(digit as u8),
+                None => return Err(self.error("invalid hex digit")),
+            }
+            self.pos += 1;
+        }
+
+        let channels: Vec<u8> = match digits.len() {
+            3 | 4 => digits.iter().map(|&digit| digit * 17).collect(),
+            6 | 8 => digits.chunks(2).map(|pair| pair[0] * 16 + pair[1]).collect(),
+            _ => return Err(CssParseError::new(start, "hex colors need 3, 4, 6 or 8 digits")),
+        };
+        let channels: Vec<f64> = channels.iter().map(|&val| f64::from(val) / 255.0).collect();
+
+        let color = Rgb::from_channels(cast(channels[0]), cast(channels[1]), cast(channels[2]));
+        Ok(with_alpha(color, channels.get(3).cloned(), CssColor::Rgb, CssColor::Rgba))
+    }
+
+    fn function<T>(&mut self) -> ParseResult<CssColor<T>>
+        where T: FreeChannelScalar + PosNormalChannelScalar
+    {
+        let start = self.pos;
+        let name = self.ident();
+        if name.is_empty() {
+            return Err(self.error("expected a color"));
+        }
+        if self.peek() != Some(b'(') {
+            return keyword(&name).ok_or_else(|| CssParseError::new(start, "unknown color name"));
+        }
+        self.pos += 1;
+
+        match name.as_str() {
+            "rgb" | "rgba" => rgb(&self.arguments(true)?),
+            "hsl" | "hsla" => hsl(&self.arguments(true)?),
+            "hwb" => hwb(&self.arguments(false)?),
+            "lab" => lab(&self.arguments(false)?, 100.0, 125.0, CssColor::Lab, CssColor::Laba),
+            "lch" => lch(&self.arguments(false)?, 100.0, 150.0, CssColor::Lchab, CssColor::Lchaba),
+            "oklab" => lab(&self.arguments(false)?, 1.0, 0.4, CssColor::Oklab, CssColor::Oklaba),
+            "oklch" => lch(&self.arguments(false)?, 1.0, 0.4, CssColor::Oklch, CssColor::Oklcha),
+            "color" => {
+                self.skip_whitespace();
+                let space_start = self.pos;
+                let space = self.ident();
+                if space.is_empty() {
+                    return Err(self.error("expected a color space"));
+                }
+                if space != "display-p3" {
+                    return Err(CssParseError::new(space_start, "unsupported color space"));
+                }
+                predefined(&self.arguments(false)?, CssColor::DisplayP3, CssColor::DisplayP3a)
+            }
+            _ => Err(CssParseError::new(start, "unknown color function")),
+        }
+    }
+}
+
+/// Parse a CSS Color Level 4 color string.
+///
+/// Channel values outside the range CSS allows are clamped, except in `color()`, which can
+/// describe colors outside the gamut of its color space. Errors report the byte offset of the
+/// problem in `s`.
+pub fn parse_css_color<T>(s: &str) -> Result<CssColor<T>, CssParseError>
+    where T: FreeChannelScalar + PosNormalChannelScalar
+{
+    let mut parser = Parser::new(s);
+    parser.skip_whitespace();
+    let color = match parser.peek() {
+        Some(b'#') => parser.hex()?,
+        Some(_) => parser.function()?,
+        None => return Err(parser.error("empty color string")),
+    };
+    parser.skip_whitespace();
+    if parser.peek().is_some() {
+        return Err(parser.error("unexpected characters after the color"));
+    }
+    Ok(color)
+}
+
+/// Look up `transparent` or a named color.
+fn keyword<T>(name: &str) -> Option<CssColor<T>>
+    where T: FreeChannelScalar + PosNormalChannelScalar
+{
+    if name == "transparent" {
+        let black = Rgb::from_channels(T::zero(), T::zero(), T::zero());
+        return Some(CssColor::Rgba(Alpha::from_color_and_alpha(black, T::zero())));
+    }
+    named::from_name(name).map(|color| {
+        let channel = |val: u8| cast(f64::from(val) / 255.0);
+        CssColor::Rgb(Rgb::from_channels(channel(color.red()),
+                                         channel(color.green()),
+                                         channel(color.blue())))
+    })
+}
+
+fn cast<T: num::Float>(val: f64) -> T {
+    num::cast(val).unwrap()
+}
+
+fn clamp(val: f64, min: f64, max: f64) -> f64 {
+    val.max(min).min(max)
+}
+
+fn with_alpha<T, C>(color: C,
+                    alpha: Option<f64>,
+                    opaque: fn(C) -> CssColor<T>,
+                    translucent: fn(Alpha<T, C>) -> CssColor<T>)
+                    -> CssColor<T>
+    where T: FreeChannelScalar + PosNormalChannelScalar,
+          C: Color
+{
+    match alpha {
+        Some(alpha) => translucent(Alpha::from_color_and_alpha(color, cast(alpha))),
+        None => opaque(color),
+    }
+}
+
+fn rgb<T>(args: &Arguments) -> ParseResult<CssColor<T>>
+    where T: FreeChannelScalar + PosNormalChannelScalar
+{
+    if args.legacy {
+        let is_percentage = |c: &Component| matches!(c.value, Value::Percentage(_));
+        let first = is_percentage(&args.channels[0]);
+        if let Some(mixed) = args.channels.iter().find(|c| is_percentage(c) != first) {
+            return Err(mixed.error("cannot mix numbers and percentages in comma-separated syntax"));
+        }
+    }
+
+    let mut channels = [0.0; 3];
+    for (channel, component) in channels.iter_mut().zip(&args.channels) {
+        *channel = clamp(component.scaled(255.0)? / 255.0, 0.0, 1.0);
+    }
+    let color = Rgb::from_channels(cast(channels[0]), cast(channels[1]), cast(channels[2]));
+    Ok(with_alpha(color, args.alpha()?, CssColor::Rgb, CssColor::Rgba))
+}
+
+/// Read the two percentage channels of `hsl()` and `hwb()` as fractions.
+fn fractions(args: &Arguments) -> ParseResult<(f64, f64)> {
+    let fraction = |c: &Component| if args.legacy {
+        c.percentage()
+    } else {
+        c.scaled(100.0).map(|val| val / 100.0)
+    };
+    Ok((clamp(fraction(&args.channels[1])?, 0.0, 1.0),
+        clamp(fraction(&args.channels[2])?, 0.0, 1.0)))
+}
+
+fn hsl<T>(args: &Arguments) -> ParseResult<CssColor<T>>
+    where T: FreeChannelScalar + PosNormalChannelScalar
+{
+    let hue = args.channels[0].hue()?;
+    let (saturation, lightness) = fractions(args)?;
+    let color = Hsl::from_channels(Deg(cast(hue)), cast(saturation), cast(lightness));
+    Ok(with_alpha(color, args.alpha()?, CssColor::Hsl, CssColor::Hsla))
+}
+
+fn hwb<T>(args: &Arguments) -> ParseResult<CssColor<T>>
+    where T: FreeChannelScalar + PosNormalChannelScalar
+{
+    let hue = args.channels[0].hue()?;
+    let (whiteness, blackness) = fractions(args)?;
+    let color = Hwb::from_channels(Deg(cast(hue)), cast(whiteness), cast(blackness));
+    Ok(with_alpha(color, args.alpha()?, CssColor::Hwb, CssColor::Hwba))
+}
+
+/// Read a rectangular lab-like color, where 100% is `max_lightness` for lightness and
+/// `full_axis` for the a and b axes.
+fn lab<T, C>(args: &Arguments,
+             max_lightness: f64,
+             full_axis: f64,
+             opaque: fn(C) -> CssColor<T>,
+             translucent: fn(Alpha<T, C>) -> CssColor<T>)
+             -> ParseResult<CssColor<T>>
+    where T: FreeChannelScalar + PosNormalChannelScalar,
+          C: Color<ChannelsTuple = (T, T, T)> + FromTuple
+{
+    let lightness = clamp(args.channels[0].scaled(max_lightness)?, 0.0, max_lightness);
+    let a = args.channels[1].scaled(full_axis)?;
+    let b = args.channels[2].scaled(full_axis)?;
+    let color = C::from_tuple((cast(lightness), cast(a), cast(b)));
+    Ok(with_alpha(color, args.alpha()?, opaque, translucent))
+}
+
+/// Read a polar lab-like color, where 100% is `max_lightness` for lightness and
+/// `full_chroma` for chroma.
+fn lch<T, C>(args: &Arguments,
+             max_lightness: f64,
+             full_chroma: f64,
+             opaque: fn(C) -> CssColor<T>,
+             translucent: fn(Alpha<T, C>) -> CssColor<T>)
+             -> ParseResult<CssColor<T>>
+    where T: FreeChannelScalar + PosNormalChannelScalar,
+          C: Color<ChannelsTuple = (T, T, Deg<T>)> + FromTuple
+{
+    let lightness = clamp(args.channels[0].scaled(max_lightness)?, 0.0, max_lightness);
+    let chroma = args.channels[1].scaled(full_chroma)?.max(0.0);
+    let hue = args.channels[2].hue()?;
+    let color = C::from_tuple((cast(lightness), cast(chroma), Deg(cast(hue))));
+    Ok(with_alpha(color, args.alpha()?, opaque, translucent))
+}
+
+fn predefined<T>(args: &Arguments,
+                 opaque: fn(Rgb<T>) -> CssColor<T>,
+                 translucent: fn(Alpha<T, Rgb<T>>) -> CssColor<T>)
+                 -> ParseResult<CssColor<T>>
+    where T: FreeChannelScalar + PosNormalChannelScalar
+{
+    let mut channels = [0.0; 3];
+    for (channel, component) in channels.iter_mut().zip(&args.channels) {
+        *channel = component.scaled(1.0)?;
+    }
+    let color = Rgb::from_channels(cast(channels[0]), cast(channels[1]), cast(channels[2]));
+    Ok(with_alpha(color, args.alpha()?, opaque, translucent))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(s: &str) -> CssColor<f64> {
+        match parse_css_color(s) {
+            Ok(color) => color,
+            Err(err) => panic!("failed to parse {:?}: {}", s, err),
+        }
+    }
+
+    fn error(s: &str) -> (usize, &'static str) {
+        let err = parse_css_color::<f64>(s).unwrap_err();
+        (err.position(), err.reason())
+    }
+
+    fn rgb_tuple(s: &str) -> (f64, f64, f64) {
+        match parse(s) {
+            CssColor::Rgb(color) => color.to_tuple(),
+            other => panic!("expected an rgb color from {:?}, got {:?}", s, other),
+        }
+    }
+
+    fn rgba_tuple(s: &str) -> ((f64, f64, f64), f64) {
+        match parse(s) {
+            CssColor::Rgba(color) => color.to_tuple(),
+            other => panic!("expected an rgba color from {:?}, got {:?}", s, other),
+        }
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(rgb_tuple("#ff8000"), (1.0, 128.0 / 255.0, 0.0));
+        assert_eq!(rgb_tuple("#F80"), (1.0, 136.0 / 255.0, 0.0));
+        assert_eq!(rgb_tuple("  #000000 "), (0.0, 0.0, 0.0));
+        assert_eq!(rgba_tuple("#ff000080"), ((1.0, 0.0, 0.0), 128.0 / 255.0));
+        assert_eq!(rgba_tuple("#0f08"), ((0.0, 1.0, 0.0), 136.0 / 255.0));
+
+        assert_eq!(error("#12345"), (0, "hex colors need 3, 4, 6 or 8 digits"));
+        assert_eq!(error("#12g"), (3, "invalid hex digit"));
+        assert_eq!(error("#123 x"), (5, "unexpected characters after the color"));
+    }
+
+    #[test]
+    fn test_rgb() {
+        assert_eq!(rgb_tuple("rgb(255, 0, 51)"), (1.0, 0.0, 0.2));
+        assert_eq!(rgb_tuple("RGB(100%,0%,20%)"), (1.0, 0.0, 0.2));
+        assert_eq!(rgb_tuple("rgb(255 0 51)"), (1.0, 0.0, 0.2));
+        assert_eq!(rgb_tuple("rgb(300 -10 50%)"), (1.0, 0.0, 0.5));
+        assert_eq!(rgb_tuple("rgb(none 255 none)"), (0.0, 1.0, 0.0));
+        assert_eq!(rgb_tuple("rgba(255 0 0)"), (1.0, 0.0, 0.0));
+        assert_eq!(rgb_tuple("rgb(2.55e2 0 0)"), (1.0, 0.0, 0.0));
+
+        assert_eq!(rgba_tuple("rgba(255, 0, 0, 0.5)"), ((1.0, 0.0, 0.0), 0.5));
+        assert_eq!(rgba_tuple("rgb(255, 0, 0, 25%)"), ((1.0, 0.0, 0.0), 0.25));
+        assert_eq!(rgba_tuple("rgb(0 0 255 / .5)"), ((0.0, 0.0, 1.0), 0.5));
+        assert_eq!(rgba_tuple("rgb(0 0 255/2)"), ((0.0, 0.0, 1.0), 1.0));
+
+        assert_eq!(error("rgb(255, 0%, 0)"),
+                   (9, "cannot mix numbers and percentages in comma-separated syntax"));
+        assert_eq!(error("rgb(255, none, 0)"),
+                   (9, "'none' is not allowed in comma-separated syntax"));
+        assert_eq!(error("rgb(255 0)"), (9, "expected three values"));
+        assert_eq!(error("rgb(1 2 3 4)"), (10, "too many values"));
+        assert_eq!(error("rgb(1, 2 3)"), (9, "expected three values"));
+        assert_eq!(error("rgb(1 2, 3)"), (7, "cannot mix commas and spaces between values"));
+        assert_eq!(error("rgb(1 2 3"), (9, "expected ')'"));
+        assert_eq!(error("rgb(1 2 3deg)"), (8, "unexpected unit"));
+        assert_eq!(error("rgb(1 2 red)"), (8, "unexpected identifier"));
+        assert_eq!(error("rgb(1 2 #3)"), (8, "expected a number"));
+        assert_eq!(error("rgb(1 2 3%4)"), (10, "expected whitespace between values"));
+        assert_eq!(error("rgb(1 2 .)"), (8, "invalid number"));
+        assert_eq!(error("rgb 1 2 3"), (0, "unknown color name"));
+    }
+
+    #[test]
+    fn test_hsl() {
+        match parse("hsl(120, 50%, 25%)") {
+            CssColor::Hsl(color) => assert_eq!(color.to_tuple(), (Deg(120.0), 0.5, 0.25)),
+            other => panic!("unexpected {:?}", other),
+        }
+        match parse("hsla(-90deg 100 50 / 40%)") {
+            CssColor::Hsla(color) => {
+                assert_eq!(color.to_tuple(), ((Deg(270.0), 1.0, 0.5), 0.4));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        match parse("hsl(0.5turn 10% 120%)") {
+            CssColor::Hsl(color) => assert_eq!(color.to_tuple(), (Deg(180.0), 0.1, 1.0)),
+            other => panic!("unexpected {:?}", other),
+        }
+        match parse("hsl(200grad 0% 0%)") {
+            CssColor::Hsl(color) => assert_relative_eq!(color.hue(), Deg(180.0)),
+            other => panic!("unexpected {:?}", other),
+        }
+        match parse("hsl(3.14159265358979rad 0% 0%)") {
+            CssColor::Hsl(color) => assert_relative_eq!(color.hue(), Deg(180.0), epsilon = 1e-6),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        assert_eq!(error("hsl(120, 50, 25%)"), (9, "expected a percentage"));
+        assert_eq!(error("hsl(120% 50% 25%)"), (4, "expected a hue"));
+        assert_eq!(error("hsl(120foo 50% 25%)"), (4, "unknown angle unit"));
+    }
+
+    #[test]
+    fn test_hwb() {
+        match parse("hwb(90 20% 30%)") {
+            CssColor::Hwb(color) => assert_eq!(color.to_tuple(), (Deg(90.0), 0.2, 0.3)),
+            other => panic!("unexpected {:?}", other),
+        }
+        match parse("hwb(90 20% 30% / 0)") {
+            CssColor::Hwba(color) => assert_eq!(color.to_tuple(), ((Deg(90.0), 0.2, 0.3), 0.0)),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(error("hwb(90, 20%, 30%)"),
+                   (6, "comma-separated syntax is not allowed here"));
+    }
+
+    #[test]
+    fn test_lab() {
+        match parse("lab(50% 40 -100%)") {
+            CssColor::Lab(color) => assert_eq!(color.to_tuple(), (50.0, 40.0, -125.0)),
+            other => panic!("unexpected {:?}", other),
+        }
+        match parse("lab(120 0 0 / 0.25)") {
+            CssColor::Laba(color) => assert_eq!(color.to_tuple(), ((100.0, 0.0, 0.0), 0.25)),
+            other => panic!("unexpected {:?}", other),
+        }
+        match parse("lch(29.2345% 44.2 27)") {
+            CssColor::Lchab(color) => {
+                assert_eq!(color.to_tuple(), (29.2345, 44.2, Deg(27.0)));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        match parse("lch(50 -10 none / 50%)") {
+            CssColor::Lchaba(color) => {
+                assert_eq!(color.to_tuple(), ((50.0, 0.0, Deg(0.0)), 0.5));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(error("lab(50, 40, 30)"), (6, "comma-separated syntax is not allowed here"));
+    }
+
+    #[test]
+    fn test_oklab() {
+        match parse("oklab(40.1% 0.1143 -50%)") {
+            CssColor::Oklab(color) => {
+                let (l, a, b) = color.to_tuple();
+                assert_relative_eq!(l, 0.401, epsilon = 1e-12);
+                assert_relative_eq!(a, 0.1143, epsilon = 1e-12);
+                assert_relative_eq!(b, -0.2, epsilon = 1e-12);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        match parse("oklch(0.7 100% 240 / 0.1)") {
+            CssColor::Oklcha(color) => {
+                assert_eq!(color.to_tuple(), ((0.7, 0.4, Deg(240.0)), 0.1));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        match parse("OKLCH(1.5 0.1 0)") {
+            CssColor::Oklch(color) => assert_eq!(color.L(), 1.0),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_color_function() {
+        match parse("color(display-p3 1 0.5 50%)") {
+            CssColor::DisplayP3(color) => assert_eq!(color.to_tuple(), (1.0, 0.5, 0.5)),
+            other => panic!("unexpected {:?}", other),
+        }
+        match parse("color( Display-P3 1.2 0 -0.1 / 0.5 )") {
+            CssColor::DisplayP3a(color) => {
+                assert_eq!(color.to_tuple(), ((1.2, 0.0, -0.1), 0.5));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(error("color(xyz-d65 1 0 0)"), (6, "unsupported color space"));
+        assert_eq!(error("color(1 0 0)"), (6, "expected a color space"));
+    }
+
+    #[test]
+    fn test_keywords() {
+        assert_eq!(rgb_tuple("red"), (1.0, 0.0, 0.0));
+        assert_eq!(rgb_tuple(" RebeccaPurple "), (0.4, 0.2, 0.6));
+        assert_eq!(rgba_tuple("transparent"), ((0.0, 0.0, 0.0), 0.0));
+        assert_eq!(error("bluish"), (0, "unknown color name"));
+        assert_eq!(error("white smoke"), (6, "unexpected characters after the color"));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(error(""), (0, "empty color string"));
+        assert_eq!(error("   "), (3, "empty color string"));
+        assert_eq!(error("(1 2 3)"), (0, "expected a color"));
+        assert_eq!(error("  cmyk(1 2 3 4)"), (2, "unknown color function"));
+        assert_eq!(error("rgb(1 2 3 / )"), (12, "expected a number"));
+
+        let err = "rgb(1 2)".parse::<CssColor<f32>>().unwrap_err();
+        assert_eq!(format!("{}", err),
+                   "invalid CSS color at position 7: expected three values");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let color: CssColor<f32> = "rgb(0 0 0 / 1)".parse().unwrap();
+        let black = Rgb::from_channels(0.0, 0.0, 0.0);
+        assert_eq!(color, CssColor::Rgba(Alpha::from_color_and_alpha(black, 1.0)));
+    }
+}
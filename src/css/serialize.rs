@@ -0,0 +1,458 @@
+use num;
+use angle::{Angle, Deg, IntoAngle};
+use alpha::Alpha;
+use color::Color;
+use channel::{AngularChannelScalar, ChannelFormatCast, FreeChannelScalar,
+              PosNormalChannelScalar};
+use encoding::{EncodedColor, SrgbEncoding};
+use rgb::Rgb;
+use hsl::Hsl;
+use hwb::Hwb;
+use lab::Lab;
+use lchab::Lchab;
+use oklab::Oklab;
+use oklch::Oklch;
+use css::CssColor;
+
+/// The notation used when writing a color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CssSyntax {
+    /// Hex notation in its shortest form, for sRGB colors. Other colors use `Modern`.
+    Hex,
+    /// Comma-separated `rgb()`, `rgba()`, `hsl()` and `hsla()`. Other colors use `Modern`.
+    Legacy,
+    /// Space-separated functional notation, with `/ alpha` when there is an alpha channel.
+    Modern,
+}
+
+/// Options controlling how colors are written as CSS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CssOptions {
+    syntax: CssSyntax,
+    precision: usize,
+}
+
+impl CssOptions {
+    /// Construct options with a syntax and the maximum number of decimal places for numbers.
+    pub fn new(syntax: CssSyntax, precision: usize) -> Self {
+        CssOptions { syntax, precision }
+    }
+
+    pub fn with_syntax(self, syntax: CssSyntax) -> Self {
+        CssOptions { syntax, ..self }
+    }
+    pub fn with_precision(self, precision: usize) -> Self {
+        CssOptions { precision, ..self }
+    }
+
+    pub fn syntax(&self) -> CssSyntax {
+        self.syntax
+    }
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+}
+
+impl Default for CssOptions {
+    fn default() -> Self {
+        CssOptions::new(CssSyntax::Modern, 3)
+    }
+}
+
+/// Colors that can be written as CSS color strings.
+pub trait ToCss {
+    fn to_css(&self, options: &CssOptions) -> String;
+
+    /// Write the color with the default options: modern syntax and three decimal places.
+    fn to_css_string(&self) -> String {
+        self.to_css(&CssOptions::default())
+    }
+}
+
+/// The predefined RGB color spaces of the CSS `color()` function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CssColorSpace {
+    Srgb,
+    SrgbLinear,
+    DisplayP3,
+    A98Rgb,
+    ProPhotoRgb,
+    Rec2020,
+}
+
+impl CssColorSpace {
+    /// The identifier of the color space in `color()`.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            CssColorSpace::Srgb => "srgb",
+            CssColorSpace::SrgbLinear => "srgb-linear",
+            CssColorSpace::DisplayP3 => "display-p3",
+            CssColorSpace::A98Rgb => "a98-rgb",
+            CssColorSpace::ProPhotoRgb => "prophoto-rgb",
+            CssColorSpace::Rec2020 => "rec2020",
+        }
+    }
+
+    /// Write channels already encoded in this color space with `color()`.
+    ///
+    /// Channels are not clamped, so colors outside the gamut of the space are preserved.
+    pub fn format_color<T>(&self, color: &Rgb<T>, alpha: Option<T>, options: &CssOptions) -> String
+        where T: PosNormalChannelScalar + num::Float
+    {
+        let mut values = vec![self.name().to_string()];
+        values.extend([color.red(), color.green(), color.blue()]
+            .iter()
+            .map(|&val| number(to_f64(val), options.precision)));
+        modern("color", &values, alpha.map(to_f64), options)
+    }
+}
+
+fn to_f64<T: num::Float>(val: T) -> f64 {
+    val.to_f64().unwrap()
+}
+
+/// Format a number with at most `precision` decimal places and no trailing zeros.
+fn number(val: f64, precision: usize) -> String {
+    let text = format!("{:.*}", precision, val);
+    let text = if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &text
+    };
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+fn percentage(val: f64, precision: usize) -> String {
+    number(val * 100.0, precision) + "%"
+}
+
+fn degrees<T, A>(hue: A, precision: usize) -> String
+    where T: num::Float,
+          A: IntoAngle<Deg<T>, OutputScalar = T>
+{
+    let text = number(to_f64(Angle::normalize(hue.into_angle()).scalar()), precision);
+    // A hue just below a full turn rounds up to it.
+    if text == "360" {
+        "0".to_string()
+    } else {
+        text
+    }
+}
+
+fn modern(function: &str, values: &[String], alpha: Option<f64>, options: &CssOptions) -> String {
+    match alpha {
+        Some(alpha) => {
+            format!("{}({} / {})",
+                    function,
+                    values.join(" "),
+                    number(alpha.clamp(0.0, 1.0), options.precision))
+        }
+        None => format!("{}({})", function, values.join(" ")),
+    }
+}
+
+fn legacy(function: &str, values: &[String], alpha: Option<f64>, options: &CssOptions) -> String {
+    match alpha {
+        Some(alpha) => {
+            format!("{}a({}, {})",
+                    function,
+                    values.join(", "),
+                    number(alpha.clamp(0.0, 1.0), options.precision))
+        }
+        None => format!("{}({})", function, values.join(", ")),
+    }
+}
+
+fn write_rgb<T>(color: &Rgb<T>, alpha: Option<T>, options: &CssOptions) -> String
+    where T: PosNormalChannelScalar + num::Float
+{
+    let alpha = alpha.map(to_f64);
+    let channels: Vec<f64> = [color.red(), color.green(), color.blue()]
+        .iter()
+        .map(|&val| to_f64(val).clamp(0.0, 1.0))
+        .collect();
+
+    match options.syntax {
+        CssSyntax::Hex => {
+            let bytes: Vec<u8> = channels.iter()
+                .chain(&alpha)
+                .map(|&val| (val.clamp(0.0, 1.0) * 255.0).round() as u8)
+                .collect();
+            let short = bytes.iter().all(|&byte| byte % 17 == 0);
+            bytes.iter().fold("#".to_string(), |hex, &byte| if short {
+                hex + &format!("{:x}", byte / 17)
+            } else {
+                hex + &format!("{:02x}", byte)
+            })
+        }
+        CssSyntax::Legacy | CssSyntax::Modern => {
+            let values: Vec<String> =
+                channels.iter().map(|&val| number(val * 255.0, options.precision)).collect();
+            if options.syntax == CssSyntax::Legacy {
+                legacy("rgb", &values, alpha, options)
+            } else {
+                modern("rgb", &values, alpha, options)
+            }
+        }
+    }
+}
+
+fn write_hsl<T, A>(color: &Hsl<T, A>, alpha: Option<T>, options: &CssOptions) -> String
+    where T: PosNormalChannelScalar + num::Float,
+          A: AngularChannelScalar + IntoAngle<Deg<T>, OutputScalar = T>
+{
+    let values = [degrees(color.hue(), options.precision),
+                  percentage(to_f64(color.saturation()), options.precision),
+                  percentage(to_f64(color.lightness()), options.precision)];
+    if options.syntax == CssSyntax::Legacy {
+        legacy("hsl", &values, alpha.map(to_f64), options)
+    } else {
+        modern("hsl", &values, alpha.map(to_f64), options)
+    }
+}
+
+fn write_hwb<T, A>(color: &Hwb<T, A>, alpha: Option<T>, options: &CssOptions) -> String
+    where T: PosNormalChannelScalar + num::Float,
+          A: AngularChannelScalar + IntoAngle<Deg<T>, OutputScalar = T>
+{
+    let values = [degrees(color.hue(), options.precision),
+                  percentage(to_f64(color.whiteness()), options.precision),
+                  percentage(to_f64(color.blackness()), options.precision)];
+    modern("hwb", &values, alpha.map(to_f64), options)
+}
+
+fn write_lab<T>(function: &str, lab: (T, T, T), alpha: Option<T>, options: &CssOptions) -> String
+    where T: FreeChannelScalar
+{
+    let values: Vec<String> = [lab.0, lab.1, lab.2]
+        .iter()
+        .map(|&val| number(to_f64(val), options.precision))
+        .collect();
+    modern(function, &values, alpha.map(to_f64), options)
+}
+
+fn write_lch<T, A>(function: &str, lch: (T, T, A), alpha: Option<T>, options: &CssOptions) -> String
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + IntoAngle<Deg<T>, OutputScalar = T>
+{
+    let values = [number(to_f64(lch.0), options.precision),
+                  number(to_f64(lch.1), options.precision),
+                  degrees(lch.2, options.precision)];
+    modern(function, &values, alpha.map(to_f64), options)
+}
+
+/// Implement `ToCss` for a color and for the color wrapped in `Alpha`.
+macro_rules! impl_to_css {
+    (impl<$($param:ident),*> $color:ty, where {$($bounds:tt)*},
+     |$c:ident, $a:ident, $o:ident| $body:expr) => {
+        impl<$($param),*> ToCss for $color
+            where $($bounds)*
+        {
+            fn to_css(&self, options: &CssOptions) -> String {
+                let ($c, $a, $o) = (self, None, options);
+                $body
+            }
+        }
+
+        impl<$($param),*> ToCss for Alpha<T, $color>
+            where $($bounds)*
+        {
+            fn to_css(&self, options: &CssOptions) -> String {
+                let ($c, $a, $o) = (self.color(), Some(self.alpha()), options);
+                $body
+            }
+        }
+    };
+}
+
+impl_to_css!(impl<T> Rgb<T>, where {T: PosNormalChannelScalar + num::Float},
+    |color, alpha, options| write_rgb(color, alpha, options));
+
+impl_to_css!(impl<T, A> Hsl<T, A>,
+    where {T: PosNormalChannelScalar + num::Float,
+           A: AngularChannelScalar + IntoAngle<Deg<T>, OutputScalar = T>},
+    |color, alpha, options| write_hsl(color, alpha, options));
+
+impl_to_css!(impl<T, A> Hwb<T, A>,
+    where {T: PosNormalChannelScalar + num::Float,
+           A: AngularChannelScalar + IntoAngle<Deg<T>, OutputScalar = T>},
+    |color, alpha, options| write_hwb(color, alpha, options));
+
+impl_to_css!(impl<T> Lab<T>, where {T: FreeChannelScalar + PosNormalChannelScalar},
+    |color, alpha, options| write_lab("lab", color.to_tuple(), alpha, options));
+
+impl_to_css!(impl<T, A> Lchab<T, A>,
+    where {T: FreeChannelScalar + PosNormalChannelScalar,
+           A: AngularChannelScalar + IntoAngle<Deg<T>, OutputScalar = T>},
+    |color, alpha, options| write_lch("lch", color.clone().to_tuple(), alpha, options));
+
+impl_to_css!(impl<T> Oklab<T>, where {T: FreeChannelScalar + PosNormalChannelScalar},
+    |color, alpha, options| write_lab("oklab", color.to_tuple(), alpha, options));
+
+impl_to_css!(impl<T, A> Oklch<T, A>,
+    where {T: FreeChannelScalar + PosNormalChannelScalar,
+           A: AngularChannelScalar + IntoAngle<Deg<T>, OutputScalar = T>},
+    |color, alpha, options| write_lch("oklch", color.clone().to_tuple(), alpha, options));
+
+// Encoded RGB colors say how their channels are encoded but not which primaries they use.
+// The sRGB curve is taken to mean sRGB, as it is for plain `Rgb`, but linear colors could be
+// in any space, so they and colors sharing the sRGB curve with other primaries, such as
+// Display P3, must be written with `CssColorSpace::format_color` and an explicit space.
+impl_to_css!(impl<T> EncodedColor<Rgb<T>, SrgbEncoding>,
+    where {T: PosNormalChannelScalar + num::Float + ChannelFormatCast<f64>,
+           f64: ChannelFormatCast<T>},
+    |color, alpha, options| write_rgb(color.color(), alpha, options));
+
+impl<T> ToCss for CssColor<T>
+    where T: FreeChannelScalar + PosNormalChannelScalar
+{
+    fn to_css(&self, options: &CssOptions) -> String {
+        let display_p3 = CssColorSpace::DisplayP3;
+        match *self {
+            CssColor::Rgb(ref color) => color.to_css(options),
+            CssColor::Rgba(ref color) => color.to_css(options),
+            CssColor::Hsl(ref color) => color.to_css(options),
+            CssColor::Hsla(ref color) => color.to_css(options),
+            CssColor::Hwb(ref color) => color.to_css(options),
+            CssColor::Hwba(ref color) => color.to_css(options),
+            CssColor::Lab(ref color) => color.to_css(options),
+            CssColor::Laba(ref color) => color.to_css(options),
+            CssColor::Lchab(ref color) => color.to_css(options),
+            CssColor::Lchaba(ref color) => color.to_css(options),
+            CssColor::Oklab(ref color) => color.to_css(options),
+            CssColor::Oklaba(ref color) => color.to_css(options),
+            CssColor::Oklch(ref color) => color.to_css(options),
+            CssColor::Oklcha(ref color) => color.to_css(options),
+            CssColor::DisplayP3(ref color) => display_p3.format_color(color, None, options),
+            CssColor::DisplayP3a(ref color) => {
+                display_p3.format_color(color.color(), Some(color.alpha()), options)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use css::parse_css_color;
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number(1.0, 3), "1");
+        assert_eq!(number(0.12345, 3), "0.123");
+        assert_eq!(number(0.5, 0), "0");
+        assert_eq!(number(127.5, 1), "127.5");
+        assert_eq!(number(-0.0001, 2), "0");
+        assert_eq!(number(-12.30, 4), "-12.3");
+    }
+
+    #[test]
+    fn test_rgb() {
+        let hex = CssOptions::default().with_syntax(CssSyntax::Hex);
+        let legacy = CssOptions::default().with_syntax(CssSyntax::Legacy);
+
+        let orange = Rgb::from_channels(1.0, 0.5, 0.0);
+        assert_eq!(orange.to_css_string(), "rgb(255 127.5 0)");
+        assert_eq!(orange.to_css(&hex), "#ff8000");
+        assert_eq!(orange.to_css(&legacy), "rgb(255, 127.5, 0)");
+        assert_eq!(orange.to_css(&legacy.with_precision(0)), "rgb(255, 128, 0)");
+
+        let teal = Rgb::from_channels(0.0, 0.6, 0.6);
+        assert_eq!(teal.to_css(&hex), "#099");
+        let translucent = Alpha::from_color_and_alpha(teal, 0.4);
+        assert_eq!(translucent.to_css(&hex), "#0996");
+        assert_eq!(translucent.to_css_string(), "rgb(0 153 153 / 0.4)");
+        assert_eq!(translucent.to_css(&legacy), "rgba(0, 153, 153, 0.4)");
+        let half = Alpha::from_color_and_alpha(teal, 0.5);
+        assert_eq!(half.to_css(&hex), "#00999980");
+
+        let out_of_range = Rgb::from_channels(1.2f32, -0.1, 0.2);
+        assert_eq!(out_of_range.to_css(&hex), "#f03");
+        assert_eq!(out_of_range.to_css_string(), "rgb(255 0 51)");
+    }
+
+    #[test]
+    fn test_polar() {
+        let legacy = CssOptions::default().with_syntax(CssSyntax::Legacy);
+
+        let hsl = Hsl::from_channels(Deg(120.0), 0.5, 0.25);
+        assert_eq!(hsl.to_css_string(), "hsl(120 50% 25%)");
+        assert_eq!(hsl.to_css(&legacy), "hsl(120, 50%, 25%)");
+        assert_eq!(hsl.to_css(&CssOptions::default().with_syntax(CssSyntax::Hex)),
+                   "hsl(120 50% 25%)");
+        let hsla = Alpha::from_color_and_alpha(hsl, 0.75);
+        assert_eq!(hsla.to_css(&legacy), "hsla(120, 50%, 25%, 0.75)");
+
+        let turns = Hsl::from_channels(::angle::Turns(0.5), 0.123456, 1.0);
+        assert_eq!(turns.to_css(&CssOptions::new(CssSyntax::Modern, 2)),
+                   "hsl(180 12.35% 100%)");
+
+        let hwb = Hwb::from_channels(Deg(90.0), 0.2, 0.3);
+        assert_eq!(hwb.to_css(&legacy), "hwb(90 20% 30%)");
+        assert_eq!(Alpha::from_color_and_alpha(hwb, 0.0).to_css_string(),
+                   "hwb(90 20% 30% / 0)");
+
+        assert_eq!(Hsl::from_channels(Deg(-30.0), 0.5, 0.5).to_css_string(), "hsl(330 50% 50%)");
+        assert_eq!(Hsl::from_channels(Deg(720.0), 0.5, 0.5).to_css_string(), "hsl(0 50% 50%)");
+        assert_eq!(Hwb::from_channels(Deg(359.9999), 0.2, 0.3).to_css_string(),
+                   "hwb(0 20% 30%)");
+    }
+
+    #[test]
+    fn test_lab() {
+        let lab = Lab::from_channels(50.0, 40.0, -125.0);
+        assert_eq!(lab.to_css_string(), "lab(50 40 -125)");
+        assert_eq!(Alpha::from_color_and_alpha(lab, 0.25).to_css_string(),
+                   "lab(50 40 -125 / 0.25)");
+
+        let lch = Lchab::from_channels(29.23456, 44.2, Deg(27.0));
+        assert_eq!(lch.to_css_string(), "lch(29.235 44.2 27)");
+        assert_eq!(lch.to_css(&CssOptions::new(CssSyntax::Modern, 1)), "lch(29.2 44.2 27)");
+        assert_eq!(Lchab::from_channels(50.0, 20.0, Deg(-90.0)).to_css_string(),
+                   "lch(50 20 270)");
+    }
+
+    #[test]
+    fn test_encoded() {
+        let srgb = EncodedColor::new(Rgb::from_channels(1.0, 1.0, 1.0), SrgbEncoding::new());
+        assert_eq!(srgb.to_css(&CssOptions::default().with_syntax(CssSyntax::Hex)), "#fff");
+        assert_eq!(srgb.to_css_string(), "rgb(255 255 255)");
+
+        let linear = Rgb::from_channels(0.5, 0.25, 1.0);
+        assert_eq!(CssColorSpace::SrgbLinear.format_color(&linear, Some(0.5),
+                                                          &CssOptions::default()),
+                   "color(srgb-linear 0.5 0.25 1 / 0.5)");
+
+        let p3 = Rgb::from_channels(1.05, 0.123456, -0.01);
+        assert_eq!(CssColorSpace::DisplayP3.format_color(&p3, None, &CssOptions::default()),
+                   "color(display-p3 1.05 0.123 -0.01)");
+        assert_eq!(CssColorSpace::Rec2020.format_color(&p3, Some(0.3), &CssOptions::default()),
+                   "color(rec2020 1.05 0.123 -0.01 / 0.3)");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let strings = ["rgb(255 127.5 0)",
+                       "rgb(0 153 153 / 0.4)",
+                       "hsl(120 50% 25%)",
+                       "hwb(90 20% 30% / 0.5)",
+                       "lab(50 40 -125)",
+                       "lch(29.235 44.2 27 / 0)",
+                       "oklab(0.401 0.114 -0.2)",
+                       "oklch(0.7 0.4 240 / 0.1)",
+                       "color(display-p3 1.2 0 -0.1)",
+                       "color(display-p3 1 0.5 0.25 / 1)"];
+        for s in strings.iter() {
+            let color = parse_css_color::<f64>(s).unwrap();
+            assert_eq!(color.to_css_string(), *s);
+        }
+
+        let color = parse_css_color::<f32>("#FFCC0080").unwrap();
+        assert_eq!(color.to_css(&CssOptions::default().with_syntax(CssSyntax::Hex)),
+                   "#ffcc0080");
+    }
+}
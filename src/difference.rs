@@ -0,0 +1,473 @@
+//! Color difference metrics.
+//!
+//! `ColorDifference` gives the CIE 1976 Euclidean distance in `Lab`, `Lchab`, `Luv` and
+//! `Lchuv`, and ΔEz in `Jzazbz` and `JzCzhz`. `LabColorDifference` adds the later metrics
+//! defined on CIELAB: ΔE94, CIEDE2000 and CMC l:c.
+
+use num;
+use angle::{Angle, Deg, Rad, FromAngle};
+use channel::{AngularChannelScalar, FreeChannelScalar};
+use convert::FromColor;
+use lab::Lab;
+use lchab::Lchab;
+use luv::Luv;
+use lchuv::Lchuv;
+use jzazbz::Jzazbz;
+use jzczhz::JzCzhz;
+
+/// The application weights of ΔE94.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Cie94Application {
+    /// kL = 1, K1 = 0.045, K2 = 0.015.
+    GraphicArts,
+    /// kL = 2, K1 = 0.048, K2 = 0.014.
+    Textiles,
+}
+
+impl Cie94Application {
+    /// Get the (kL, K1, K2) weights.
+    pub fn weights<T: num::Float>(&self) -> (T, T, T) {
+        let (k_l, k_1, k_2) = match *self {
+            Cie94Application::GraphicArts => (1.0, 0.045, 0.015),
+            Cie94Application::Textiles => (2.0, 0.048, 0.014),
+        };
+        (num::cast(k_l).unwrap(), num::cast(k_1).unwrap(), num::cast(k_2).unwrap())
+    }
+}
+
+/// The parametric weights kL, kC and kH of CIEDE2000.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ciede2000Weights<T> {
+    pub lightness: T,
+    pub chroma: T,
+    pub hue: T,
+}
+
+impl<T: num::Float> Ciede2000Weights<T> {
+    pub fn new(lightness: T, chroma: T, hue: T) -> Self {
+        Ciede2000Weights {
+            lightness,
+            chroma,
+            hue,
+        }
+    }
+}
+
+impl<T: num::Float> Default for Ciede2000Weights<T> {
+    fn default() -> Self {
+        Ciede2000Weights::new(T::one(), T::one(), T::one())
+    }
+}
+
+/// The lightness and chroma weights l and c of CMC l:c.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CmcWeights<T> {
+    pub lightness: T,
+    pub chroma: T,
+}
+
+impl<T: num::Float> CmcWeights<T> {
+    pub fn new(lightness: T, chroma: T) -> Self {
+        CmcWeights { lightness, chroma }
+    }
+
+    /// CMC 2:1, used for acceptability.
+    pub fn acceptability() -> Self {
+        CmcWeights::new(num::cast(2.0).unwrap(), T::one())
+    }
+
+    /// CMC 1:1, used for perceptibility.
+    pub fn perceptibility() -> Self {
+        CmcWeights::new(T::one(), T::one())
+    }
+}
+
+/// The CIE 1976 color difference, the Euclidean distance in a uniform color space.
+pub trait ColorDifference<T> {
+    /// Get ΔE*ab for CIELAB colors, ΔE*uv for CIELUV colors, or ΔEz for Jzazbz colors.
+    fn delta_e(&self, other: &Self) -> T;
+}
+
+/// Color difference metrics defined on CIELAB.
+///
+/// ΔE94 and CMC l:c are not symmetric: `self` is the reference color and `other` the sample.
+pub trait LabColorDifference<T>: ColorDifference<T> {
+    /// Get ΔE94 (CIE 1994) with the weights of an application.
+    fn delta_e_94(&self, other: &Self, application: Cie94Application) -> T;
+
+    /// Get ΔE00 (CIEDE2000) with parametric weights.
+    fn delta_e_2000_weighted(&self, other: &Self, weights: &Ciede2000Weights<T>) -> T;
+
+    /// Get ΔE00 (CIEDE2000) with kL = kC = kH = 1.
+    fn delta_e_2000(&self, other: &Self) -> T
+        where T: num::Float
+    {
+        self.delta_e_2000_weighted(other, &Ciede2000Weights::default())
+    }
+
+    /// Get ΔE CMC l:c.
+    fn delta_e_cmc(&self, other: &Self, weights: &CmcWeights<T>) -> T;
+}
+
+impl<T> ColorDifference<T> for Lab<T>
+    where T: FreeChannelScalar
+{
+    fn delta_e(&self, other: &Self) -> T {
+        euclidean([self.L(), self.a(), self.b()], [other.L(), other.a(), other.b()])
+    }
+}
+
+impl<T> LabColorDifference<T> for Lab<T>
+    where T: FreeChannelScalar
+{
+    fn delta_e_94(&self, other: &Self, application: Cie94Application) -> T {
+        let (k_l, k_1, k_2) = application.weights::<T>();
+        let (delta_l, delta_c, delta_h_sq) = lch_deltas(self, other);
+        let chroma = chroma(self);
+        let s_c = T::one() + k_1 * chroma;
+        let s_h = T::one() + k_2 * chroma;
+
+        ((delta_l / k_l).powi(2) + (delta_c / s_c).powi(2) + delta_h_sq / s_h.powi(2)).sqrt()
+    }
+
+    fn delta_e_2000_weighted(&self, other: &Self, weights: &Ciede2000Weights<T>) -> T {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        let two = c(2.0);
+        let pow25_7 = c(25.0f64.powi(7));
+
+        // Rescale a* so that neutral colors get a larger weight.
+        let mean_chroma = (chroma(self) + chroma(other)) / two;
+        let g = c(0.5) *
+                (T::one() - (mean_chroma.powi(7) / (mean_chroma.powi(7) + pow25_7)).sqrt());
+        let prime = |lab: &Lab<T>| {
+            let a = lab.a() * (T::one() + g);
+            let chroma = a.hypot(lab.b());
+            let hue = if chroma == T::zero() {
+                T::zero()
+            } else {
+                Angle::normalize(Deg::from_angle(Rad::atan2(lab.b(), a))).scalar()
+            };
+            (chroma, hue)
+        };
+        let (c1, h1) = prime(self);
+        let (c2, h2) = prime(other);
+
+        let delta_l = other.L() - self.L();
+        let delta_c = c2 - c1;
+        let full_turn = c(360.0);
+        let half_turn = c(180.0);
+        let delta_h_angle = if c1 * c2 == T::zero() {
+            T::zero()
+        } else if (h2 - h1).abs() <= half_turn {
+            h2 - h1
+        } else if h2 - h1 > half_turn {
+            h2 - h1 - full_turn
+        } else {
+            h2 - h1 + full_turn
+        };
+        let delta_h = two * (c1 * c2).sqrt() * Deg(delta_h_angle / two).sin();
+
+        let mean_l = (self.L() + other.L()) / two;
+        let mean_c = (c1 + c2) / two;
+        let mean_h = if c1 * c2 == T::zero() {
+            h1 + h2
+        } else if (h1 - h2).abs() <= half_turn {
+            (h1 + h2) / two
+        } else if h1 + h2 < full_turn {
+            (h1 + h2 + full_turn) / two
+        } else {
+            (h1 + h2 - full_turn) / two
+        };
+
+        let t = T::one() - c(0.17) * Deg(mean_h - c(30.0)).cos() +
+                c(0.24) * Deg(two * mean_h).cos() +
+                c(0.32) * Deg(c(3.0) * mean_h + c(6.0)).cos() -
+                c(0.20) * Deg(c(4.0) * mean_h - c(63.0)).cos();
+        let mean_l_sq = (mean_l - c(50.0)).powi(2);
+        let s_l = T::one() + c(0.015) * mean_l_sq / (c(20.0) + mean_l_sq).sqrt();
+        let s_c = T::one() + c(0.045) * mean_c;
+        let s_h = T::one() + c(0.015) * mean_c * t;
+
+        // The rotation term corrects the tilted ellipses in the blue region.
+        let delta_theta = c(30.0) * (-((mean_h - c(275.0)) / c(25.0)).powi(2)).exp();
+        let r_c = two * (mean_c.powi(7) / (mean_c.powi(7) + pow25_7)).sqrt();
+        let r_t = -r_c * Deg(two * delta_theta).sin();
+
+        let l_term = delta_l / (weights.lightness * s_l);
+        let c_term = delta_c / (weights.chroma * s_c);
+        let h_term = delta_h / (weights.hue * s_h);
+        (l_term.powi(2) + c_term.powi(2) + h_term.powi(2) + r_t * c_term * h_term).sqrt()
+    }
+
+    fn delta_e_cmc(&self, other: &Self, weights: &CmcWeights<T>) -> T {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        let (delta_l, delta_c, delta_h_sq) = lch_deltas(self, other);
+        let lightness = self.L();
+        let chroma = chroma(self);
+        let hue = Angle::normalize(Deg::from_angle(Rad::atan2(self.b(), self.a()))).scalar();
+
+        let s_l = if lightness < c(16.0) {
+            c(0.511)
+        } else {
+            c(0.040975) * lightness / (T::one() + c(0.01765) * lightness)
+        };
+        let s_c = c(0.0638) * chroma / (T::one() + c(0.0131) * chroma) + c(0.638);
+        let t = if hue >= c(164.0) && hue <= c(345.0) {
+            c(0.56) + (c(0.2) * Deg(hue + c(168.0)).cos()).abs()
+        } else {
+            c(0.36) + (c(0.4) * Deg(hue + c(35.0)).cos()).abs()
+        };
+        let f = (chroma.powi(4) / (chroma.powi(4) + c(1900.0))).sqrt();
+        let s_h = s_c * (f * t + T::one() - f);
+
+        ((delta_l / (weights.lightness * s_l)).powi(2) +
+         (delta_c / (weights.chroma * s_c)).powi(2) + delta_h_sq / s_h.powi(2))
+            .sqrt()
+    }
+}
+
+impl<T, A> ColorDifference<T> for Lchab<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn delta_e(&self, other: &Self) -> T {
+        Lab::from_color(self).delta_e(&Lab::from_color(other))
+    }
+}
+
+impl<T, A> LabColorDifference<T> for Lchab<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn delta_e_94(&self, other: &Self, application: Cie94Application) -> T {
+        Lab::from_color(self).delta_e_94(&Lab::from_color(other), application)
+    }
+
+    fn delta_e_2000_weighted(&self, other: &Self, weights: &Ciede2000Weights<T>) -> T {
+        Lab::from_color(self).delta_e_2000_weighted(&Lab::from_color(other), weights)
+    }
+
+    fn delta_e_cmc(&self, other: &Self, weights: &CmcWeights<T>) -> T {
+        Lab::from_color(self).delta_e_cmc(&Lab::from_color(other), weights)
+    }
+}
+
+impl<T> ColorDifference<T> for Luv<T>
+    where T: FreeChannelScalar
+{
+    fn delta_e(&self, other: &Self) -> T {
+        euclidean([self.L(), self.u(), self.v()], [other.L(), other.u(), other.v()])
+    }
+}
+
+impl<T, A> ColorDifference<T> for Lchuv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn delta_e(&self, other: &Self) -> T {
+        Luv::from_color(self).delta_e(&Luv::from_color(other))
+    }
+}
+
+impl<T> ColorDifference<T> for Jzazbz<T>
+    where T: FreeChannelScalar
+{
+    fn delta_e(&self, other: &Self) -> T {
+        euclidean([self.Jz(), self.az(), self.bz()], [other.Jz(), other.az(), other.bz()])
+    }
+}
+
+impl<T, A> ColorDifference<T> for JzCzhz<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    /// ΔEz combines ΔJz, ΔCz and ΔHz = 2 √(Cz1 Cz2) sin(Δhz / 2), which is the distance
+    /// between the colors in `Jzazbz`.
+    fn delta_e(&self, other: &Self) -> T {
+        Jzazbz::from_color(self).delta_e(&Jzazbz::from_color(other))
+    }
+}
+
+fn euclidean<T: num::Float>(left: [T; 3], right: [T; 3]) -> T {
+    left.iter()
+        .zip(right.iter())
+        .fold(T::zero(), |sum, (&l, &r)| sum + (l - r) * (l - r))
+        .sqrt()
+}
+
+/// Split the difference of two Lab colors into ΔL*, ΔC*ab and ΔH*ab squared.
+fn lch_deltas<T: FreeChannelScalar>(reference: &Lab<T>, sample: &Lab<T>) -> (T, T, T) {
+    let delta_l = reference.L() - sample.L();
+    let delta_c = chroma(reference) - chroma(sample);
+    let delta_a = reference.a() - sample.a();
+    let delta_b = reference.b() - sample.b();
+    let delta_h_sq = (delta_a * delta_a + delta_b * delta_b - delta_c * delta_c).max(T::zero());
+    (delta_l, delta_c, delta_h_sq)
+}
+
+fn chroma<T: FreeChannelScalar>(lab: &Lab<T>) -> T {
+    lab.a().hypot(lab.b())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use xyz::Xyz;
+
+    /// The CIEDE2000 test data of Sharma, Wu and Dalal (2005).
+    static SHARMA_DATA: [([f64; 3], [f64; 3], f64); 34] =
+        [([50.0000, 2.6772, -79.7751], [50.0000, 0.0000, -82.7485], 2.0425),
+         ([50.0000, 3.1571, -77.2803], [50.0000, 0.0000, -82.7485], 2.8615),
+         ([50.0000, 2.8361, -74.0200], [50.0000, 0.0000, -82.7485], 3.4412),
+         ([50.0000, -1.3802, -84.2814], [50.0000, 0.0000, -82.7485], 1.0000),
+         ([50.0000, -1.1848, -84.8006], [50.0000, 0.0000, -82.7485], 1.0000),
+         ([50.0000, -0.9009, -85.5211], [50.0000, 0.0000, -82.7485], 1.0000),
+         ([50.0000, 0.0000, 0.0000], [50.0000, -1.0000, 2.0000], 2.3669),
+         ([50.0000, -1.0000, 2.0000], [50.0000, 0.0000, 0.0000], 2.3669),
+         ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0009], 7.1792),
+         ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0010], 7.1792),
+         ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0011], 7.2195),
+         ([50.0000, 2.4900, -0.0010], [50.0000, -2.4900, 0.0012], 7.2195),
+         ([50.0000, -0.0010, 2.4900], [50.0000, 0.0009, -2.4900], 4.8045),
+         ([50.0000, -0.0010, 2.4900], [50.0000, 0.0010, -2.4900], 4.8045),
+         ([50.0000, -0.0010, 2.4900], [50.0000, 0.0011, -2.4900], 4.7461),
+         ([50.0000, 2.5000, 0.0000], [50.0000, 0.0000, -2.5000], 4.3065),
+         ([50.0000, 2.5000, 0.0000], [73.0000, 25.0000, -18.0000], 27.1492),
+         ([50.0000, 2.5000, 0.0000], [61.0000, -5.0000, 29.0000], 22.8977),
+         ([50.0000, 2.5000, 0.0000], [56.0000, -27.0000, -3.0000], 31.9030),
+         ([50.0000, 2.5000, 0.0000], [58.0000, 24.0000, 15.0000], 19.4535),
+         ([50.0000, 2.5000, 0.0000], [50.0000, 3.1736, 0.5854], 1.0000),
+         ([50.0000, 2.5000, 0.0000], [50.0000, 3.2972, 0.0000], 1.0000),
+         ([50.0000, 2.5000, 0.0000], [50.0000, 1.8634, 0.5757], 1.0000),
+         ([50.0000, 2.5000, 0.0000], [50.0000, 3.2592, 0.3350], 1.0000),
+         ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.2644),
+         ([63.0109, -31.0961, -5.8663], [62.8187, -29.7946, -4.0864], 1.2630),
+         ([61.2901, 3.7196, -5.3901], [61.4292, 2.2480, -4.9620], 1.8731),
+         ([35.0831, -44.1164, 3.7933], [35.0232, -40.0716, 1.5901], 1.8645),
+         ([22.7233, 20.0904, -46.6940], [23.0331, 14.9730, -42.5619], 2.0373),
+         ([36.4612, 47.8580, 18.3852], [36.2715, 50.5065, 21.2231], 1.4146),
+         ([90.8027, -2.0831, 1.4410], [91.1528, -1.6435, 0.0447], 1.4441),
+         ([90.9257, -0.5406, -0.9208], [88.6381, -0.8985, -0.7239], 1.5381),
+         ([6.7747, -0.2908, -2.4247], [5.8714, -0.0985, -2.2286], 0.6377),
+         ([2.0776, 0.0795, -1.1350], [0.9033, -0.0636, -0.5514], 0.9082)];
+
+    fn lab(values: [f64; 3]) -> Lab<f64> {
+        Lab::from_channels(values[0], values[1], values[2])
+    }
+
+    #[test]
+    fn test_ciede2000_sharma() {
+        for &(left, right, expected) in SHARMA_DATA.iter() {
+            let (left, right) = (lab(left), lab(right));
+            assert_relative_eq!(left.delta_e_2000(&right), expected, epsilon = 1e-4);
+            assert_relative_eq!(right.delta_e_2000(&left), expected, epsilon = 1e-4);
+        }
+
+        // The first pairs test hue discontinuities, which rounding in Lchab can cross.
+        for &(left, right, expected) in SHARMA_DATA[16..].iter() {
+            let left: Lchab<f64> = Lchab::from_color(&lab(left));
+            let right: Lchab<f64> = Lchab::from_color(&lab(right));
+            assert_relative_eq!(left.delta_e_2000(&right), expected, epsilon = 1e-4);
+        }
+
+        let f32_pair = (Lab::from_channels(50.0f32, 2.6772, -79.7751),
+                        Lab::from_channels(50.0f32, 0.0, -82.7485));
+        assert_relative_eq!(f32_pair.0.delta_e_2000(&f32_pair.1), 2.0425, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_ciede2000_weights() {
+        let left = lab([50.0, 2.5, 0.0]);
+        let right = lab([73.0, 25.0, -18.0]);
+        let unweighted = left.delta_e_2000(&right);
+        assert_relative_eq!(left.delta_e_2000_weighted(&right, &Ciede2000Weights::default()),
+                            unweighted);
+
+        // Only the lightness differs, so kL divides the difference directly.
+        let light = lab([40.0, 10.0, 10.0]);
+        let dark = lab([60.0, 10.0, 10.0]);
+        let weights = Ciede2000Weights::new(2.0, 1.0, 1.0);
+        assert_relative_eq!(light.delta_e_2000_weighted(&dark, &weights),
+                            light.delta_e_2000(&dark) / 2.0,
+                            epsilon = 1e-12);
+        assert!(left.delta_e_2000_weighted(&right, &weights) < unweighted);
+    }
+
+    #[test]
+    fn test_cie76() {
+        let left = lab([50.0, 2.6772, -79.7751]);
+        let right = lab([50.0, 0.0, -82.7485]);
+        assert_relative_eq!(left.delta_e(&right), 4.001063, epsilon = 1e-6);
+        assert_relative_eq!(left.delta_e(&left), 0.0);
+
+        let left_lch: Lchab<f64> = Lchab::from_color(&left);
+        let right_lch: Lchab<f64> = Lchab::from_color(&right);
+        assert_relative_eq!(left_lch.delta_e(&right_lch), 4.001063, epsilon = 1e-6);
+
+        let luv1 = Luv::from_channels(50.0, 10.0, -20.0);
+        let luv2 = Luv::from_channels(53.0, 14.0, -20.0);
+        assert_relative_eq!(luv1.delta_e(&luv2), 5.0, epsilon = 1e-12);
+        let lchuv1: Lchuv<f64> = Lchuv::from_color(&luv1);
+        let lchuv2: Lchuv<f64> = Lchuv::from_color(&luv2);
+        assert_relative_eq!(lchuv1.delta_e(&lchuv2), 5.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_cie94() {
+        let cases = [([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 1.395039, 1.423046),
+                     ([50.0, 2.5, 0.0], [73.0, 25.0, -18.0], 34.689163, 28.250263),
+                     ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.390995,
+                      1.389733),
+                     ([6.7747, -0.2908, -2.4247], [5.8714, -0.0985, -2.2286], 0.938533,
+                      0.518211)];
+        for &(reference, sample, graphic_arts, textiles) in cases.iter() {
+            let (reference, sample) = (lab(reference), lab(sample));
+            assert_relative_eq!(reference.delta_e_94(&sample, Cie94Application::GraphicArts),
+                                graphic_arts,
+                                epsilon = 1e-6);
+            assert_relative_eq!(reference.delta_e_94(&sample, Cie94Application::Textiles),
+                                textiles,
+                                epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_cmc() {
+        let cases = [([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 1.738736, 1.738736),
+                     ([50.0, 2.5, 0.0], [73.0, 25.0, -18.0], 37.923276, 42.108755),
+                     ([60.2574, -34.0099, 36.2677], [60.4626, -34.1751, 39.4387], 1.420486,
+                      1.428230),
+                     ([6.7747, -0.2908, -2.4247], [5.8714, -0.0985, -2.2286], 0.952801,
+                      1.803172),
+                     ([90.9257, -0.5406, -0.9208], [88.6381, -0.8985, -0.7239], 0.990070,
+                      1.702581)];
+        for &(reference, sample, acceptability, perceptibility) in cases.iter() {
+            let (reference, sample) = (lab(reference), lab(sample));
+            assert_relative_eq!(reference.delta_e_cmc(&sample, &CmcWeights::acceptability()),
+                                acceptability,
+                                epsilon = 1e-6);
+            assert_relative_eq!(reference.delta_e_cmc(&sample, &CmcWeights::perceptibility()),
+                                perceptibility,
+                                epsilon = 1e-6);
+        }
+
+        // The reference color sets the weighting functions, so swapping the colors matters.
+        let (reference, sample) = (lab([50.0, 2.5, 0.0]), lab([73.0, 25.0, -18.0]));
+        let weights = CmcWeights::acceptability();
+        assert!((reference.delta_e_cmc(&sample, &weights) -
+                 sample.delta_e_cmc(&reference, &weights))
+            .abs() > 1.0);
+    }
+
+    #[test]
+    fn test_jzazbz() {
+        let left = Jzazbz::from_xyz(&Xyz::from_channels(20.654008, 12.197225, 5.136952));
+        let right = Jzazbz::from_xyz(&Xyz::from_channels(30.0, 22.0, 50.0));
+        assert_relative_eq!(left.delta_e(&right), 0.103391, epsilon = 1e-6);
+        assert_relative_eq!(left.delta_e(&left), 0.0);
+
+        let left_lch: JzCzhz<f64> = JzCzhz::from_color(&left);
+        let right_lch: JzCzhz<f64> = JzCzhz::from_color(&right);
+        assert_relative_eq!(left_lch.delta_e(&right_lch), 0.103391, epsilon = 1e-6);
+    }
+}
@@ -32,6 +32,26 @@ pub struct SrgbEncoding {}
 pub struct LinearEncoding {}
 #[derive(Clone, Debug, PartialEq)]
 pub struct GammaEncoding<T>(pub T);
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Rec709Encoding {}
+#[derive(Clone, Debug, PartialEq)]
+pub struct PqEncoding<T> {
+    peak_luminance: T,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct HlgEncoding<T> {
+    nominal_peak_luminance: T,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bt1886Encoding<T> {
+    black_luminance: T,
+    white_luminance: T,
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParametricCurveEncoding<T> {
+    function_type: u16,
+    params: [T; 7],
+}
 
 impl SrgbEncoding {
     pub fn new() -> Self {
@@ -174,6 +194,548 @@ impl<T> fmt::Display for GammaEncoding<T>
     }
 }
 
+impl Rec709Encoding {
+    pub fn new() -> Self {
+        Rec709Encoding {}
+    }
+}
+
+impl ChannelDecoder for Rec709Encoding {
+    fn decode_channel<T>(&self, val: T) -> T
+        where T: num::Float
+    {
+        let one: T = num::cast(1.0).unwrap();
+        let a: T = num::cast(0.099).unwrap();
+        let k: T = num::cast(4.5).unwrap();
+        let gamma: T = num::cast(0.45).unwrap();
+        let linear_threshold: T = num::cast(0.081).unwrap();
+
+        if val.abs() < linear_threshold {
+            val / k
+        } else {
+            let operand = (val.abs() + a) / (one + a);
+            val.signum() * operand.powf(one / gamma)
+        }
+    }
+}
+
+impl ChannelEncoder for Rec709Encoding {
+    fn encode_channel<T>(&self, val: T) -> T
+        where T: num::Float
+    {
+        let one: T = num::cast(1.0).unwrap();
+        let a: T = num::cast(0.099).unwrap();
+        let k: T = num::cast(4.5).unwrap();
+        let gamma: T = num::cast(0.45).unwrap();
+        let linear_threshold: T = num::cast(0.018).unwrap();
+
+        if val.abs() < linear_threshold {
+            k * val
+        } else {
+            val.signum() * ((one + a) * val.abs().powf(gamma) - a)
+        }
+    }
+}
+
+impl ColorEncoding for Rec709Encoding {}
+
+impl fmt::Display for Rec709Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rec.709")
+    }
+}
+
+impl<T> PqEncoding<T>
+    where T: num::Float
+{
+    /// Construct a PQ encoding where a linear value of 1.0 maps to `peak_luminance` cd/m².
+    pub fn new(peak_luminance: T) -> Self {
+        PqEncoding { peak_luminance }
+    }
+
+    pub fn peak_luminance(&self) -> T {
+        self.peak_luminance
+    }
+
+    /// The absolute luminance ceiling of the SMPTE ST 2084 curve, in cd/m².
+    pub fn max_luminance() -> T {
+        num::cast(10000.0).unwrap()
+    }
+
+    fn constants<U>() -> (U, U, U, U, U)
+        where U: num::Float
+    {
+        (num::cast(2610.0 / 16384.0).unwrap(),
+         num::cast(2523.0 / 4096.0 * 128.0).unwrap(),
+         num::cast(3424.0 / 4096.0).unwrap(),
+         num::cast(2413.0 / 4096.0 * 32.0).unwrap(),
+         num::cast(2392.0 / 4096.0 * 32.0).unwrap())
+    }
+}
+
+impl<T> ChannelDecoder for PqEncoding<T>
+    where T: num::Float
+{
+    fn decode_channel<U>(&self, val: U) -> U
+        where U: num::Float
+    {
+        let (m1, m2, c1, c2, c3) = Self::constants::<U>();
+        let scale: U = num::cast(Self::max_luminance() / self.peak_luminance).unwrap();
+
+        let powed = val.abs().powf(U::one() / m2);
+        let numerator = (powed - c1).max(U::zero());
+        let y = (numerator / (c2 - c3 * powed)).powf(U::one() / m1);
+
+        val.signum() * y * scale
+    }
+}
+
+impl<T> ChannelEncoder for PqEncoding<T>
+    where T: num::Float
+{
+    fn encode_channel<U>(&self, val: U) -> U
+        where U: num::Float
+    {
+        let (m1, m2, c1, c2, c3) = Self::constants::<U>();
+        let scale: U = num::cast(self.peak_luminance / Self::max_luminance()).unwrap();
+
+        let y = (val.abs() * scale).powf(m1);
+        let encoded = ((c1 + c2 * y) / (U::one() + c3 * y)).powf(m2);
+
+        if val < U::zero() { -encoded } else { encoded }
+    }
+}
+
+impl<T: num::Float> ColorEncoding for PqEncoding<T> {}
+
+impl<T: num::Float> Default for PqEncoding<T> {
+    fn default() -> Self {
+        PqEncoding::new(Self::max_luminance())
+    }
+}
+
+impl<T> fmt::Display for PqEncoding<T>
+    where T: num::Float + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PQ({} cd/m²)", self.peak_luminance)
+    }
+}
+
+impl<T> HlgEncoding<T>
+    where T: num::Float
+{
+    /// Construct an HLG encoding for a display with the given nominal peak luminance in cd/m².
+    ///
+    /// The peak luminance only affects the OOTF; the OETF itself is scene-referred.
+    pub fn new(nominal_peak_luminance: T) -> Self {
+        HlgEncoding { nominal_peak_luminance }
+    }
+
+    pub fn nominal_peak_luminance(&self) -> T {
+        self.nominal_peak_luminance
+    }
+
+    /// The OOTF system gamma, 1.2 for a 1000 cd/m² display.
+    pub fn system_gamma(&self) -> T {
+        let base: T = num::cast(1.2).unwrap();
+        let slope: T = num::cast(0.42).unwrap();
+        let reference: T = num::cast(1000.0).unwrap();
+
+        base + slope * (self.nominal_peak_luminance / reference).log10()
+    }
+
+    /// Apply the BT.2100 OOTF to a scene-linear BT.2020 color.
+    ///
+    /// The result is display-linear light relative to the nominal peak luminance.
+    pub fn ootf<U>(&self, color: &Rgb<U>) -> Rgb<U>
+        where U: num::Float + PosNormalChannelScalar
+    {
+        let gamma: U = num::cast(self.system_gamma()).unwrap();
+        let luminance = Self::bt2020_luminance(color);
+        let gain = luminance.powf(gamma - U::one());
+
+        Rgb::from_channels(color.red() * gain, color.green() * gain, color.blue() * gain)
+    }
+
+    /// Invert the BT.2100 OOTF, going from display-linear to scene-linear light.
+    pub fn inverse_ootf<U>(&self, color: &Rgb<U>) -> Rgb<U>
+        where U: num::Float + PosNormalChannelScalar
+    {
+        let gamma: U = num::cast(self.system_gamma()).unwrap();
+        let luminance = Self::bt2020_luminance(color);
+        let gain = if luminance > U::zero() {
+            luminance.powf((U::one() - gamma) / gamma)
+        } else {
+            U::zero()
+        };
+
+        Rgb::from_channels(color.red() * gain, color.green() * gain, color.blue() * gain)
+    }
+
+    /// Decode a signal into display-linear light, applying the inverse OETF and then the OOTF.
+    pub fn decode_display<U>(&self, color: &Rgb<U>) -> Rgb<U>
+        where U: num::Float + PosNormalChannelScalar
+    {
+        let scene = Rgb::from_channels(self.decode_channel(color.red()),
+                                       self.decode_channel(color.green()),
+                                       self.decode_channel(color.blue()));
+        self.ootf(&scene)
+    }
+
+    /// Encode display-linear light, applying the inverse OOTF and then the OETF.
+    pub fn encode_display<U>(&self, color: &Rgb<U>) -> Rgb<U>
+        where U: num::Float + PosNormalChannelScalar
+    {
+        let scene = self.inverse_ootf(color);
+        Rgb::from_channels(self.encode_channel(scene.red()),
+                           self.encode_channel(scene.green()),
+                           self.encode_channel(scene.blue()))
+    }
+
+    fn bt2020_luminance<U>(color: &Rgb<U>) -> U
+        where U: num::Float + PosNormalChannelScalar
+    {
+        let kr: U = num::cast(0.2627).unwrap();
+        let kg: U = num::cast(0.6780).unwrap();
+        let kb: U = num::cast(0.0593).unwrap();
+
+        kr * color.red() + kg * color.green() + kb * color.blue()
+    }
+
+    fn constants<U>() -> (U, U, U)
+        where U: num::Float
+    {
+        let a: U = num::cast(0.17883277).unwrap();
+        let b = U::one() - num::cast::<_, U>(4.0).unwrap() * a;
+        let c = num::cast::<_, U>(0.5).unwrap() - a * (num::cast::<_, U>(4.0).unwrap() * a).ln();
+
+        (a, b, c)
+    }
+}
+
+impl<T> ChannelDecoder for HlgEncoding<T>
+    where T: num::Float
+{
+    fn decode_channel<U>(&self, val: U) -> U
+        where U: num::Float
+    {
+        let (a, b, c) = Self::constants::<U>();
+        let three: U = num::cast(3.0).unwrap();
+        let twelve: U = num::cast(12.0).unwrap();
+        let half: U = num::cast(0.5).unwrap();
+
+        let abs = val.abs();
+        let linear = if abs <= half {
+            abs * abs / three
+        } else {
+            (((abs - c) / a).exp() + b) / twelve
+        };
+
+        val.signum() * linear
+    }
+}
+
+impl<T> ChannelEncoder for HlgEncoding<T>
+    where T: num::Float
+{
+    fn encode_channel<U>(&self, val: U) -> U
+        where U: num::Float
+    {
+        let (a, b, c) = Self::constants::<U>();
+        let three: U = num::cast(3.0).unwrap();
+        let twelve: U = num::cast(12.0).unwrap();
+
+        let abs = val.abs();
+        let encoded = if abs <= U::one() / twelve {
+            (three * abs).sqrt()
+        } else {
+            a * (twelve * abs - b).ln() + c
+        };
+
+        val.signum() * encoded
+    }
+}
+
+impl<T: num::Float> ColorEncoding for HlgEncoding<T> {}
+
+impl<T: num::Float> Default for HlgEncoding<T> {
+    fn default() -> Self {
+        HlgEncoding::new(num::cast(1000.0).unwrap())
+    }
+}
+
+impl<T> fmt::Display for HlgEncoding<T>
+    where T: num::Float + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HLG({} cd/m²)", self.nominal_peak_luminance)
+    }
+}
+
+impl<T> Bt1886Encoding<T>
+    where T: num::Float
+{
+    /// Construct a BT.1886 encoding for a display with the given black and white luminance.
+    ///
+    /// Both luminances are in cd/m². Decoded values are relative to the white luminance,
+    /// so a signal of 1.0 always decodes to 1.0 and a signal of 0.0 decodes to the black level.
+    pub fn new(black_luminance: T, white_luminance: T) -> Self {
+        Bt1886Encoding {
+            black_luminance,
+            white_luminance,
+        }
+    }
+
+    pub fn black_luminance(&self) -> T {
+        self.black_luminance
+    }
+
+    pub fn white_luminance(&self) -> T {
+        self.white_luminance
+    }
+
+    /// Get the `a` (gain) and `b` (black lift) terms of the EOTF.
+    fn constants<U>(&self) -> (U, U, U)
+        where U: num::Float
+    {
+        let gamma: U = num::cast(2.4).unwrap();
+        let lb: U = num::cast(self.black_luminance).unwrap();
+        let lw: U = num::cast(self.white_luminance).unwrap();
+
+        let lb_root = lb.powf(gamma.recip());
+        let lw_root = lw.powf(gamma.recip());
+        let a = (lw_root - lb_root).powf(gamma);
+        let b = lb_root / (lw_root - lb_root);
+
+        (a, b, gamma)
+    }
+}
+
+impl<T> ChannelDecoder for Bt1886Encoding<T>
+    where T: num::Float
+{
+    fn decode_channel<U>(&self, val: U) -> U
+        where U: num::Float
+    {
+        let (a, b, gamma) = self.constants::<U>();
+        let lw: U = num::cast(self.white_luminance).unwrap();
+
+        a * (val + b).max(U::zero()).powf(gamma) / lw
+    }
+}
+
+impl<T> ChannelEncoder for Bt1886Encoding<T>
+    where T: num::Float
+{
+    fn encode_channel<U>(&self, val: U) -> U
+        where U: num::Float
+    {
+        let (a, b, gamma) = self.constants::<U>();
+        let lw: U = num::cast(self.white_luminance).unwrap();
+
+        (val.max(U::zero()) * lw / a).powf(gamma.recip()) - b
+    }
+}
+
+impl<T: num::Float> ColorEncoding for Bt1886Encoding<T> {}
+
+impl<T: num::Float> Default for Bt1886Encoding<T> {
+    fn default() -> Self {
+        Bt1886Encoding::new(num::cast(0.0).unwrap(), num::cast(100.0).unwrap())
+    }
+}
+
+impl<T> fmt::Display for Bt1886Encoding<T>
+    where T: num::Float + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BT.1886({}-{} cd/m²)", self.black_luminance, self.white_luminance)
+    }
+}
+
+impl<T> ParametricCurveEncoding<T>
+    where T: num::Float
+{
+    /// Construct a curve from an ICC `parametricCurveType` function type and its parameters.
+    ///
+    /// `params` holds the parameters in ICC order (g, a, b, c, d, e, f), and must contain
+    /// exactly as many as the function type uses. Returns `None` for an unknown function
+    /// type or the wrong number of parameters.
+    pub fn from_params(function_type: u16, params: &[T]) -> Option<Self> {
+        if Self::param_count(function_type) != Some(params.len()) {
+            return None;
+        }
+
+        let mut all = [T::zero(); 7];
+        all[..params.len()].copy_from_slice(params);
+        Some(ParametricCurveEncoding {
+            function_type,
+            params: all,
+        })
+    }
+
+    /// Y = X^g
+    pub fn gamma(g: T) -> Self {
+        Self::from_params(0, &[g]).unwrap()
+    }
+
+    /// Y = (aX + b)^g for X >= -b/a, otherwise 0 (CIE 122-1996).
+    pub fn cie_122(g: T, a: T, b: T) -> Self {
+        Self::from_params(1, &[g, a, b]).unwrap()
+    }
+
+    /// Y = (aX + b)^g + c for X >= -b/a, otherwise c (IEC 61966-3).
+    pub fn iec_61966_3(g: T, a: T, b: T, c: T) -> Self {
+        Self::from_params(2, &[g, a, b, c]).unwrap()
+    }
+
+    /// Y = (aX + b)^g for X >= d, otherwise cX (IEC 61966-2-1).
+    pub fn iec_61966_2_1(g: T, a: T, b: T, c: T, d: T) -> Self {
+        Self::from_params(3, &[g, a, b, c, d]).unwrap()
+    }
+
+    /// Y = (aX + b)^g + e for X >= d, otherwise cX + f.
+    #[allow(clippy::too_many_arguments)]
+    pub fn full(g: T, a: T, b: T, c: T, d: T, e: T, f: T) -> Self {
+        Self::from_params(4, &[g, a, b, c, d, e, f]).unwrap()
+    }
+
+    /// The IEC 61966-2-1 sRGB curve.
+    pub fn srgb() -> Self {
+        Self::iec_61966_2_1(num::cast(2.4).unwrap(),
+                            num::cast(1.0 / 1.055).unwrap(),
+                            num::cast(0.055 / 1.055).unwrap(),
+                            num::cast(1.0 / 12.92).unwrap(),
+                            num::cast(0.04045).unwrap())
+    }
+
+    /// The ROMM RGB (ProPhoto) curve, a 1.8 gamma with a short linear segment near black.
+    pub fn romm() -> Self {
+        Self::iec_61966_2_1(num::cast(1.8).unwrap(),
+                            num::cast(1.0).unwrap(),
+                            num::cast(0.0).unwrap(),
+                            num::cast(1.0 / 16.0).unwrap(),
+                            num::cast(1.0 / 32.0).unwrap())
+    }
+
+    /// The CIE L* curve, mapping lightness in [0, 1] to relative luminance.
+    pub fn lstar() -> Self {
+        Self::iec_61966_2_1(num::cast(3.0).unwrap(),
+                            num::cast(1.0 / 1.16).unwrap(),
+                            num::cast(0.16 / 1.16).unwrap(),
+                            num::cast(27.0 / 24389.0 * 100.0).unwrap(),
+                            num::cast(0.08).unwrap())
+    }
+
+    /// The ICC function type, from 0 to 4.
+    pub fn function_type(&self) -> u16 {
+        self.function_type
+    }
+
+    /// The parameters used by the function type, in ICC order.
+    pub fn params(&self) -> &[T] {
+        &self.params[..Self::param_count(self.function_type).unwrap()]
+    }
+
+    /// Get the number of parameters used by an ICC function type.
+    pub fn param_count(function_type: u16) -> Option<usize> {
+        match function_type {
+            0 => Some(1),
+            1 => Some(3),
+            2 => Some(4),
+            3 => Some(5),
+            4 => Some(7),
+            _ => None,
+        }
+    }
+
+    fn cast_params<U>(&self) -> [U; 7]
+        where U: num::Float
+    {
+        let mut out = [U::zero(); 7];
+        for (to, from) in out.iter_mut().zip(self.params.iter()) {
+            *to = num::cast(*from).unwrap();
+        }
+        out
+    }
+}
+
+impl<T> ChannelDecoder for ParametricCurveEncoding<T>
+    where T: num::Float
+{
+    fn decode_channel<U>(&self, val: U) -> U
+        where U: num::Float
+    {
+        let [g, a, b, c, d, e, f] = self.cast_params::<U>();
+        let power = |x: U| (a * x + b).max(U::zero()).powf(g);
+
+        match self.function_type {
+            0 => val.max(U::zero()).powf(g),
+            1 => if a * val + b >= U::zero() { power(val) } else { U::zero() },
+            2 => if a * val + b >= U::zero() { power(val) + c } else { c },
+            3 => if val >= d { power(val) } else { c * val },
+            _ => if val >= d { power(val) + e } else { c * val + f },
+        }
+    }
+}
+
+impl<T> ChannelEncoder for ParametricCurveEncoding<T>
+    where T: num::Float
+{
+    fn encode_channel<U>(&self, val: U) -> U
+        where U: num::Float
+    {
+        let [g, a, b, c, d, e, f] = self.cast_params::<U>();
+        let root = |y: U| (y.max(U::zero()).powf(g.recip()) - b) / a;
+
+        match self.function_type {
+            0 => val.max(U::zero()).powf(g.recip()),
+            1 => root(val),
+            2 => root(val - c),
+            _ => {
+                let (e, f) = if self.function_type == 3 {
+                    (U::zero(), U::zero())
+                } else {
+                    (e, f)
+                };
+                let threshold = (a * d + b).max(U::zero()).powf(g) + e;
+
+                if val >= threshold {
+                    root(val - e)
+                } else if c != U::zero() {
+                    (val - f) / c
+                } else {
+                    d
+                }
+            }
+        }
+    }
+}
+
+impl<T: num::Float> ColorEncoding for ParametricCurveEncoding<T> {}
+
+impl<T: num::Float> Default for ParametricCurveEncoding<T> {
+    fn default() -> Self {
+        ParametricCurveEncoding::gamma(num::cast(2.2).unwrap())
+    }
+}
+
+impl<T> fmt::Display for ParametricCurveEncoding<T>
+    where T: num::Float + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Parametric{}(", self.function_type)?;
+        for (i, param) in self.params().iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", param)?;
+        }
+        write!(f, ")")
+    }
+}
+
 impl<T> EncodableColor for Rgb<T>
     where T: PosNormalChannelScalar + ChannelFormatCast<f64>,
           f64: ChannelFormatCast<T>
@@ -281,4 +843,168 @@ mod test {
                             epsilon = 1e-6);
         assert_relative_eq!(t6.decode(), c6, epsilon = 1e-6);
     }
+
+    #[test]
+    fn test_rec709_encoding() {
+        let c1 = Rgb::from_channels(0.0, 0.0, 0.0).with_encoding(LinearEncoding::new());
+        let t1 = c1.clone().encode(Rec709Encoding::new());
+        assert_relative_eq!(t1.color(), c1.color(), epsilon = 1e-6);
+
+        let c2 = Rgb::from_channels(1.0, 1.0, 1.0).with_encoding(LinearEncoding::new());
+        let t2 = c2.clone().encode(Rec709Encoding::new());
+        assert_relative_eq!(t2.color(), c2.color(), epsilon = 1e-6);
+
+        let c3 = Rgb::from_channels(0.2, 0.8, 0.01).with_encoding(LinearEncoding::new());
+        let t3 = c3.clone().encode(Rec709Encoding::new());
+        assert_relative_eq!(*t3.color(),
+                            Rgb::from_channels(0.43367362, 0.89500412, 0.045),
+                            epsilon = 1e-6);
+        assert_relative_eq!(t3.decode(), c3, epsilon = 1e-6);
+
+        let c4 = Rgb::from_channels(0.5, 0.5, 0.5).with_encoding(Rec709Encoding::new());
+        let t4 = c4.clone().decode();
+        assert_relative_eq!(*t4.color(), Rgb::broadcast(0.25958940), epsilon = 1e-6);
+        assert_relative_eq!(t4.encode(Rec709Encoding::new()), c4, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_pq_encoding() {
+        let c1 = Rgb::from_channels(0.0, 0.01, 0.1).with_encoding(LinearEncoding::new());
+        let t1 = c1.clone().encode(PqEncoding::<f64>::default());
+        assert_relative_eq!(*t1.color(),
+                            Rgb::from_channels(0.00000073, 0.50807842, 0.75182710),
+                            epsilon = 1e-6);
+        assert_relative_eq!(t1.decode(), c1, epsilon = 1e-6);
+
+        let c2 = Rgb::from_channels(1.0, 0.1, 0.5).with_encoding(LinearEncoding::new());
+        let t2 = c2.clone().encode(PqEncoding::new(1000.0));
+        assert_relative_eq!(*t2.color(),
+                            Rgb::from_channels(0.75182710, 0.50807842, 0.67658481),
+                            epsilon = 1e-6);
+        assert_relative_eq!(t2.decode(), c2, epsilon = 1e-6);
+
+        let c3 = Rgb::from_channels(1.0, 0.0, -0.5).with_encoding(PqEncoding::new(10000.0));
+        let t3 = c3.clone().decode();
+        assert_relative_eq!(*t3.color(),
+                            Rgb::from_channels(1.0, 0.0, -0.00922457),
+                            epsilon = 1e-6);
+        assert_relative_eq!(t3.encode(PqEncoding::<f64>::default()), c3, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_hlg_encoding() {
+        let c1 = Rgb::from_channels(1.0 / 12.0, 1.0, 0.5).with_encoding(LinearEncoding::new());
+        let t1 = c1.clone().encode(HlgEncoding::<f64>::default());
+        assert_relative_eq!(*t1.color(),
+                            Rgb::from_channels(0.5, 1.0, 0.87164347),
+                            epsilon = 1e-6);
+        assert_relative_eq!(t1.decode(), c1, epsilon = 1e-6);
+
+        let c2 = Rgb::from_channels(0.02, 0.0, -0.25).with_encoding(LinearEncoding::new());
+        let t2 = c2.clone().encode(HlgEncoding::<f64>::default());
+        assert_relative_eq!(*t2.color(),
+                            Rgb::from_channels(0.24494897, 0.0, -0.73854927),
+                            epsilon = 1e-6);
+        assert_relative_eq!(t2.decode(), c2, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_hlg_ootf() {
+        let hlg = HlgEncoding::default();
+        assert_relative_eq!(hlg.system_gamma(), 1.2, epsilon = 1e-6);
+        assert_relative_eq!(HlgEncoding::new(2000.0).system_gamma(), 1.32643260, epsilon = 1e-6);
+
+        let c1 = Rgb::broadcast(0.5);
+        assert_relative_eq!(hlg.ootf(&c1), Rgb::broadcast(0.43527528), epsilon = 1e-6);
+        assert_relative_eq!(hlg.inverse_ootf(&hlg.ootf(&c1)), c1, epsilon = 1e-6);
+
+        let c2 = Rgb::from_channels(0.8, 0.3, 0.1);
+        let t2 = hlg.ootf(&c2);
+        assert_relative_eq!(t2,
+                            Rgb::from_channels(0.67241023, 0.25215383, 0.08405128),
+                            epsilon = 1e-6);
+        assert_relative_eq!(hlg.inverse_ootf(&t2), c2, epsilon = 1e-6);
+        assert_relative_eq!(hlg.inverse_ootf(&Rgb::broadcast(0.0)), Rgb::broadcast(0.0));
+
+        let signal = Rgb::from_channels(0.75, 0.5, 0.25);
+        let display = hlg.decode_display(&signal);
+        assert_relative_eq!(hlg.encode_display(&display), signal, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_bt1886_encoding() {
+        let enc = Bt1886Encoding::new(0.1, 100.0);
+        let c1 = Rgb::from_channels(0.0, 0.5, 1.0).with_encoding(enc.clone());
+        let t1 = c1.clone().decode();
+        assert_relative_eq!(*t1.color(),
+                            Rgb::from_channels(0.001, 0.21604911, 1.0),
+                            epsilon = 1e-6);
+        assert_relative_eq!(t1.encode(enc.clone()), c1, epsilon = 1e-6);
+        assert_relative_eq!(enc.decode_channel(-0.5), 0.0);
+
+        let pure = Bt1886Encoding::<f64>::default();
+        assert_relative_eq!(pure.decode_channel(0.5), 0.5f64.powf(2.4), epsilon = 1e-6);
+        assert_relative_eq!(pure.encode_channel(0.5f64.powf(2.4)), 0.5, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_parametric_curve_encoding() {
+        let srgb = ParametricCurveEncoding::<f64>::srgb();
+        for &val in [0.0, 0.02, 0.04045, 0.5, 1.0].iter() {
+            assert_relative_eq!(srgb.decode_channel(val),
+                                SrgbEncoding::new().decode_channel(val),
+                                epsilon = 1e-6);
+            assert_relative_eq!(srgb.encode_channel(srgb.decode_channel(val)),
+                                val,
+                                epsilon = 1e-6);
+        }
+
+        let gamma = ParametricCurveEncoding::gamma(563.0 / 256.0);
+        assert_relative_eq!(gamma.decode_channel(0.5),
+                            GammaEncoding::new(563.0 / 256.0).decode_channel(0.5),
+                            epsilon = 1e-6);
+        assert_relative_eq!(gamma.encode_channel(gamma.decode_channel(0.3)), 0.3, epsilon = 1e-6);
+
+        let cie = ParametricCurveEncoding::cie_122(2.0, 1.5, -0.5);
+        assert_relative_eq!(cie.decode_channel(0.2), 0.0);
+        assert_relative_eq!(cie.decode_channel(0.8), 0.49, epsilon = 1e-6);
+        assert_relative_eq!(cie.encode_channel(0.49), 0.8, epsilon = 1e-6);
+        assert_relative_eq!(cie.encode_channel(0.0), 1.0 / 3.0, epsilon = 1e-6);
+
+        let iec = ParametricCurveEncoding::iec_61966_3(2.0, 1.0, 0.0, 0.1);
+        assert_relative_eq!(iec.decode_channel(0.5), 0.35, epsilon = 1e-6);
+        assert_relative_eq!(iec.encode_channel(0.35), 0.5, epsilon = 1e-6);
+
+        let full = ParametricCurveEncoding::full(2.2, 0.9, 0.1, 0.5, 0.2, 0.05, 0.02);
+        assert_relative_eq!(full.decode_channel(0.1), 0.07, epsilon = 1e-6);
+        assert_relative_eq!(full.decode_channel(0.7), 0.55039192, epsilon = 1e-6);
+        assert_relative_eq!(full.encode_channel(0.07), 0.1, epsilon = 1e-6);
+        assert_relative_eq!(full.encode_channel(0.55039192), 0.7, epsilon = 1e-6);
+
+        let romm = ParametricCurveEncoding::<f64>::romm();
+        assert_relative_eq!(romm.decode_channel(0.02), 0.00125, epsilon = 1e-6);
+        assert_relative_eq!(romm.decode_channel(0.5), 0.28717459, epsilon = 1e-6);
+        assert_relative_eq!(romm.encode_channel(0.00125), 0.02, epsilon = 1e-6);
+
+        let lstar = ParametricCurveEncoding::<f64>::lstar();
+        assert_relative_eq!(lstar.decode_channel(0.5), 0.18418652, epsilon = 1e-6);
+        assert_relative_eq!(lstar.decode_channel(0.05), 0.05 * 27.0 / 243.89, epsilon = 1e-6);
+        assert_relative_eq!(lstar.encode_channel(0.18418652), 0.5, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_parametric_curve_params() {
+        let curve = ParametricCurveEncoding::iec_61966_3(2.0, 1.0, 0.0, 0.1);
+        assert_eq!(curve.function_type(), 2);
+        assert_eq!(curve.params(), &[2.0, 1.0, 0.0, 0.1]);
+        assert_eq!(ParametricCurveEncoding::from_params(2, curve.params()), Some(curve.clone()));
+        assert_eq!(ParametricCurveEncoding::from_params(2, &[2.0, 1.0]), None);
+        assert_eq!(ParametricCurveEncoding::<f64>::from_params(5, &[2.0]), None);
+        assert_eq!(format!("{}", curve), "Parametric2(2, 1, 0, 0.1)");
+
+        let srgb = ParametricCurveEncoding::<f64>::srgb();
+        let c1 = Rgb::from_channels(0.2, 0.4, 0.6).with_encoding(srgb);
+        let c2 = Rgb::from_channels(0.2, 0.4, 0.6).with_encoding(SrgbEncoding::new());
+        assert_relative_eq!(c1.decode(), c2.decode(), epsilon = 1e-6);
+    }
 }
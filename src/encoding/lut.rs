@@ -0,0 +1,318 @@
+use std::fmt;
+use num;
+use encoding::encode::{ChannelDecoder, ChannelEncoder, ColorEncoding};
+
+/// The minimum number of entries in the inverted table of a `LutEncoding`.
+const MIN_INVERSE_SIZE: usize = 4096;
+/// The number of bisection steps used when inverting a table.
+const INVERSION_ITERATIONS: usize = 48;
+
+/// How a `LutEncoding` interpolates between table entries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LutInterpolation {
+    /// Piecewise linear interpolation.
+    Linear,
+    /// Monotone cubic Hermite interpolation (Fritsch-Butland tangents).
+    ///
+    /// The curve is smooth, and never overshoots between two entries, so a monotonic table
+    /// stays monotonic.
+    MonotoneCubic,
+}
+
+/// An encoding defined by a sampled 1D transfer curve, such as an ICC `curv` tag or a
+/// measured display response.
+///
+/// The table maps encoded values, sampled uniformly over [0, 1], to linear values, so it
+/// is used directly for decoding. Encoding uses an inverted table computed when the
+/// encoding is constructed. Inputs outside [0, 1] are clamped.
+///
+/// Tables do not need to be monotonic. Decoding always follows the table as given, while
+/// the inverse is computed from a monotonic envelope of the table: the running maximum for
+/// a table that rises overall, or the running minimum for one that falls.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LutEncoding<T> {
+    table: Vec<T>,
+    inverse: Vec<T>,
+    inverse_range: (T, T),
+    interpolation: LutInterpolation,
+}
+
+impl<T> LutEncoding<T>
+    where T: num::Float
+{
+    /// Construct an encoding from a decoding table using linear interpolation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `table` has fewer than two entries.
+    pub fn new(table: Vec<T>) -> Self {
+        Self::with_interpolation(table, LutInterpolation::Linear)
+    }
+
+    /// Construct an encoding from a decoding table using the given interpolation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `table` has fewer than two entries.
+    pub fn with_interpolation(table: Vec<T>, interpolation: LutInterpolation) -> Self {
+        assert!(table.len() >= 2, "A lookup table needs at least two entries");
+
+        let (inverse, inverse_range) = invert_table(&table, interpolation);
+        LutEncoding {
+            table,
+            inverse,
+            inverse_range,
+            interpolation,
+        }
+    }
+
+    /// Build a table of `size` entries by sampling the decoding curve of another encoding.
+    pub fn sample<D>(decoder: &D, size: usize, interpolation: LutInterpolation) -> Self
+        where D: ChannelDecoder
+    {
+        let last: T = num::cast(size - 1).unwrap();
+        let table = (0..size)
+            .map(|i| decoder.decode_channel(num::cast::<_, T>(i).unwrap() / last))
+            .collect();
+        Self::with_interpolation(table, interpolation)
+    }
+
+    /// The decoding table, mapping encoded values to linear values.
+    pub fn table(&self) -> &[T] {
+        &self.table
+    }
+
+    /// The inverted table, mapping linear values in `inverse_range()` to encoded values.
+    pub fn inverse_table(&self) -> &[T] {
+        &self.inverse
+    }
+
+    /// The lowest and highest linear values covered by the inverted table.
+    pub fn inverse_range(&self) -> (T, T) {
+        self.inverse_range
+    }
+
+    pub fn interpolation(&self) -> LutInterpolation {
+        self.interpolation
+    }
+}
+
+impl<T> ChannelDecoder for LutEncoding<T>
+    where T: num::Float
+{
+    fn decode_channel<U>(&self, val: U) -> U
+        where U: num::Float
+    {
+        let pos: T = num::cast(val).unwrap();
+        num::cast(interpolate(&self.table, pos, self.interpolation)).unwrap()
+    }
+}
+
+impl<T> ChannelEncoder for LutEncoding<T>
+    where T: num::Float
+{
+    fn encode_channel<U>(&self, val: U) -> U
+        where U: num::Float
+    {
+        let (low, high) = self.inverse_range;
+        let val: T = num::cast(val).unwrap();
+        let pos = if high > low {
+            (val - low) / (high - low)
+        } else {
+            T::zero()
+        };
+
+        num::cast(interpolate(&self.inverse, pos, self.interpolation)).unwrap()
+    }
+}
+
+impl<T: num::Float> ColorEncoding for LutEncoding<T> {}
+
+impl<T> fmt::Display for LutEncoding<T>
+    where T: num::Float
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LUT({} entries)", self.table.len())
+    }
+}
+
+/// Evaluate a uniformly sampled table at `pos` in [0, 1], clamping outside that range.
+fn interpolate<T>(table: &[T], pos: T, interpolation: LutInterpolation) -> T
+    where T: num::Float
+{
+    let last = table.len() - 1;
+    let scaled = pos.max(T::zero()).min(T::one()) * num::cast(last).unwrap();
+    let index = scaled.floor().to_usize().unwrap().min(last - 1);
+    let frac = scaled - num::cast(index).unwrap();
+
+    let (y0, y1) = (table[index], table[index + 1]);
+    match interpolation {
+        LutInterpolation::Linear => y0 + (y1 - y0) * frac,
+        LutInterpolation::MonotoneCubic => {
+            let one = T::one();
+            let two: T = num::cast(2.0).unwrap();
+            let three: T = num::cast(3.0).unwrap();
+
+            let m0 = tangent(table, index);
+            let m1 = tangent(table, index + 1);
+            let t2 = frac * frac;
+            let t3 = t2 * frac;
+
+            (two * t3 - three * t2 + one) * y0 + (t3 - two * t2 + frac) * m0 +
+            (three * t2 - two * t3) * y1 + (t3 - t2) * m1
+        }
+    }
+}
+
+/// Get the Fritsch-Butland tangent at a table entry, in units of one entry.
+fn tangent<T>(table: &[T], index: usize) -> T
+    where T: num::Float
+{
+    let last = table.len() - 1;
+    if index == 0 {
+        return table[1] - table[0];
+    }
+    if index == last {
+        return table[last] - table[last - 1];
+    }
+
+    let before = table[index] - table[index - 1];
+    let after = table[index + 1] - table[index];
+    if before * after <= T::zero() {
+        T::zero()
+    } else {
+        let two: T = num::cast(2.0).unwrap();
+        two * before * after / (before + after)
+    }
+}
+
+/// Compute the inverted table and the linear range it covers.
+fn invert_table<T>(table: &[T], interpolation: LutInterpolation) -> (Vec<T>, (T, T))
+    where T: num::Float
+{
+    let rising = table[table.len() - 1] >= table[0];
+    let mut envelope = table.to_vec();
+    for i in 1..envelope.len() {
+        envelope[i] = if rising {
+            envelope[i].max(envelope[i - 1])
+        } else {
+            envelope[i].min(envelope[i - 1])
+        };
+    }
+
+    let (first, last) = (envelope[0], envelope[envelope.len() - 1]);
+    let (low, high) = if rising { (first, last) } else { (last, first) };
+
+    let size = table.len().max(MIN_INVERSE_SIZE);
+    let step = (high - low) / num::cast(size - 1).unwrap();
+    let two: T = num::cast(2.0).unwrap();
+
+    let inverse = (0..size)
+        .map(|i| {
+            let target = low + step * num::cast(i).unwrap();
+            let (mut lower, mut upper) = (T::zero(), T::one());
+            for _ in 0..INVERSION_ITERATIONS {
+                let mid = (lower + upper) / two;
+                let below = interpolate(&envelope, mid, interpolation) < target;
+                if below == rising {
+                    lower = mid;
+                } else {
+                    upper = mid;
+                }
+            }
+            (lower + upper) / two
+        })
+        .collect();
+
+    (inverse, (low, high))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use encoding::{EncodableColor, GammaEncoding, LinearEncoding, SrgbEncoding};
+    use rgb::Rgb;
+
+    #[test]
+    fn test_linear_lut() {
+        let lut = LutEncoding::new(vec![0.0, 0.25, 1.0]);
+        assert_relative_eq!(lut.decode_channel(0.0), 0.0);
+        assert_relative_eq!(lut.decode_channel(0.25), 0.125, epsilon = 1e-6);
+        assert_relative_eq!(lut.decode_channel(0.75), 0.625, epsilon = 1e-6);
+        assert_relative_eq!(lut.decode_channel(1.5), 1.0);
+        assert_relative_eq!(lut.decode_channel(-0.5), 0.0);
+
+        assert_relative_eq!(lut.encode_channel(0.125), 0.25, epsilon = 1e-4);
+        assert_relative_eq!(lut.encode_channel(0.625), 0.75, epsilon = 1e-4);
+        assert_relative_eq!(lut.encode_channel(1.0), 1.0, epsilon = 1e-6);
+        assert_eq!(lut.inverse_range(), (0.0, 1.0));
+        assert_eq!(lut.inverse_table().len(), MIN_INVERSE_SIZE);
+    }
+
+    #[test]
+    fn test_sampled_curve() {
+        let lut = LutEncoding::<f64>::sample(&SrgbEncoding::new(),
+                                             1024,
+                                             LutInterpolation::MonotoneCubic);
+        for &val in [0.0, 0.01, 0.2, 0.5, 0.8, 1.0].iter() {
+            assert_relative_eq!(lut.decode_channel(val),
+                                SrgbEncoding::new().decode_channel(val),
+                                epsilon = 1e-5);
+            assert_relative_eq!(lut.encode_channel(SrgbEncoding::new().decode_channel(val)),
+                                val,
+                                epsilon = 1e-3);
+        }
+
+        let coarse = LutEncoding::<f64>::sample(&GammaEncoding::new(2.2),
+                                                16,
+                                                LutInterpolation::MonotoneCubic);
+        let linear = LutEncoding::<f64>::sample(&GammaEncoding::new(2.2),
+                                                16,
+                                                LutInterpolation::Linear);
+        let exact = 0.3f64.powf(2.2);
+        assert!((coarse.decode_channel(0.3) - exact).abs() <
+                (linear.decode_channel(0.3) - exact).abs());
+    }
+
+    #[test]
+    fn test_non_monotonic() {
+        let lut = LutEncoding::new(vec![0.0, 0.5, 0.4, 1.0]);
+        assert_relative_eq!(lut.decode_channel(2.0 / 3.0), 0.4, epsilon = 1e-6);
+        assert_relative_eq!(lut.encode_channel(0.45), 0.3, epsilon = 1e-3);
+        assert_relative_eq!(lut.encode_channel(0.75), 5.0 / 6.0, epsilon = 1e-3);
+
+        let cubic = LutEncoding::with_interpolation(vec![0.0, 0.5, 0.4, 1.0],
+                                                    LutInterpolation::MonotoneCubic);
+        let mut prev = cubic.encode_channel(0.0);
+        for i in 1..=100 {
+            let next = cubic.encode_channel(i as f64 / 100.0);
+            assert!(next >= prev);
+            prev = next;
+        }
+
+        let falling = LutEncoding::new(vec![1.0, 0.5, 0.0]);
+        assert_eq!(falling.inverse_range(), (0.0, 1.0));
+        assert_relative_eq!(falling.encode_channel(0.75), 0.25, epsilon = 1e-4);
+        assert_relative_eq!(falling.encode_channel(0.0), 1.0, epsilon = 1e-6);
+
+        let flat = LutEncoding::new(vec![0.5, 0.5]);
+        assert_relative_eq!(flat.decode_channel(0.3), 0.5);
+        assert_relative_eq!(flat.encode_channel(0.5), 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_transcode() {
+        let lut = LutEncoding::<f64>::sample(&GammaEncoding::new(2.2),
+                                             256,
+                                             LutInterpolation::Linear);
+        let c1 = Rgb::from_channels(0.2, 0.5, 0.9).with_encoding(lut.clone());
+        let t1 = c1.clone().transcode(GammaEncoding::new(2.2));
+        assert_relative_eq!(*t1.color(), *c1.color(), epsilon = 1e-3);
+
+        let t2 = t1.transcode(lut.clone());
+        assert_relative_eq!(t2, c1, epsilon = 1e-3);
+
+        let linear = Rgb::from_channels(0.25, 0.5, 0.75).with_encoding(LinearEncoding::new());
+        assert_relative_eq!(linear.clone().encode(lut).decode(), linear, epsilon = 1e-4);
+    }
+}
@@ -1,7 +1,9 @@
-
 pub mod encoded_color;
 pub mod encode;
+pub mod lut;
 
-pub use self::encode::{ColorEncoding, EncodableColor, LinearEncoding, SrgbEncoding,
-                       ChannelDecoder, ChannelEncoder};
+pub use self::encode::{ColorEncoding, EncodableColor, LinearEncoding, SrgbEncoding, GammaEncoding,
+                       Rec709Encoding, PqEncoding, HlgEncoding, Bt1886Encoding,
+                       ParametricCurveEncoding, ChannelDecoder, ChannelEncoder};
+pub use self::lut::{LutEncoding, LutInterpolation};
 pub use self::encoded_color::{LinearColor, EncodedColor};
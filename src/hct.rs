@@ -0,0 +1,592 @@
+use std::fmt;
+use std::mem;
+use std::slice;
+use num;
+use approx;
+use angle::{Deg, Angle, FromAngle, IntoAngle, Turns};
+use angle;
+use channel::{PosFreeChannel, FreeChannelScalar, PosNormalChannelScalar, AngularChannel,
+              AngularChannelScalar, ChannelFormatCast, ChannelCast, ColorChannel};
+use color::{Color, PolarColor, FromTuple, Lerp, Bounded, Flatten};
+use convert::{GetChroma, GetHue, FromColor};
+use cam::{Cam16, Surround, ViewingConditions};
+use encoding::{EncodableColor, EncodedColor, SrgbEncoding};
+use lab::Lab;
+use linalg::Matrix3;
+use rgb::Rgb;
+use xyz::Xyz;
+
+pub struct HctTag;
+
+/// Material Design's HCT: the hue and chroma of CAM16 with the tone of CIELAB's L*.
+///
+/// The CAM16 correlates are computed under Material's fixed viewing conditions. Tone is in
+/// [0, 100]. Not every hue, chroma and tone is in the sRGB gamut: converting to sRGB keeps
+/// the hue and tone and reduces the chroma until the color fits.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Hct<T, A = Deg<T>> {
+    pub hue: AngularChannel<A>,
+    pub chroma: PosFreeChannel<T>,
+    pub tone: PosFreeChannel<T>,
+}
+
+impl<T, A> Hct<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    pub fn from_channels(hue: A, chroma: T, tone: T) -> Self {
+        Hct {
+            hue: AngularChannel::new(hue),
+            chroma: PosFreeChannel::new(chroma),
+            tone: PosFreeChannel::new(tone),
+        }
+    }
+
+    impl_color_color_cast_angular!(Hct {hue, chroma, tone},
+        chan_traits={FreeChannelScalar});
+
+    pub fn hue(&self) -> A {
+        self.hue.0.clone()
+    }
+    pub fn chroma(&self) -> T {
+        self.chroma.0
+    }
+    pub fn tone(&self) -> T {
+        self.tone.0
+    }
+    pub fn hue_mut(&mut self) -> &mut A {
+        &mut self.hue.0
+    }
+    pub fn chroma_mut(&mut self) -> &mut T {
+        &mut self.chroma.0
+    }
+    pub fn tone_mut(&mut self) -> &mut T {
+        &mut self.tone.0
+    }
+    pub fn set_hue(&mut self, val: A) {
+        self.hue.0 = val;
+    }
+    pub fn set_chroma(&mut self, val: T) {
+        self.chroma.0 = val;
+    }
+    pub fn set_tone(&mut self, val: T) {
+        self.tone.0 = val;
+    }
+}
+
+impl<T, A> Color for Hct<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type Tag = HctTag;
+    type ChannelsTuple = (A, T, T);
+
+    fn num_channels() -> u32 {
+        3
+    }
+    fn to_tuple(self) -> Self::ChannelsTuple {
+        (self.hue.0, self.chroma.0, self.tone.0)
+    }
+}
+
+impl<T, A> PolarColor for Hct<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type Angular = A;
+    type Cartesian = T;
+}
+
+impl<T, A> FromTuple for Hct<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    fn from_tuple(values: Self::ChannelsTuple) -> Self {
+        Hct::from_channels(values.0, values.1, values.2)
+    }
+}
+
+impl<T, A> Lerp for Hct<T, A>
+    where T: FreeChannelScalar + Lerp,
+          A: AngularChannelScalar + Lerp
+{
+    type Position = A::Position;
+
+    impl_color_lerp_angular!(Hct<T> {hue, chroma, tone});
+}
+
+impl<T, A> Bounded for Hct<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_bounded!(Hct {hue, chroma, tone});
+}
+
+impl<T, A> Flatten for Hct<T, A>
+    where T: FreeChannelScalar + num::Float,
+          A: AngularChannelScalar + Angle<Scalar = T> + FromAngle<Turns<T>>
+{
+    type ScalarFormat = T;
+
+    impl_color_as_slice!(T);
+
+    fn from_slice(vals: &[T]) -> Self {
+        Hct::from_channels(A::from_angle(angle::Turns(vals[0])), vals[1], vals[2])
+    }
+}
+
+impl<T, A> approx::ApproxEq for Hct<T, A>
+    where T: FreeChannelScalar + approx::ApproxEq<Epsilon = A::Epsilon>,
+          A: AngularChannelScalar + approx::ApproxEq,
+          A::Epsilon: Clone + num::Float
+{
+    impl_approx_eq!({hue, chroma, tone});
+}
+
+impl<T, A> Default for Hct<T, A>
+    where T: FreeChannelScalar + num::Zero,
+          A: AngularChannelScalar + num::Zero
+{
+    impl_color_default!(Hct {hue: AngularChannel,
+        chroma: PosFreeChannel, tone: PosFreeChannel});
+}
+
+impl<T, A> fmt::Display for Hct<T, A>
+    where T: FreeChannelScalar + fmt::Display,
+          A: AngularChannelScalar + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Hct({}, {}, {})", self.hue, self.chroma, self.tone)
+    }
+}
+
+impl<T, A> GetChroma for Hct<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type ChromaType = T;
+    fn get_chroma(&self) -> T {
+        self.chroma()
+    }
+}
+
+impl<T, A> GetHue for Hct<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_get_hue_angular!(Hct);
+}
+
+/// The CAM16 model under Material's viewing conditions: a D65 white, an adapting field of
+/// 200/π times the luminance of a mid-gray with L* 50, and that gray as the background.
+fn material_model<T: FreeChannelScalar>() -> Cam16<T> {
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    let white = Xyz::from_channels(c(0.95047), c(1.0), c(1.08883));
+    let background = y_from_tone(c(50.0));
+    let conditions = ViewingConditions::new(white,
+                                            c(200.0 / ::std::f64::consts::PI) * background,
+                                            c(100.0) * background,
+                                            Surround::Average);
+    Cam16::new(conditions)
+}
+
+/// Material's transform from linear sRGB to XYZ, which its HCT values are defined with.
+fn xyz_from_srgb<T: FreeChannelScalar>() -> Matrix3<T> {
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    Matrix3::new([c(0.41233895), c(0.35762064), c(0.18051042),
+                  c(0.2126), c(0.7152), c(0.0722),
+                  c(0.01932141), c(0.11916382), c(0.95034478)])
+}
+
+fn tone_from_y<T: FreeChannelScalar>(y: T) -> T {
+    let one = T::one();
+    Lab::from_xyz(&Xyz::from_channels(one, y, one), &Xyz::from_channels(one, one, one)).L()
+}
+
+fn y_from_tone<T: FreeChannelScalar>(tone: T) -> T {
+    let one = T::one();
+    Lab::from_channels(tone, T::zero(), T::zero()).to_xyz(&Xyz::from_channels(one, one, one)).y()
+}
+
+/// Find the stimulus with a CAM16 chroma and hue whose relative luminance is `y`.
+///
+/// Luminance grows smoothly with lightness at a fixed chroma and hue, so the secant method
+/// converges in a few steps. Should it leave the range of valid lightnesses, bisect instead.
+fn xyz_from_hcy<T: FreeChannelScalar>(model: &Cam16<T>, hue: Deg<T>, chroma: T, y: T) -> Xyz<T> {
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    let tolerance = c(16.0) * T::epsilon() * y;
+
+    // Luminance is roughly proportional to the square of lightness.
+    let mut previous = (T::zero(), T::zero());
+    let mut j = c(100.0) * y.sqrt();
+    for _ in 0..16 {
+        let xyz = model.xyz_from_jch(j, chroma, hue);
+        let found = xyz.y();
+        if (found - y).abs() <= tolerance {
+            return xyz;
+        }
+        let next = j - (found - y) * (j - previous.0) / (found - previous.1);
+        if !(next > T::zero() && next.is_finite()) {
+            break;
+        }
+        previous = (j, found);
+        j = next;
+    }
+
+    let mut low = T::zero();
+    let mut high = c(100.0);
+    while model.xyz_from_jch(high, chroma, hue).y() < y && high < c(1e4) {
+        high = high * c(2.0);
+    }
+    for _ in 0..64 {
+        let mid = (low + high) / c(2.0);
+        if mid <= low || mid >= high {
+            break;
+        }
+        if model.xyz_from_jch(mid, chroma, hue).y() < y {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    model.xyz_from_jch((low + high) / c(2.0), chroma, hue)
+}
+
+/// Find the linear sRGB color with the hue and tone requested and the most chroma up to the
+/// requested chroma.
+fn solve_linear_srgb<T>(model: &Cam16<T>, hue: Deg<T>, chroma: T, tone: T) -> (T, T, T)
+    where T: FreeChannelScalar + PosNormalChannelScalar + num::Float
+{
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    let (zero, one) = (T::zero(), T::one());
+    if tone <= zero {
+        return (zero, zero, zero);
+    } else if tone >= c(100.0) {
+        return (one, one, one);
+    }
+
+    let from_xyz = xyz_from_srgb::<T>().inverse().unwrap();
+    let y = y_from_tone(tone);
+    let linear_srgb = |chroma: T| {
+        from_xyz.transform_vector(xyz_from_hcy(model, hue, chroma, y).to_tuple())
+    };
+    let tolerance = c(64.0) * T::epsilon();
+    let in_gamut = |rgb: (T, T, T)| {
+        [rgb.0, rgb.1, rgb.2].iter().all(|&x| x >= -tolerance && x <= one + tolerance)
+    };
+
+    let rgb = linear_srgb(chroma);
+    if chroma <= zero || in_gamut(rgb) {
+        return rgb;
+    }
+
+    // The colors of a hue and tone within the gamut run from gray up to a maximum chroma.
+    let (mut low, mut high) = (zero, chroma);
+    let mut best = (y, y, y);
+    for _ in 0..50 {
+        let mid = (low + high) / c(2.0);
+        let rgb = linear_srgb(mid);
+        if in_gamut(rgb) {
+            low = mid;
+            best = rgb;
+        } else {
+            high = mid;
+        }
+    }
+    best
+}
+
+/// Solve for the sRGB color of a hue, chroma and tone, as in the conversion from `Hct`.
+fn srgb_from_hct<T>(model: &Cam16<T>, hue: Deg<T>, chroma: T, tone: T)
+                    -> EncodedColor<Rgb<T>, SrgbEncoding>
+    where T: FreeChannelScalar + PosNormalChannelScalar + num::Float,
+          Rgb<T>: EncodableColor
+{
+    let (r, g, b) = solve_linear_srgb(model, hue, chroma, tone);
+    let clamp = |x: T| x.max(T::zero()).min(T::one());
+    Rgb::from_channels(clamp(r), clamp(g), clamp(b))
+        .with_encoding(::encoding::LinearEncoding::new())
+        .encode(SrgbEncoding::new())
+}
+
+/// Converts colors to and from `Hct` with a single CAM16 model.
+///
+/// The `FromColor` conversions set up Material's viewing conditions for every color. Building
+/// a converter does that once, so converting many colors only evaluates the model.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HctConverter<T> {
+    model: Cam16<T>,
+}
+
+impl<T> HctConverter<T>
+    where T: FreeChannelScalar
+{
+    pub fn new() -> Self {
+        HctConverter { model: material_model() }
+    }
+
+    pub fn hct_from_xyz<A>(&self, color: &Xyz<T>) -> Hct<T, A>
+        where A: AngularChannelScalar + FromAngle<Deg<T>>
+    {
+        let appearance = self.model.appearance(color);
+        Hct::from_channels(A::from_angle(appearance.hue),
+                           appearance.chroma,
+                           tone_from_y(color.y()))
+    }
+
+    /// Find the stimulus with exactly the hue, chroma and tone of `color`, in the sRGB gamut
+    /// or not.
+    pub fn xyz_from_hct<A>(&self, color: &Hct<T, A>) -> Xyz<T>
+        where A: AngularChannelScalar + IntoAngle<Deg<T>, OutputScalar = T>
+    {
+        if color.tone() <= T::zero() {
+            return Xyz::from_channels(T::zero(), T::zero(), T::zero());
+        }
+        xyz_from_hcy(&self.model,
+                     color.hue().into_angle(),
+                     color.chroma(),
+                     y_from_tone(color.tone()))
+    }
+}
+
+impl<T> HctConverter<T>
+    where T: FreeChannelScalar + PosNormalChannelScalar + num::Float,
+          Rgb<T>: EncodableColor
+{
+    pub fn hct_from_srgb<A>(&self, color: &EncodedColor<Rgb<T>, SrgbEncoding>) -> Hct<T, A>
+        where A: AngularChannelScalar + FromAngle<Deg<T>>
+    {
+        let linear = color.clone().decode();
+        let rgb = (linear.color().red(), linear.color().green(), linear.color().blue());
+        let (x, y, z) = xyz_from_srgb::<T>().transform_vector(rgb);
+        self.hct_from_xyz(&Xyz::from_channels(x, y, z))
+    }
+
+    /// Solve for the sRGB color with the hue and tone of `color`, reducing the chroma as far
+    /// as needed to bring it into gamut.
+    pub fn srgb_from_hct<A>(&self, color: &Hct<T, A>) -> EncodedColor<Rgb<T>, SrgbEncoding>
+        where A: AngularChannelScalar + IntoAngle<Deg<T>, OutputScalar = T>
+    {
+        srgb_from_hct(&self.model, color.hue().into_angle(), color.chroma(), color.tone())
+    }
+}
+
+impl<T> Default for HctConverter<T>
+    where T: FreeChannelScalar
+{
+    fn default() -> Self {
+        HctConverter::new()
+    }
+}
+
+impl<T, A> FromColor<Xyz<T>> for Hct<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + FromAngle<Deg<T>>
+{
+    fn from_color(from: &Xyz<T>) -> Self {
+        HctConverter::new().hct_from_xyz(from)
+    }
+}
+
+impl<T, A> FromColor<Hct<T, A>> for Xyz<T>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + IntoAngle<Deg<T>, OutputScalar = T>
+{
+    /// Find the stimulus with exactly the hue, chroma and tone of `from`, in the sRGB gamut
+    /// or not.
+    fn from_color(from: &Hct<T, A>) -> Self {
+        HctConverter::new().xyz_from_hct(from)
+    }
+}
+
+impl<T, A> FromColor<EncodedColor<Rgb<T>, SrgbEncoding>> for Hct<T, A>
+    where T: FreeChannelScalar + PosNormalChannelScalar + num::Float,
+          A: AngularChannelScalar + FromAngle<Deg<T>>,
+          Rgb<T>: EncodableColor
+{
+    fn from_color(from: &EncodedColor<Rgb<T>, SrgbEncoding>) -> Self {
+        HctConverter::new().hct_from_srgb(from)
+    }
+}
+
+impl<T, A> FromColor<Hct<T, A>> for EncodedColor<Rgb<T>, SrgbEncoding>
+    where T: FreeChannelScalar + PosNormalChannelScalar + num::Float,
+          A: AngularChannelScalar + IntoAngle<Deg<T>, OutputScalar = T>,
+          Rgb<T>: EncodableColor
+{
+    /// Solve for the sRGB color with the hue and tone of `from`, reducing the chroma as far as
+    /// needed to bring it into gamut.
+    fn from_color(from: &Hct<T, A>) -> Self {
+        HctConverter::new().srgb_from_hct(from)
+    }
+}
+
+/// A palette of sRGB colors sharing a hue and chroma at different tones.
+///
+/// Tones that cannot reach the palette's chroma in sRGB get the most chroma they can.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TonalPalette<T> {
+    hue: Deg<T>,
+    chroma: T,
+    converter: HctConverter<T>,
+}
+
+impl<T> TonalPalette<T>
+    where T: FreeChannelScalar + PosNormalChannelScalar + num::Float,
+          Rgb<T>: EncodableColor
+{
+    /// The tones of a Material Design tonal palette.
+    pub const MATERIAL_TONES: [u8; 13] = [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99, 100];
+
+    pub fn new(hue: Deg<T>, chroma: T) -> Self {
+        TonalPalette {
+            hue,
+            chroma,
+            converter: HctConverter::new(),
+        }
+    }
+
+    /// Construct the palette with the hue and chroma of an sRGB-encoded seed color.
+    pub fn from_seed(seed: &Rgb<T>) -> Self {
+        let converter = HctConverter::new();
+        let hct: Hct<T> = converter.hct_from_srgb(&(*seed).with_encoding(SrgbEncoding::new()));
+        TonalPalette {
+            hue: hct.hue(),
+            chroma: hct.chroma(),
+            converter,
+        }
+    }
+
+    pub fn hue(&self) -> Deg<T> {
+        self.hue
+    }
+    pub fn chroma(&self) -> T {
+        self.chroma
+    }
+
+    /// The sRGB-encoded color of the palette at `tone`, in [0, 100].
+    pub fn tone(&self, tone: T) -> Rgb<T> {
+        srgb_from_hct(&self.converter.model, self.hue, self.chroma, tone).strip_encoding()
+    }
+
+    /// The colors of the palette at each of `MATERIAL_TONES`.
+    pub fn material_tones(&self) -> Vec<Rgb<T>> {
+        Self::MATERIAL_TONES
+            .iter()
+            .map(|&tone| self.tone(num::cast(tone).unwrap()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use angle::Rad;
+
+    fn srgb(r: f64, g: f64, b: f64) -> EncodedColor<Rgb<f64>, SrgbEncoding> {
+        Rgb::from_channels(r, g, b).with_encoding(SrgbEncoding::new())
+    }
+
+    #[test]
+    fn test_construct() {
+        let c1 = Hct::from_channels(Deg(270.0), 36.0, 50.0);
+        assert_relative_eq!(c1.hue(), Deg(270.0));
+        assert_relative_eq!(c1.chroma(), 36.0);
+        assert_relative_eq!(c1.tone(), 50.0);
+        assert_eq!(c1.to_tuple(), (Deg(270.0), 36.0, 50.0));
+        assert_relative_eq!(Hct::from_tuple(c1.to_tuple()), c1);
+
+        let c2 = Hct::from_channels(Turns(0.25), 10.0, 80.0);
+        assert_eq!(c2.as_slice(), &[0.25, 10.0, 80.0]);
+        assert_relative_eq!(Hct::from_slice(c2.as_slice()), c2);
+        assert_eq!(c2.get_hue::<Deg<_>>(), Deg(90.0));
+        assert_eq!(c2.get_chroma(), 10.0);
+    }
+
+    #[test]
+    fn test_from_srgb() {
+        let red = Hct::<f64>::from_color(&srgb(1.0, 0.0, 0.0));
+        assert_relative_eq!(red, Hct::from_channels(Deg(27.408225), 113.357887, 53.232882),
+            epsilon=1e-4);
+        let green = Hct::<f64>::from_color(&srgb(0.0, 1.0, 0.0));
+        assert_relative_eq!(green, Hct::from_channels(Deg(142.139894), 108.410061, 87.737033),
+            epsilon=1e-4);
+        let blue = Hct::<f64>::from_color(&srgb(0.0, 0.0, 1.0));
+        assert_relative_eq!(blue, Hct::from_channels(Deg(282.788180), 87.230694, 32.302587),
+            epsilon=1e-4);
+        let white = Hct::<f64>::from_color(&srgb(1.0, 1.0, 1.0));
+        assert_relative_eq!(white.tone(), 100.0, epsilon=1e-9);
+        assert!(white.chroma() < 3.0);
+    }
+
+    #[test]
+    fn test_xyz_round_trip() {
+        let c1 = Xyz::from_channels(0.3, 0.22, 0.5);
+        let t1: Hct<f64> = Hct::from_color(&c1);
+        assert_relative_eq!(Xyz::from_color(&t1), c1, epsilon=1e-9);
+
+        let c2 = Hct::from_channels(Rad(1.0), 150.0, 70.0);
+        let t2 = Xyz::from_color(&c2);
+        assert_relative_eq!(Hct::<f64, Rad<f64>>::from_color(&t2), c2, epsilon=1e-9);
+    }
+
+    #[test]
+    fn test_solve_srgb() {
+        let c1 = Hct::from_channels(Deg(270.0), 36.0, 50.0);
+        let t1 = EncodedColor::from_color(&c1);
+        assert_relative_eq!(t1, srgb(0.381164, 0.461450, 0.675392), epsilon=1e-6);
+        assert_relative_eq!(Hct::from_color(&t1), c1, epsilon=1e-6);
+
+        let c2 = Hct::from_channels(Deg(120.0), 200.0, 60.0);
+        let t2 = EncodedColor::from_color(&c2);
+        assert_relative_eq!(t2, srgb(0.521388, 0.604270, 0.0), epsilon=1e-6);
+        let t2 = Hct::<f64>::from_color(&t2);
+        assert_relative_eq!(t2.hue(), Deg(120.0), epsilon=1e-6);
+        assert_relative_eq!(t2.chroma(), 59.354062, epsilon=1e-4);
+        assert_relative_eq!(t2.tone(), 60.0, epsilon=1e-6);
+
+        let black = Hct::from_channels(Deg(30.0), 50.0, 0.0);
+        assert_relative_eq!(EncodedColor::from_color(&black), srgb(0.0, 0.0, 0.0));
+        let white = Hct::from_channels(Deg(30.0), 50.0, 100.0);
+        assert_relative_eq!(EncodedColor::from_color(&white), srgb(1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_converter() {
+        let converter = HctConverter::new();
+        let c1 = Xyz::from_channels(0.3, 0.22, 0.5);
+        let t1: Hct<f64> = converter.hct_from_xyz(&c1);
+        assert_relative_eq!(t1, Hct::from_color(&c1), epsilon=1e-12);
+        assert_relative_eq!(converter.xyz_from_hct(&t1), c1, epsilon=1e-9);
+
+        let c2 = Hct::from_channels(Deg(120.0), 200.0, 60.0);
+        let t2 = converter.srgb_from_hct(&c2);
+        assert_relative_eq!(t2, EncodedColor::from_color(&c2), epsilon=1e-12);
+        assert_relative_eq!(converter.hct_from_srgb::<Deg<f64>>(&t2),
+                            Hct::from_color(&t2),
+                            epsilon=1e-12);
+    }
+
+    #[test]
+    fn test_tonal_palette() {
+        let palette = TonalPalette::from_seed(&Rgb::from_channels(0.0, 0.0, 1.0));
+        assert_relative_eq!(palette.hue(), Deg(282.788180), epsilon=1e-4);
+        assert_relative_eq!(palette.chroma(), 87.230694, epsilon=1e-4);
+
+        let tones = palette.material_tones();
+        assert_eq!(tones.len(), 13);
+        assert_relative_eq!(tones[0], Rgb::from_channels(0.0, 0.0, 0.0));
+        assert_relative_eq!(tones[12], Rgb::from_channels(1.0, 1.0, 1.0));
+        for (&tone, color) in TonalPalette::<f64>::MATERIAL_TONES.iter().zip(tones.iter()) {
+            let hct = Hct::<f64>::from_color(&(*color).with_encoding(SrgbEncoding::new()));
+            assert_relative_eq!(hct.tone(), tone as f64, epsilon=1e-6);
+            if tone > 0 && tone < 100 {
+                assert_relative_eq!(hct.hue(), palette.hue(), epsilon=1e-4);
+                assert!(hct.chroma() <= palette.chroma() + 1e-6);
+            }
+        }
+        assert_relative_eq!(palette.tone(32.302587), Rgb::from_channels(0.0, 0.0, 1.0),
+            epsilon=1e-4);
+    }
+}
@@ -0,0 +1,282 @@
+#![allow(non_snake_case)]
+
+use std::fmt;
+use std::mem;
+use std::slice;
+use num;
+use approx;
+use angle::{Deg, Angle, FromAngle, IntoAngle, Turns, Rad};
+use angle;
+use channel::{PosFreeChannel, FreeChannelScalar, PosNormalChannelScalar, AngularChannel,
+              AngularChannelScalar, ChannelFormatCast, ChannelCast, ColorChannel};
+use color::{Color, PolarColor, FromTuple, Lerp, Bounded, Flatten};
+use convert::{GetHue, FromColor};
+use encoding::{EncodableColor, EncodedColor, SrgbEncoding};
+use lchuv::Lchuv;
+use hsluv::Hsluv;
+use rgb::Rgb;
+
+pub struct HpluvTag;
+
+/// HPLuv, the pastel variant of `Hsluv`.
+///
+/// Saturation is the chroma as a percentage of the largest chroma sRGB reaches at that
+/// lightness for every hue, rather than for the color's own hue. A saturation in [0, 100]
+/// is always in gamut and a fixed saturation looks equally saturated at every hue, at the
+/// cost of covering only pastel colors. Saturated sRGB colors map above 100.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Hpluv<T, A = Deg<T>> {
+    pub hue: AngularChannel<A>,
+    pub saturation: PosFreeChannel<T>,
+    pub lightness: PosFreeChannel<T>,
+}
+
+impl<T, A> Hpluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    pub fn from_channels(hue: A, saturation: T, lightness: T) -> Self {
+        Hpluv {
+            hue: AngularChannel::new(hue),
+            saturation: PosFreeChannel::new(saturation),
+            lightness: PosFreeChannel::new(lightness),
+        }
+    }
+
+    impl_color_color_cast_angular!(Hpluv {hue, saturation, lightness},
+        chan_traits={FreeChannelScalar});
+
+    pub fn hue(&self) -> A {
+        self.hue.0.clone()
+    }
+    pub fn saturation(&self) -> T {
+        self.saturation.0
+    }
+    pub fn lightness(&self) -> T {
+        self.lightness.0
+    }
+    pub fn hue_mut(&mut self) -> &mut A {
+        &mut self.hue.0
+    }
+    pub fn saturation_mut(&mut self) -> &mut T {
+        &mut self.saturation.0
+    }
+    pub fn lightness_mut(&mut self) -> &mut T {
+        &mut self.lightness.0
+    }
+    pub fn set_hue(&mut self, val: A) {
+        self.hue.0 = val;
+    }
+    pub fn set_saturation(&mut self, val: T) {
+        self.saturation.0 = val;
+    }
+    pub fn set_lightness(&mut self, val: T) {
+        self.lightness.0 = val;
+    }
+}
+
+impl<T, A> Color for Hpluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type Tag = HpluvTag;
+    type ChannelsTuple = (A, T, T);
+
+    fn num_channels() -> u32 {
+        3
+    }
+    fn to_tuple(self) -> Self::ChannelsTuple {
+        (self.hue.0, self.saturation.0, self.lightness.0)
+    }
+}
+
+impl<T, A> PolarColor for Hpluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type Angular = A;
+    type Cartesian = T;
+}
+
+impl<T, A> FromTuple for Hpluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    fn from_tuple(values: Self::ChannelsTuple) -> Self {
+        Hpluv::from_channels(values.0, values.1, values.2)
+    }
+}
+
+impl<T, A> Lerp for Hpluv<T, A>
+    where T: FreeChannelScalar + Lerp,
+          A: AngularChannelScalar + Lerp
+{
+    type Position = A::Position;
+
+    impl_color_lerp_angular!(Hpluv<T> {hue, saturation, lightness});
+}
+
+impl<T, A> Bounded for Hpluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_bounded!(Hpluv {hue, saturation, lightness});
+}
+
+impl<T, A> Flatten for Hpluv<T, A>
+    where T: FreeChannelScalar + num::Float,
+          A: AngularChannelScalar + Angle<Scalar = T> + FromAngle<Turns<T>>
+{
+    type ScalarFormat = T;
+
+    impl_color_as_slice!(T);
+
+    fn from_slice(vals: &[T]) -> Self {
+        Hpluv::from_channels(A::from_angle(angle::Turns(vals[0])), vals[1], vals[2])
+    }
+}
+
+impl<T, A> approx::ApproxEq for Hpluv<T, A>
+    where T: FreeChannelScalar + approx::ApproxEq<Epsilon = A::Epsilon>,
+          A: AngularChannelScalar + approx::ApproxEq,
+          A::Epsilon: Clone + num::Float
+{
+    impl_approx_eq!({hue, saturation, lightness});
+}
+
+impl<T, A> Default for Hpluv<T, A>
+    where T: FreeChannelScalar + num::Zero,
+          A: AngularChannelScalar + num::Zero
+{
+    impl_color_default!(Hpluv {hue: AngularChannel,
+        saturation: PosFreeChannel, lightness: PosFreeChannel});
+}
+
+impl<T, A> fmt::Display for Hpluv<T, A>
+    where T: FreeChannelScalar + fmt::Display,
+          A: AngularChannelScalar + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Hpluv({}, {}, {})", self.hue, self.saturation, self.lightness)
+    }
+}
+
+impl<T, A> GetHue for Hpluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_get_hue_angular!(Hpluv);
+}
+
+/// Whether `L` is too close to black or white for the saturation to be meaningful.
+fn is_achromatic<T: FreeChannelScalar>(L: T) -> bool {
+    L > num::cast(99.9999999).unwrap() || L < num::cast(1e-8).unwrap()
+}
+
+impl<T, A> FromColor<Lchuv<T, A>> for Hpluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn from_color(from: &Lchuv<T, A>) -> Self {
+        let saturation = if is_achromatic(from.L()) {
+            T::zero()
+        } else {
+            let max_chroma = Lchuv::<T, A>::srgb_max_safe_chroma(from.L());
+            from.chroma() / max_chroma * num::cast(100.0).unwrap()
+        };
+        Hpluv::from_channels(from.hue(), saturation, from.L())
+    }
+}
+
+impl<T, A> FromColor<Hpluv<T, A>> for Lchuv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn from_color(from: &Hpluv<T, A>) -> Self {
+        let L = from.lightness();
+        let chroma = if is_achromatic(L) {
+            T::zero()
+        } else {
+            let max_chroma = Lchuv::<T, A>::srgb_max_safe_chroma(L);
+            max_chroma * from.saturation() / num::cast(100.0).unwrap()
+        };
+        Lchuv::from_channels(L, chroma, from.hue())
+    }
+}
+
+impl<T, A> FromColor<EncodedColor<Rgb<T>, SrgbEncoding>> for Hpluv<T, A>
+    where T: FreeChannelScalar + PosNormalChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T> + FromAngle<Rad<T>>,
+          Rgb<T>: EncodableColor
+{
+    fn from_color(from: &EncodedColor<Rgb<T>, SrgbEncoding>) -> Self {
+        let hsluv: Hsluv<T, A> = Hsluv::from_color(from);
+        Hpluv::from_color(&Lchuv::from_color(&hsluv))
+    }
+}
+
+impl<T, A> FromColor<Hpluv<T, A>> for EncodedColor<Rgb<T>, SrgbEncoding>
+    where T: FreeChannelScalar + PosNormalChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>,
+          Rgb<T>: EncodableColor
+{
+    fn from_color(from: &Hpluv<T, A>) -> Self {
+        EncodedColor::from_color(&Hsluv::from_color(&Lchuv::from_color(from)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn srgb(r: f64, g: f64, b: f64) -> EncodedColor<Rgb<f64>, SrgbEncoding> {
+        Rgb::from_channels(r, g, b).with_encoding(SrgbEncoding::new())
+    }
+
+    #[test]
+    fn test_construct() {
+        let c1 = Hpluv::from_channels(Deg(240.0), 60.0, 75.0);
+        assert_relative_eq!(c1.hue(), Deg(240.0));
+        assert_relative_eq!(c1.saturation(), 60.0);
+        assert_relative_eq!(c1.lightness(), 75.0);
+        assert_eq!(c1.to_tuple(), (Deg(240.0), 60.0, 75.0));
+        assert_relative_eq!(Hpluv::from_tuple(c1.to_tuple()), c1);
+    }
+
+    #[test]
+    fn test_from_lchuv() {
+        let c1 = Lchuv::from_channels(50.0, 19.701563, Deg(300.0));
+        let t1 = Hpluv::from_color(&c1);
+        assert_relative_eq!(t1, Hpluv::from_channels(Deg(300.0), 50.0, 50.0), epsilon=1e-6);
+        assert_relative_eq!(Lchuv::from_color(&t1), c1, epsilon=1e-9);
+    }
+
+    #[test]
+    fn test_from_srgb() {
+        let red = Hpluv::from_color(&srgb(1.0, 0.0, 0.0));
+        assert_relative_eq!(red, Hpluv::from_channels(Deg(12.177051), 426.746789, 53.237116),
+            epsilon=1e-5);
+        assert_relative_eq!(EncodedColor::from_color(&red), srgb(1.0, 0.0, 0.0), epsilon=1e-6);
+
+        let c1 = srgb(0.2, 0.6, 0.4);
+        let t1 = Hpluv::from_color(&c1);
+        assert_relative_eq!(t1, Hpluv::from_channels(Deg(143.898662), 116.958797, 56.530227),
+            epsilon=1e-5);
+        assert_relative_eq!(EncodedColor::from_color(&t1), c1, epsilon=1e-6);
+    }
+
+    #[test]
+    fn test_always_in_gamut() {
+        for &lightness in [5.0, 30.0, 60.0, 95.0].iter() {
+            for hue in (0..12).map(|i| Deg(i as f64 * 30.0)) {
+                let color: EncodedColor<Rgb<f64>, SrgbEncoding> =
+                    EncodedColor::from_color(&Hpluv::from_channels(hue, 100.0, lightness));
+                let rgb = color.color();
+                for &channel in [rgb.red(), rgb.green(), rgb.blue()].iter() {
+                    assert!(channel > -1e-6 && channel < 1.0 + 1e-6);
+                }
+            }
+        }
+    }
+}
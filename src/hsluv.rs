@@ -0,0 +1,323 @@
+#![allow(non_snake_case)]
+
+use std::fmt;
+use std::mem;
+use std::slice;
+use num;
+use approx;
+use angle::{Deg, Angle, FromAngle, IntoAngle, Turns, Rad};
+use angle;
+use channel::{PosFreeChannel, FreeChannelScalar, PosNormalChannelScalar, AngularChannel,
+              AngularChannelScalar, ChannelFormatCast, ChannelCast, ColorChannel};
+use color::{Color, PolarColor, FromTuple, Lerp, Bounded, Flatten};
+use convert::{GetHue, FromColor};
+use encoding::{EncodableColor, EncodedColor, LinearEncoding, SrgbEncoding};
+use lchuv::Lchuv;
+use linalg::Matrix3;
+use luv::Luv;
+use rgb::Rgb;
+use xyz::Xyz;
+
+pub struct HsluvTag;
+
+/// HSLuv, an HSL-like model built on `Lchuv` whose saturation is always in the sRGB gamut.
+///
+/// The hue and lightness are those of `Lchuv` relative to D65. Saturation is the chroma as a
+/// percentage of the largest chroma sRGB reaches at that lightness and hue, so every
+/// saturation in [0, 100] is displayable, and unlike `Hsl` colors with the same lightness
+/// look equally light.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Hsluv<T, A = Deg<T>> {
+    pub hue: AngularChannel<A>,
+    pub saturation: PosFreeChannel<T>,
+    pub lightness: PosFreeChannel<T>,
+}
+
+impl<T, A> Hsluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    pub fn from_channels(hue: A, saturation: T, lightness: T) -> Self {
+        Hsluv {
+            hue: AngularChannel::new(hue),
+            saturation: PosFreeChannel::new(saturation),
+            lightness: PosFreeChannel::new(lightness),
+        }
+    }
+
+    impl_color_color_cast_angular!(Hsluv {hue, saturation, lightness},
+        chan_traits={FreeChannelScalar});
+
+    pub fn hue(&self) -> A {
+        self.hue.0.clone()
+    }
+    pub fn saturation(&self) -> T {
+        self.saturation.0
+    }
+    pub fn lightness(&self) -> T {
+        self.lightness.0
+    }
+    pub fn hue_mut(&mut self) -> &mut A {
+        &mut self.hue.0
+    }
+    pub fn saturation_mut(&mut self) -> &mut T {
+        &mut self.saturation.0
+    }
+    pub fn lightness_mut(&mut self) -> &mut T {
+        &mut self.lightness.0
+    }
+    pub fn set_hue(&mut self, val: A) {
+        self.hue.0 = val;
+    }
+    pub fn set_saturation(&mut self, val: T) {
+        self.saturation.0 = val;
+    }
+    pub fn set_lightness(&mut self, val: T) {
+        self.lightness.0 = val;
+    }
+}
+
+impl<T, A> Color for Hsluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type Tag = HsluvTag;
+    type ChannelsTuple = (A, T, T);
+
+    fn num_channels() -> u32 {
+        3
+    }
+    fn to_tuple(self) -> Self::ChannelsTuple {
+        (self.hue.0, self.saturation.0, self.lightness.0)
+    }
+}
+
+impl<T, A> PolarColor for Hsluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type Angular = A;
+    type Cartesian = T;
+}
+
+impl<T, A> FromTuple for Hsluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    fn from_tuple(values: Self::ChannelsTuple) -> Self {
+        Hsluv::from_channels(values.0, values.1, values.2)
+    }
+}
+
+impl<T, A> Lerp for Hsluv<T, A>
+    where T: FreeChannelScalar + Lerp,
+          A: AngularChannelScalar + Lerp
+{
+    type Position = A::Position;
+
+    impl_color_lerp_angular!(Hsluv<T> {hue, saturation, lightness});
+}
+
+impl<T, A> Bounded for Hsluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_bounded!(Hsluv {hue, saturation, lightness});
+}
+
+impl<T, A> Flatten for Hsluv<T, A>
+    where T: FreeChannelScalar + num::Float,
+          A: AngularChannelScalar + Angle<Scalar = T> + FromAngle<Turns<T>>
+{
+    type ScalarFormat = T;
+
+    impl_color_as_slice!(T);
+
+    fn from_slice(vals: &[T]) -> Self {
+        Hsluv::from_channels(A::from_angle(angle::Turns(vals[0])), vals[1], vals[2])
+    }
+}
+
+impl<T, A> approx::ApproxEq for Hsluv<T, A>
+    where T: FreeChannelScalar + approx::ApproxEq<Epsilon = A::Epsilon>,
+          A: AngularChannelScalar + approx::ApproxEq,
+          A::Epsilon: Clone + num::Float
+{
+    impl_approx_eq!({hue, saturation, lightness});
+}
+
+impl<T, A> Default for Hsluv<T, A>
+    where T: FreeChannelScalar + num::Zero,
+          A: AngularChannelScalar + num::Zero
+{
+    impl_color_default!(Hsluv {hue: AngularChannel,
+        saturation: PosFreeChannel, lightness: PosFreeChannel});
+}
+
+impl<T, A> fmt::Display for Hsluv<T, A>
+    where T: FreeChannelScalar + fmt::Display,
+          A: AngularChannelScalar + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Hsluv({}, {}, {})", self.hue, self.saturation, self.lightness)
+    }
+}
+
+impl<T, A> GetHue for Hsluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_get_hue_angular!(Hsluv);
+}
+
+/// The D65 white point HSLuv is defined with.
+fn white_point<T: FreeChannelScalar>() -> Xyz<T> {
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    Xyz::from_channels(c(0.95045592705), c(1.0), c(1.08905775076))
+}
+
+/// The transform from linear sRGB to XYZ HSLuv is defined with.
+fn xyz_from_srgb<T: FreeChannelScalar>() -> Matrix3<T> {
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    Matrix3::new([c(0.41239079926595), c(0.35758433938387), c(0.18048078840183),
+                  c(0.21263900587151), c(0.71516867876775), c(0.072192315360733),
+                  c(0.019330818715591), c(0.11919477979462), c(0.95053215224966)])
+}
+
+/// Whether `L` is too close to black or white for the saturation to be meaningful.
+fn is_achromatic<T: FreeChannelScalar>(L: T) -> bool {
+    L > num::cast(99.9999999).unwrap() || L < num::cast(1e-8).unwrap()
+}
+
+impl<T, A> FromColor<Lchuv<T, A>> for Hsluv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn from_color(from: &Lchuv<T, A>) -> Self {
+        let saturation = if is_achromatic(from.L()) {
+            T::zero()
+        } else {
+            let max_chroma = Lchuv::<T, A>::srgb_max_chroma(from.L(), from.hue());
+            from.chroma() / max_chroma * num::cast(100.0).unwrap()
+        };
+        Hsluv::from_channels(from.hue(), saturation, from.L())
+    }
+}
+
+impl<T, A> FromColor<Hsluv<T, A>> for Lchuv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn from_color(from: &Hsluv<T, A>) -> Self {
+        let L = from.lightness();
+        let chroma = if is_achromatic(L) {
+            T::zero()
+        } else {
+            let max_chroma = Lchuv::<T, A>::srgb_max_chroma(L, from.hue());
+            max_chroma * from.saturation() / num::cast(100.0).unwrap()
+        };
+        Lchuv::from_channels(L, chroma, from.hue())
+    }
+}
+
+impl<T, A> FromColor<EncodedColor<Rgb<T>, SrgbEncoding>> for Hsluv<T, A>
+    where T: FreeChannelScalar + PosNormalChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T> + FromAngle<Rad<T>>,
+          Rgb<T>: EncodableColor
+{
+    fn from_color(from: &EncodedColor<Rgb<T>, SrgbEncoding>) -> Self {
+        let linear = from.clone().decode();
+        let rgb = (linear.color().red(), linear.color().green(), linear.color().blue());
+        let (x, y, z) = xyz_from_srgb::<T>().transform_vector(rgb);
+        let luv = Luv::from_xyz(&Xyz::from_channels(x, y, z), &white_point());
+        Hsluv::from_color(&Lchuv::<T, A>::from_color(&luv))
+    }
+}
+
+impl<T, A> FromColor<Hsluv<T, A>> for EncodedColor<Rgb<T>, SrgbEncoding>
+    where T: FreeChannelScalar + PosNormalChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>,
+          Rgb<T>: EncodableColor
+{
+    fn from_color(from: &Hsluv<T, A>) -> Self {
+        if from.lightness() <= T::zero() {
+            let black = Rgb::from_channels(T::zero(), T::zero(), T::zero());
+            return black.with_encoding(SrgbEncoding::new());
+        }
+        let xyz = Luv::from_color(&Lchuv::from_color(from)).to_xyz(&white_point());
+        let srgb_from_xyz = xyz_from_srgb::<T>().inverse().unwrap();
+        let (r, g, b) = srgb_from_xyz.transform_vector(xyz.to_tuple());
+        Rgb::from_channels(r, g, b)
+            .with_encoding(LinearEncoding::new())
+            .encode(SrgbEncoding::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn srgb(r: f64, g: f64, b: f64) -> EncodedColor<Rgb<f64>, SrgbEncoding> {
+        Rgb::from_channels(r, g, b).with_encoding(SrgbEncoding::new())
+    }
+
+    #[test]
+    fn test_construct() {
+        let c1 = Hsluv::from_channels(Deg(120.0), 80.0, 50.0);
+        assert_relative_eq!(c1.hue(), Deg(120.0));
+        assert_relative_eq!(c1.saturation(), 80.0);
+        assert_relative_eq!(c1.lightness(), 50.0);
+        assert_eq!(c1.to_tuple(), (Deg(120.0), 80.0, 50.0));
+        assert_relative_eq!(Hsluv::from_tuple(c1.to_tuple()), c1);
+        assert_relative_eq!(c1.lerp(&Hsluv::from_channels(Deg(140.0), 60.0, 70.0), 0.5),
+                            Hsluv::from_channels(Deg(130.0), 70.0, 60.0));
+    }
+
+    #[test]
+    fn test_from_lchuv() {
+        let c1 = Lchuv::from_channels(50.0, 34.437712, Deg(120.0));
+        let t1 = Hsluv::from_color(&c1);
+        assert_relative_eq!(t1, Hsluv::from_channels(Deg(120.0), 50.0, 50.0), epsilon=1e-6);
+        assert_relative_eq!(Lchuv::from_color(&t1), c1, epsilon=1e-9);
+
+        let white = Hsluv::from_color(&Lchuv::from_channels(100.0, 0.0, Deg(20.0)));
+        assert_relative_eq!(white, Hsluv::from_channels(Deg(20.0), 0.0, 100.0));
+    }
+
+    #[test]
+    fn test_from_srgb() {
+        let red = Hsluv::from_color(&srgb(1.0, 0.0, 0.0));
+        assert_relative_eq!(red, Hsluv::from_channels(Deg(12.177051), 100.0, 53.237116),
+            epsilon=1e-5);
+        assert_relative_eq!(EncodedColor::from_color(&red), srgb(1.0, 0.0, 0.0), epsilon=1e-6);
+
+        let blue = Hsluv::from_color(&srgb(0.0, 0.0, 1.0));
+        assert_relative_eq!(blue, Hsluv::from_channels(Deg(265.874320), 100.0, 32.300873),
+            epsilon=1e-5);
+        assert_relative_eq!(EncodedColor::from_color(&blue), srgb(0.0, 0.0, 1.0), epsilon=1e-6);
+
+        let c1 = srgb(0.2, 0.6, 0.4);
+        let t1 = Hsluv::from_color(&c1);
+        assert_relative_eq!(t1, Hsluv::from_channels(Deg(143.898662), 85.249172, 56.530227),
+            epsilon=1e-5);
+        assert_relative_eq!(EncodedColor::from_color(&t1), c1, epsilon=1e-6);
+
+        assert_relative_eq!(EncodedColor::from_color(&Hsluv::from_channels(Deg(0.0), 0.0, 0.0)),
+                            srgb(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_always_in_gamut() {
+        for &lightness in [5.0, 30.0, 60.0, 95.0].iter() {
+            for hue in (0..12).map(|i| Deg(i as f64 * 30.0)) {
+                let color: EncodedColor<Rgb<f64>, SrgbEncoding> =
+                    EncodedColor::from_color(&Hsluv::from_channels(hue, 100.0, lightness));
+                let rgb = color.color();
+                for &channel in [rgb.red(), rgb.green(), rgb.blue()].iter() {
+                    assert!(channel > -1e-6 && channel < 1.0 + 1e-6);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::fmt;
+use num;
+use encoding::{ChannelDecoder, ChannelEncoder, ColorEncoding, GammaEncoding, LinearEncoding,
+               SrgbEncoding, Rec709Encoding, PqEncoding, HlgEncoding, Bt1886Encoding,
+               ParametricCurveEncoding, LutEncoding};
+
+/// The number of entries used when an encoding has to be sampled into a table.
+const SAMPLED_CURVE_SIZE: usize = 4096;
+
+/// Encodings that can be stored as an ICC tone curve.
+pub trait ToIccCurve<T> {
+    /// Get the curve, exactly if it has a parametric form and sampled otherwise.
+    fn to_icc_curve(&self) -> IccCurve<T>;
+}
+
+/// A tone curve read from an ICC `curv` or `para` tag.
+///
+/// Like the curves in a profile, decoding maps device values to linear values and
+/// encoding goes the other way.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IccCurve<T> {
+    /// A pure power function, stored as a `curv` with zero or one entries.
+    Gamma(T),
+    /// One of the five ICC `parametricCurveType` functions.
+    Parametric(ParametricCurveEncoding<T>),
+    /// A sampled curve, stored as a `curv` with a table of entries.
+    Table(LutEncoding<T>),
+}
+
+impl<T> IccCurve<T>
+    where T: num::Float
+{
+    /// Construct a curve that leaves values unchanged.
+    pub fn identity() -> Self {
+        IccCurve::Gamma(T::one())
+    }
+
+    /// Build a table curve by sampling the decoding curve of an encoding.
+    ///
+    /// Values are clamped to [0, 1], the range a `curv` table can hold.
+    pub fn sampled<D>(decoder: &D, size: usize) -> Self
+        where D: ChannelDecoder
+    {
+        let last: T = num::cast(size - 1).unwrap();
+        let table = (0..size)
+            .map(|i| decoder.decode_channel(num::cast::<_, T>(i).unwrap() / last))
+            .map(|val| val.max(T::zero()).min(T::one()))
+            .collect();
+        IccCurve::Table(LutEncoding::new(table))
+    }
+}
+
+impl<T> ChannelDecoder for IccCurve<T>
+    where T: num::Float
+{
+    fn decode_channel<U>(&self, val: U) -> U
+        where U: num::Float
+    {
+        match *self {
+            IccCurve::Gamma(gamma) => GammaEncoding::new(gamma).decode_channel(val),
+            IccCurve::Parametric(ref curve) => curve.decode_channel(val),
+            IccCurve::Table(ref table) => table.decode_channel(val),
+        }
+    }
+}
+
+impl<T> ChannelEncoder for IccCurve<T>
+    where T: num::Float
+{
+    fn encode_channel<U>(&self, val: U) -> U
+        where U: num::Float
+    {
+        match *self {
+            IccCurve::Gamma(gamma) => GammaEncoding::new(gamma).encode_channel(val),
+            IccCurve::Parametric(ref curve) => curve.encode_channel(val),
+            IccCurve::Table(ref table) => table.encode_channel(val),
+        }
+    }
+}
+
+impl<T: num::Float> ColorEncoding for IccCurve<T> {}
+
+impl<T: num::Float> ToIccCurve<T> for IccCurve<T> {
+    fn to_icc_curve(&self) -> IccCurve<T> {
+        self.clone()
+    }
+}
+
+impl<T: num::Float> ToIccCurve<T> for LinearEncoding {
+    fn to_icc_curve(&self) -> IccCurve<T> {
+        IccCurve::identity()
+    }
+}
+
+impl<T: num::Float> ToIccCurve<T> for GammaEncoding<T> {
+    fn to_icc_curve(&self) -> IccCurve<T> {
+        IccCurve::Parametric(ParametricCurveEncoding::gamma(self.exponent()))
+    }
+}
+
+impl<T: num::Float> ToIccCurve<T> for SrgbEncoding {
+    fn to_icc_curve(&self) -> IccCurve<T> {
+        IccCurve::Parametric(ParametricCurveEncoding::srgb())
+    }
+}
+
+impl<T: num::Float> ToIccCurve<T> for Rec709Encoding {
+    fn to_icc_curve(&self) -> IccCurve<T> {
+        let curve = ParametricCurveEncoding::iec_61966_2_1(num::cast(1.0 / 0.45).unwrap(),
+                                                           num::cast(1.0 / 1.099).unwrap(),
+                                                           num::cast(0.099 / 1.099).unwrap(),
+                                                           num::cast(1.0 / 4.5).unwrap(),
+                                                           num::cast(0.081).unwrap());
+        IccCurve::Parametric(curve)
+    }
+}
+
+impl<T: num::Float> ToIccCurve<T> for Bt1886Encoding<T> {
+    fn to_icc_curve(&self) -> IccCurve<T> {
+        // Relative to white, the EOTF is ((Lw^(1/γ) - Lb^(1/γ)) V + Lb^(1/γ))^γ / Lw.
+        let gamma: T = num::cast(2.4).unwrap();
+        let black_root = self.black_luminance().powf(gamma.recip());
+        let white_root = self.white_luminance().powf(gamma.recip());
+        let gain = (white_root - black_root) / white_root;
+        let offset = black_root / white_root;
+
+        IccCurve::Parametric(ParametricCurveEncoding::cie_122(gamma, gain, offset))
+    }
+}
+
+impl<T: num::Float> ToIccCurve<T> for ParametricCurveEncoding<T> {
+    fn to_icc_curve(&self) -> IccCurve<T> {
+        IccCurve::Parametric(self.clone())
+    }
+}
+
+impl<T: num::Float> ToIccCurve<T> for LutEncoding<T> {
+    fn to_icc_curve(&self) -> IccCurve<T> {
+        IccCurve::Table(self.clone())
+    }
+}
+
+impl<T: num::Float> ToIccCurve<T> for PqEncoding<T> {
+    fn to_icc_curve(&self) -> IccCurve<T> {
+        IccCurve::sampled(self, SAMPLED_CURVE_SIZE)
+    }
+}
+
+impl<T: num::Float> ToIccCurve<T> for HlgEncoding<T> {
+    fn to_icc_curve(&self) -> IccCurve<T> {
+        IccCurve::sampled(self, SAMPLED_CURVE_SIZE)
+    }
+}
+
+impl<T> fmt::Display for IccCurve<T>
+    where T: num::Float + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IccCurve::Gamma(gamma) => write!(f, "γ={}", gamma),
+            IccCurve::Parametric(ref curve) => write!(f, "{}", curve),
+            IccCurve::Table(ref table) => write!(f, "{}", table),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_curves() {
+        let gamma = IccCurve::Gamma(2.2);
+        assert_relative_eq!(gamma.decode_channel(0.5), 0.5f64.powf(2.2), epsilon = 1e-6);
+        assert_relative_eq!(gamma.encode_channel(0.5f64.powf(2.2)), 0.5, epsilon = 1e-6);
+
+        let identity = IccCurve::<f64>::identity();
+        assert_relative_eq!(identity.decode_channel(0.3), 0.3);
+        assert_relative_eq!(identity.encode_channel(0.3), 0.3);
+
+        let para = IccCurve::Parametric(ParametricCurveEncoding::<f64>::srgb());
+        assert_relative_eq!(para.decode_channel(0.5), 0.21404114, epsilon = 1e-6);
+
+        let table = IccCurve::Table(LutEncoding::new(vec![0.0, 0.25, 1.0]));
+        assert_relative_eq!(table.decode_channel(0.25), 0.125, epsilon = 1e-6);
+        assert_relative_eq!(table.encode_channel(0.125), 0.25, epsilon = 1e-4);
+        assert_eq!(format!("{}", table), "LUT(3 entries)");
+    }
+
+    #[test]
+    fn test_to_icc_curve() {
+        fn check<E>(encoding: E)
+            where E: ChannelDecoder + ToIccCurve<f64>
+        {
+            let curve = encoding.to_icc_curve();
+            for &val in [0.0, 0.01, 0.05, 0.2, 0.5, 0.9, 1.0].iter() {
+                assert_relative_eq!(curve.decode_channel(val),
+                                    encoding.decode_channel(val),
+                                    epsilon = 1e-4);
+            }
+        }
+
+        check(LinearEncoding::new());
+        check(SrgbEncoding::new());
+        check(Rec709Encoding::new());
+        check(GammaEncoding::new(2.6));
+        check(Bt1886Encoding::new(0.1, 100.0));
+        check(Bt1886Encoding::default());
+        check(ParametricCurveEncoding::romm());
+        check(LutEncoding::new(vec![0.0, 0.3, 1.0]));
+        check(PqEncoding::new(10000.0));
+        check(HlgEncoding::default());
+
+        let pq = PqEncoding::new(1000.0).to_icc_curve();
+        assert_relative_eq!(pq.decode_channel(1.0), 1.0);
+    }
+}
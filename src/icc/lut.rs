@@ -0,0 +1,322 @@
+use num;
+use encoding::ChannelDecoder;
+use linalg::Matrix3;
+use icc::curve::IccCurve;
+
+/// Which way a `LutTransform` converts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LutDirection {
+    /// Device to PCS, stored in an `mAB ` tag.
+    AToB,
+    /// PCS to device, stored in an `mBA ` tag.
+    BToA,
+}
+
+/// A multi-dimensional color lookup table with uniformly spaced grid points.
+///
+/// Values are normalized to [0, 1] and stored with the first input channel varying
+/// slowest, as in an ICC profile.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Clut<T> {
+    grid_points: Vec<usize>,
+    output_channels: usize,
+    values: Vec<T>,
+}
+
+impl<T> Clut<T>
+    where T: num::Float
+{
+    /// Construct a lookup table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a dimension has no grid points, or if the number of values is not the
+    /// product of the grid points and the output channel count.
+    pub fn new(grid_points: Vec<usize>, output_channels: usize, values: Vec<T>) -> Self {
+        assert!(grid_points.iter().all(|&points| points > 0),
+                "Every lookup table dimension needs at least one grid point");
+        assert_eq!(grid_points.iter().product::<usize>() * output_channels, values.len());
+
+        Clut {
+            grid_points,
+            output_channels,
+            values,
+        }
+    }
+
+    pub fn grid_points(&self) -> &[usize] {
+        &self.grid_points
+    }
+
+    pub fn input_channels(&self) -> usize {
+        self.grid_points.len()
+    }
+
+    pub fn output_channels(&self) -> usize {
+        self.output_channels
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Look up a point using multilinear interpolation, clamping inputs to [0, 1].
+    pub fn lookup(&self, input: &[T]) -> Vec<T> {
+        let dims = self.grid_points.len();
+        assert_eq!(input.len(), dims);
+
+        let mut strides = vec![self.output_channels; dims];
+        for i in (0..dims.saturating_sub(1)).rev() {
+            strides[i] = strides[i + 1] * self.grid_points[i + 1];
+        }
+
+        let mut base = 0;
+        let mut fracs = Vec::with_capacity(dims);
+        for (i, &val) in input.iter().enumerate() {
+            let last = self.grid_points[i] - 1;
+            if last == 0 {
+                fracs.push(T::zero());
+                continue;
+            }
+            let scaled = val.max(T::zero()).min(T::one()) * num::cast(last).unwrap();
+            let index = scaled.floor().to_usize().unwrap().min(last - 1);
+            fracs.push(scaled - num::cast(index).unwrap());
+            base += index * strides[i];
+        }
+
+        let mut out = vec![T::zero(); self.output_channels];
+        for corner in 0..(1usize << dims) {
+            let mut weight = T::one();
+            let mut offset = base;
+            for i in 0..dims {
+                if corner & (1 << (dims - 1 - i)) != 0 {
+                    if self.grid_points[i] == 1 {
+                        weight = T::zero();
+                        break;
+                    }
+                    weight = weight * fracs[i];
+                    offset += strides[i];
+                } else {
+                    weight = weight * (T::one() - fracs[i]);
+                }
+            }
+            if weight == T::zero() {
+                continue;
+            }
+            for (channel, out) in out.iter_mut().enumerate() {
+                *out = *out + weight * self.values[offset + channel];
+            }
+        }
+
+        out
+    }
+}
+
+/// A general lookup-table based transform from an ICC `mAB ` or `mBA ` tag.
+///
+/// An A-to-B transform applies the A curves, the CLUT, the M curves, the matrix and then
+/// the B curves. A B-to-A transform applies the same elements in the opposite order. Only
+/// the B curves are required. All values are normalized to [0, 1], so PCS values need to
+/// go through `IccProfile::pcs_to_xyz` or `IccProfile::xyz_to_pcs`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LutTransform<T> {
+    direction: LutDirection,
+    input_channels: usize,
+    output_channels: usize,
+    a_curves: Option<Vec<IccCurve<T>>>,
+    clut: Option<Clut<T>>,
+    m_curves: Option<Vec<IccCurve<T>>>,
+    matrix: Option<(Matrix3<T>, [T; 3])>,
+    b_curves: Vec<IccCurve<T>>,
+}
+
+impl<T> LutTransform<T>
+    where T: num::Float
+{
+    /// Construct a transform made of only B curves, one per channel.
+    pub fn new(direction: LutDirection, b_curves: Vec<IccCurve<T>>) -> Self {
+        let channels = b_curves.len();
+        LutTransform {
+            direction,
+            input_channels: channels,
+            output_channels: channels,
+            a_curves: None,
+            clut: None,
+            m_curves: None,
+            matrix: None,
+            b_curves,
+        }
+    }
+
+    /// Set the A curves, which sit on the device side of the transform.
+    pub fn with_a_curves(mut self, curves: Vec<IccCurve<T>>) -> Self {
+        match self.direction {
+            LutDirection::AToB => self.input_channels = curves.len(),
+            LutDirection::BToA => self.output_channels = curves.len(),
+        }
+        self.a_curves = Some(curves);
+        self
+    }
+
+    /// Set the lookup table, which converts between the device and PCS channel counts.
+    pub fn with_clut(mut self, clut: Clut<T>) -> Self {
+        self.input_channels = clut.input_channels();
+        self.output_channels = clut.output_channels();
+        self.clut = Some(clut);
+        self
+    }
+
+    pub fn with_m_curves(mut self, curves: Vec<IccCurve<T>>) -> Self {
+        self.m_curves = Some(curves);
+        self
+    }
+
+    /// Set the 3x3 matrix and the offset added after it.
+    pub fn with_matrix(mut self, matrix: Matrix3<T>, offset: [T; 3]) -> Self {
+        self.matrix = Some((matrix, offset));
+        self
+    }
+
+    pub fn direction(&self) -> LutDirection {
+        self.direction
+    }
+
+    pub fn input_channels(&self) -> usize {
+        self.input_channels
+    }
+
+    pub fn output_channels(&self) -> usize {
+        self.output_channels
+    }
+
+    pub fn a_curves(&self) -> Option<&[IccCurve<T>]> {
+        self.a_curves.as_deref()
+    }
+
+    pub fn clut(&self) -> Option<&Clut<T>> {
+        self.clut.as_ref()
+    }
+
+    pub fn m_curves(&self) -> Option<&[IccCurve<T>]> {
+        self.m_curves.as_deref()
+    }
+
+    pub fn matrix(&self) -> Option<&(Matrix3<T>, [T; 3])> {
+        self.matrix.as_ref()
+    }
+
+    pub fn b_curves(&self) -> &[IccCurve<T>] {
+        &self.b_curves
+    }
+
+    /// Apply the transform to normalized input values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` does not have `input_channels()` values.
+    pub fn apply(&self, input: &[T]) -> Vec<T> {
+        assert_eq!(input.len(), self.input_channels);
+
+        let mut values = input.to_vec();
+        match self.direction {
+            LutDirection::AToB => {
+                self.apply_curves(self.a_curves.as_ref(), &mut values);
+                values = self.apply_clut(values);
+                self.apply_curves(self.m_curves.as_ref(), &mut values);
+                self.apply_matrix(&mut values);
+                self.apply_curves(Some(&self.b_curves), &mut values);
+            }
+            LutDirection::BToA => {
+                self.apply_curves(Some(&self.b_curves), &mut values);
+                self.apply_matrix(&mut values);
+                self.apply_curves(self.m_curves.as_ref(), &mut values);
+                values = self.apply_clut(values);
+                self.apply_curves(self.a_curves.as_ref(), &mut values);
+            }
+        }
+        values
+    }
+
+    fn apply_curves(&self, curves: Option<&Vec<IccCurve<T>>>, values: &mut [T]) {
+        if let Some(curves) = curves {
+            for (val, curve) in values.iter_mut().zip(curves.iter()) {
+                *val = curve.decode_channel(val.max(T::zero()).min(T::one()));
+            }
+        }
+    }
+
+    fn apply_clut(&self, values: Vec<T>) -> Vec<T> {
+        match self.clut {
+            Some(ref clut) => clut.lookup(&values),
+            None => values,
+        }
+    }
+
+    fn apply_matrix(&self, values: &mut [T]) {
+        if let Some((ref matrix, offset)) = self.matrix {
+            let (x, y, z) = matrix.transform_vector((values[0], values[1], values[2]));
+            values[0] = x + offset[0];
+            values[1] = y + offset[1];
+            values[2] = z + offset[2];
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use encoding::ParametricCurveEncoding;
+
+    #[test]
+    fn test_clut() {
+        let identity = Clut::new(vec![2, 2],
+                                 2,
+                                 vec![0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 1.0]);
+        assert_eq!(identity.lookup(&[0.25, 0.75]), vec![0.25, 0.75]);
+        assert_eq!(identity.lookup(&[1.5, -0.5]), vec![1.0, 0.0]);
+
+        let sum = Clut::new(vec![3, 2, 2],
+                            1,
+                            vec![0.0, 0.1, 0.1, 0.2, 0.5, 0.6, 0.6, 0.7, 1.0, 1.1, 1.1, 1.2]);
+        assert_relative_eq!(sum.lookup(&[0.25, 0.5, 1.0])[0], 0.4, epsilon = 1e-6);
+        assert_relative_eq!(sum.lookup(&[1.0, 1.0, 1.0])[0], 1.2, epsilon = 1e-6);
+
+        let flat = Clut::new(vec![1, 2], 1, vec![0.2, 0.4]);
+        assert_relative_eq!(flat.lookup(&[0.7, 0.5])[0], 0.3, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_pipeline() {
+        let swap = Clut::new(vec![2, 2, 2],
+                             3,
+                             vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0,
+                                  0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0]);
+        let square = IccCurve::Parametric(ParametricCurveEncoding::gamma(2.0));
+        let transform = LutTransform::new(LutDirection::AToB, vec![IccCurve::identity(); 3])
+            .with_a_curves(vec![IccCurve::identity(); 3])
+            .with_clut(swap)
+            .with_m_curves(vec![square.clone(), square.clone(), square])
+            .with_matrix(Matrix3::new([0.5, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.5]),
+                         [0.1, 0.1, 0.1]);
+
+        let out = transform.apply(&[0.2, 0.4, 0.6]);
+        assert_relative_eq!(out[0], 0.28, epsilon = 1e-6);
+        assert_relative_eq!(out[1], 0.18, epsilon = 1e-6);
+        assert_relative_eq!(out[2], 0.12, epsilon = 1e-6);
+
+        let average = Clut::new(vec![2, 2, 2],
+                                1,
+                                (0..8)
+                                    .map(|i| (i & 1) as f64 + ((i >> 1) & 1) as f64 +
+                                             (i >> 2) as f64)
+                                    .map(|sum| sum / 3.0)
+                                    .collect());
+        let root = IccCurve::Gamma(0.5);
+        let inverse = LutTransform::new(LutDirection::BToA, vec![root; 3])
+            .with_clut(average)
+            .with_a_curves(vec![IccCurve::identity()]);
+        assert_eq!(inverse.input_channels(), 3);
+        assert_eq!(inverse.output_channels(), 1);
+        assert_relative_eq!(inverse.apply(&[0.25, 0.49, 0.81])[0], 0.7, epsilon = 1e-6);
+    }
+}
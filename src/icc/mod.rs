@@ -0,0 +1,140 @@
+//! Reading and writing ICC color profiles.
+//!
+//! `IccProfile::parse` reads the header and tag table of a version 2 or version 4 profile.
+//! Matrix/TRC RGB profiles can be turned into an `EncodedColorSpace` with
+//! `IccProfile::to_color_space`, gray profiles expose their tone curve, and `mAB `/`mBA `
+//! profiles expose their lookup tables as a `LutTransform`.
+//!
+//! In the other direction, `IccProfile::from_color_space` describes any `EncodedColorSpace`
+//! whose encoding implements `ToIccCurve` as a version 4 matrix/TRC profile, and
+//! `IccProfile::to_bytes` serializes a profile, with its MD5 profile ID for version 4.
+
+use std::error;
+use std::fmt;
+
+pub mod curve;
+pub mod lut;
+pub mod md5;
+pub mod profile;
+pub mod write;
+mod parse;
+
+pub use self::curve::{IccCurve, ToIccCurve};
+pub use self::lut::{Clut, LutDirection, LutTransform};
+pub use self::profile::{IccHeader, IccProfile, Tag, current_date_time};
+pub use self::write::compute_profile_id;
+
+/// A four byte ICC signature, such as a tag name or a type name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Signature(pub [u8; 4]);
+
+impl Signature {
+    pub const MAGIC: Signature = Signature(*b"acsp");
+
+    pub const DISPLAY_CLASS: Signature = Signature(*b"mntr");
+    pub const INPUT_CLASS: Signature = Signature(*b"scnr");
+    pub const OUTPUT_CLASS: Signature = Signature(*b"prtr");
+    pub const COLOR_SPACE_CLASS: Signature = Signature(*b"spac");
+
+    pub const RGB_DATA: Signature = Signature(*b"RGB ");
+    pub const GRAY_DATA: Signature = Signature(*b"GRAY");
+    pub const CMYK_DATA: Signature = Signature(*b"CMYK");
+    pub const XYZ_DATA: Signature = Signature(*b"XYZ ");
+    pub const LAB_DATA: Signature = Signature(*b"Lab ");
+
+    pub const PROFILE_DESCRIPTION: Signature = Signature(*b"desc");
+    pub const COPYRIGHT: Signature = Signature(*b"cprt");
+    pub const MEDIA_WHITE_POINT: Signature = Signature(*b"wtpt");
+    pub const CHROMATIC_ADAPTATION: Signature = Signature(*b"chad");
+    pub const RED_COLORANT: Signature = Signature(*b"rXYZ");
+    pub const GREEN_COLORANT: Signature = Signature(*b"gXYZ");
+    pub const BLUE_COLORANT: Signature = Signature(*b"bXYZ");
+    pub const RED_TRC: Signature = Signature(*b"rTRC");
+    pub const GREEN_TRC: Signature = Signature(*b"gTRC");
+    pub const BLUE_TRC: Signature = Signature(*b"bTRC");
+    pub const GRAY_TRC: Signature = Signature(*b"kTRC");
+    pub const A_TO_B0: Signature = Signature(*b"A2B0");
+    pub const A_TO_B1: Signature = Signature(*b"A2B1");
+    pub const A_TO_B2: Signature = Signature(*b"A2B2");
+    pub const B_TO_A0: Signature = Signature(*b"B2A0");
+    pub const B_TO_A1: Signature = Signature(*b"B2A1");
+    pub const B_TO_A2: Signature = Signature(*b"B2A2");
+
+    pub const XYZ_TYPE: Signature = Signature(*b"XYZ ");
+    pub const CURVE_TYPE: Signature = Signature(*b"curv");
+    pub const PARAMETRIC_CURVE_TYPE: Signature = Signature(*b"para");
+    pub const S15_FIXED16_ARRAY_TYPE: Signature = Signature(*b"sf32");
+    pub const LUT_A_TO_B_TYPE: Signature = Signature(*b"mAB ");
+    pub const LUT_B_TO_A_TYPE: Signature = Signature(*b"mBA ");
+    pub const TEXT_TYPE: Signature = Signature(*b"text");
+    pub const TEXT_DESCRIPTION_TYPE: Signature = Signature(*b"desc");
+    pub const MULTI_LOCALIZED_UNICODE_TYPE: Signature = Signature(*b"mluc");
+
+    pub fn from_u32(val: u32) -> Self {
+        Signature([(val >> 24) as u8, (val >> 16) as u8, (val >> 8) as u8, val as u8])
+    }
+
+    pub fn to_u32(self) -> u32 {
+        (u32::from(self.0[0]) << 24) | (u32::from(self.0[1]) << 16) |
+        (u32::from(self.0[2]) << 8) | u32::from(self.0[3])
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for &byte in self.0.iter() {
+            if byte.is_ascii_graphic() || byte == b' ' {
+                write!(f, "{}", byte as char)?;
+            } else {
+                write!(f, "\\x{:02x}", byte)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An error encountered while reading a profile or converting it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum IccError {
+    /// The profile header is missing or malformed.
+    InvalidHeader(&'static str),
+    /// The data of a tag is malformed.
+    InvalidTag(Signature, &'static str),
+    /// A tag needed for the requested operation is not in the profile.
+    MissingTag(Signature),
+    /// A tag has a type that cannot be used for the requested operation.
+    UnsupportedTagType(Signature, Signature),
+    /// The profile is well formed but cannot be represented as requested.
+    UnsupportedProfile(&'static str),
+}
+
+impl fmt::Display for IccError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IccError::InvalidHeader(reason) => write!(f, "invalid ICC header: {}", reason),
+            IccError::InvalidTag(tag, reason) => write!(f, "invalid '{}' tag: {}", tag, reason),
+            IccError::MissingTag(tag) => write!(f, "missing '{}' tag", tag),
+            IccError::UnsupportedTagType(tag, ty) => {
+                write!(f, "unsupported type '{}' for '{}' tag", ty, tag)
+            }
+            IccError::UnsupportedProfile(reason) => write!(f, "unsupported profile: {}", reason),
+        }
+    }
+}
+
+impl error::Error for IccError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_signature() {
+        assert_eq!(Signature::from_u32(0x61637370), Signature::MAGIC);
+        assert_eq!(Signature::RGB_DATA.to_u32(), 0x52474220);
+        assert_eq!(format!("{}", Signature::LUT_A_TO_B_TYPE), "mAB ");
+        assert_eq!(format!("{}", Signature([b'a', 0, b'b', 0xff])), "a\\x00b\\xff");
+        assert_eq!(format!("{}", IccError::MissingTag(Signature::RED_TRC)),
+                   "missing 'rTRC' tag");
+    }
+}
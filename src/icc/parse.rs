@@ -0,0 +1,318 @@
+use num;
+use channel::FreeChannelScalar;
+use encoding::{LutEncoding, ParametricCurveEncoding};
+use linalg::Matrix3;
+use xyz::Xyz;
+use icc::Signature;
+use icc::curve::IccCurve;
+use icc::lut::{Clut, LutDirection, LutTransform};
+use icc::profile::Tag;
+
+pub type ParseResult<V> = Result<V, &'static str>;
+
+const UNEXPECTED_END: &str = "unexpected end of data";
+
+/// Bounds-checked big-endian reads from a byte slice.
+#[derive(Clone, Copy, Debug)]
+pub struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Reader { data }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn bytes(&self, offset: usize, len: usize) -> ParseResult<&'a [u8]> {
+        let end = offset.checked_add(len).ok_or(UNEXPECTED_END)?;
+        self.data.get(offset..end).ok_or(UNEXPECTED_END)
+    }
+
+    pub fn sub(&self, offset: usize) -> ParseResult<Reader<'a>> {
+        self.data.get(offset..).map(Reader::new).ok_or(UNEXPECTED_END)
+    }
+
+    pub fn u8(&self, offset: usize) -> ParseResult<u8> {
+        self.data.get(offset).cloned().ok_or(UNEXPECTED_END)
+    }
+
+    pub fn u16(&self, offset: usize) -> ParseResult<u16> {
+        let bytes = self.bytes(offset, 2)?;
+        Ok((u16::from(bytes[0]) << 8) | u16::from(bytes[1]))
+    }
+
+    pub fn u32(&self, offset: usize) -> ParseResult<u32> {
+        let bytes = self.bytes(offset, 4)?;
+        Ok(bytes.iter().fold(0, |acc, &byte| (acc << 8) | u32::from(byte)))
+    }
+
+    pub fn u64(&self, offset: usize) -> ParseResult<u64> {
+        Ok((u64::from(self.u32(offset)?) << 32) | u64::from(self.u32(offset + 4)?))
+    }
+
+    pub fn signature(&self, offset: usize) -> ParseResult<Signature> {
+        self.u32(offset).map(Signature::from_u32)
+    }
+
+    pub fn s15_fixed16<T: num::Float>(&self, offset: usize) -> ParseResult<T> {
+        let raw = self.u32(offset)? as i32;
+        Ok(num::cast::<_, T>(raw).unwrap() / num::cast(65536.0).unwrap())
+    }
+
+    pub fn u8_fixed8<T: num::Float>(&self, offset: usize) -> ParseResult<T> {
+        let raw = self.u16(offset)?;
+        Ok(num::cast::<_, T>(raw).unwrap() / num::cast(256.0).unwrap())
+    }
+
+    pub fn xyz<T>(&self, offset: usize) -> ParseResult<Xyz<T>>
+        where T: num::Float + FreeChannelScalar
+    {
+        Ok(Xyz::from_channels(self.s15_fixed16(offset)?,
+                              self.s15_fixed16(offset + 4)?,
+                              self.s15_fixed16(offset + 8)?))
+    }
+}
+
+/// Round a byte count up to the four byte alignment used between tag elements.
+pub fn align4(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+/// Parse the data of one tag, given the bytes from its start to the end of the profile.
+pub fn parse_tag<T>(data: Reader, size: usize) -> ParseResult<Tag<T>>
+    where T: num::Float + FreeChannelScalar
+{
+    let type_sig = data.signature(0)?;
+    let tag = match type_sig {
+        Signature::XYZ_TYPE => {
+            let count = size.checked_sub(8).ok_or(UNEXPECTED_END)? / 12;
+            Tag::Xyz((0..count).map(|i| data.xyz(8 + 12 * i)).collect::<ParseResult<_>>()?)
+        }
+        Signature::CURVE_TYPE | Signature::PARAMETRIC_CURVE_TYPE => {
+            Tag::Curve(parse_curve(data)?.0)
+        }
+        Signature::S15_FIXED16_ARRAY_TYPE => {
+            let count = size.checked_sub(8).ok_or(UNEXPECTED_END)? / 4;
+            Tag::S15Fixed16Array((0..count)
+                .map(|i| data.s15_fixed16(8 + 4 * i))
+                .collect::<ParseResult<_>>()?)
+        }
+        Signature::LUT_A_TO_B_TYPE => Tag::Lut(parse_lut(data, LutDirection::AToB)?),
+        Signature::LUT_B_TO_A_TYPE => Tag::Lut(parse_lut(data, LutDirection::BToA)?),
+        Signature::TEXT_TYPE => {
+            let bytes = data.bytes(8, size.checked_sub(8).ok_or(UNEXPECTED_END)?)?;
+            Tag::Text(parse_ascii(bytes))
+        }
+        Signature::TEXT_DESCRIPTION_TYPE => {
+            let count = data.u32(8)? as usize;
+            Tag::Text(parse_ascii(data.bytes(12, count)?))
+        }
+        Signature::MULTI_LOCALIZED_UNICODE_TYPE => Tag::Text(parse_mluc(data)?),
+        _ => Tag::Unknown(type_sig, data.bytes(0, size)?.to_vec()),
+    };
+    Ok(tag)
+}
+
+/// Parse a `curv` or `para` element, returning the curve and its unaligned size in bytes.
+pub fn parse_curve<T>(data: Reader) -> ParseResult<(IccCurve<T>, usize)>
+    where T: num::Float
+{
+    match data.signature(0)? {
+        Signature::CURVE_TYPE => {
+            let count = data.u32(8)? as usize;
+            let curve = match count {
+                0 => IccCurve::identity(),
+                1 => IccCurve::Gamma(data.u8_fixed8(12)?),
+                _ => {
+                    let max: T = num::cast(65535.0).unwrap();
+                    let table = (0..count)
+                        .map(|i| data.u16(12 + 2 * i).map(|v| num::cast::<_, T>(v).unwrap() / max))
+                        .collect::<ParseResult<Vec<T>>>()?;
+                    IccCurve::Table(LutEncoding::new(table))
+                }
+            };
+            Ok((curve, 12 + 2 * count))
+        }
+        Signature::PARAMETRIC_CURVE_TYPE => {
+            let function_type = data.u16(8)?;
+            let count = ParametricCurveEncoding::<T>::param_count(function_type)
+                .ok_or("unknown parametric curve function type")?;
+            let params = (0..count)
+                .map(|i| data.s15_fixed16(12 + 4 * i))
+                .collect::<ParseResult<Vec<T>>>()?;
+            let curve = ParametricCurveEncoding::from_params(function_type, &params).unwrap();
+            Ok((IccCurve::Parametric(curve), 12 + 4 * count))
+        }
+        _ => Err("expected a curve or parametric curve"),
+    }
+}
+
+fn parse_curves<T>(data: Reader, offset: usize, count: usize) -> ParseResult<Vec<IccCurve<T>>>
+    where T: num::Float
+{
+    let mut curves = Vec::with_capacity(count);
+    let mut position = offset;
+    for _ in 0..count {
+        let (curve, len) = parse_curve(data.sub(position)?)?;
+        curves.push(curve);
+        position += align4(len);
+    }
+    Ok(curves)
+}
+
+fn parse_lut<T>(data: Reader, direction: LutDirection) -> ParseResult<LutTransform<T>>
+    where T: num::Float
+{
+    let input_channels = data.u8(8)? as usize;
+    let output_channels = data.u8(9)? as usize;
+    let b_offset = data.u32(12)? as usize;
+    let matrix_offset = data.u32(16)? as usize;
+    let m_offset = data.u32(20)? as usize;
+    let clut_offset = data.u32(24)? as usize;
+    let a_offset = data.u32(28)? as usize;
+
+    if input_channels == 0 || output_channels == 0 {
+        return Err("lookup tables need at least one input and output channel");
+    }
+    if clut_offset == 0 && input_channels != output_channels {
+        return Err("lookup tables without a CLUT must keep the channel count");
+    }
+    if b_offset == 0 {
+        return Err("lookup tables need B curves");
+    }
+
+    let (device_channels, pcs_channels) = match direction {
+        LutDirection::AToB => (input_channels, output_channels),
+        LutDirection::BToA => (output_channels, input_channels),
+    };
+    if (matrix_offset != 0 || m_offset != 0) && pcs_channels != 3 {
+        return Err("matrix and M curves need three PCS channels");
+    }
+
+    let mut transform = LutTransform::new(direction, parse_curves(data, b_offset, pcs_channels)?);
+    if matrix_offset != 0 {
+        let values = (0..12)
+            .map(|i| data.s15_fixed16(matrix_offset + 4 * i))
+            .collect::<ParseResult<Vec<T>>>()?;
+        let mut matrix = [T::zero(); 9];
+        matrix.copy_from_slice(&values[..9]);
+        let offset = [values[9], values[10], values[11]];
+        transform = transform.with_matrix(Matrix3::new(matrix), offset);
+    }
+    if m_offset != 0 {
+        transform = transform.with_m_curves(parse_curves(data, m_offset, pcs_channels)?);
+    }
+    if clut_offset != 0 {
+        transform = transform.with_clut(parse_clut(data.sub(clut_offset)?,
+                                                   input_channels,
+                                                   output_channels)?);
+    }
+    if a_offset != 0 {
+        transform = transform.with_a_curves(parse_curves(data, a_offset, device_channels)?);
+    }
+    Ok(transform)
+}
+
+fn parse_clut<T>(data: Reader, input_channels: usize, output_channels: usize)
+                 -> ParseResult<Clut<T>>
+    where T: num::Float
+{
+    if input_channels > 16 {
+        return Err("lookup tables support at most 16 input channels");
+    }
+    let grid_points = (0..input_channels)
+        .map(|i| data.u8(i).map(|points| points as usize))
+        .collect::<ParseResult<Vec<usize>>>()?;
+    if grid_points.contains(&0) {
+        return Err("lookup table dimensions need at least one grid point");
+    }
+
+    let count = grid_points.iter()
+        .try_fold(output_channels, |acc, &points| acc.checked_mul(points))
+        .ok_or("lookup table is too large")?;
+    let values = match data.u8(16)? {
+        1 => {
+            let max: T = num::cast(255.0).unwrap();
+            data.bytes(20, count)?.iter().map(|&v| num::cast::<_, T>(v).unwrap() / max).collect()
+        }
+        2 => {
+            let max: T = num::cast(65535.0).unwrap();
+            (0..count)
+                .map(|i| data.u16(20 + 2 * i).map(|v| num::cast::<_, T>(v).unwrap() / max))
+                .collect::<ParseResult<Vec<T>>>()?
+        }
+        _ => return Err("lookup table precision must be one or two bytes"),
+    };
+
+    Ok(Clut::new(grid_points, output_channels, values))
+}
+
+fn parse_ascii(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+/// Read the first record of a `mluc` element, preferring English if it is present.
+fn parse_mluc(data: Reader) -> ParseResult<String> {
+    let count = data.u32(8)? as usize;
+    let record_size = data.u32(12)? as usize;
+    if count == 0 {
+        return Ok(String::new());
+    }
+    if record_size < 12 {
+        return Err("multi-localized unicode records are too small");
+    }
+
+    let mut chosen = 16;
+    for i in 0..count {
+        let record = 16 + i * record_size;
+        if data.bytes(record, 2)? == b"en" {
+            chosen = record;
+            break;
+        }
+    }
+
+    let len = data.u32(chosen + 4)? as usize;
+    let offset = data.u32(chosen + 8)? as usize;
+    let units = (0..len / 2).map(|i| data.u16(offset + 2 * i)).collect::<ParseResult<Vec<_>>>()?;
+    String::from_utf16(&units).map_err(|_| "invalid UTF-16 text")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reader() {
+        let data = [0x00, 0x01, 0x80, 0x00, 0xff, 0xff, 0x00, 0x00, 0x02, 0x33];
+        let reader = Reader::new(&data);
+        assert_eq!(reader.u16(0), Ok(1));
+        assert_eq!(reader.u32(0), Ok(0x00018000));
+        assert_relative_eq!(reader.s15_fixed16::<f64>(0).unwrap(), 1.5);
+        assert_relative_eq!(reader.s15_fixed16::<f64>(4).unwrap(), -1.0);
+        assert_relative_eq!(reader.u8_fixed8::<f64>(8).unwrap(), 2.19921875);
+        assert_eq!(reader.u32(8), Err(UNEXPECTED_END));
+        assert_eq!(reader.bytes(usize::MAX, 2), Err(UNEXPECTED_END));
+        assert_eq!(align4(13), 16);
+    }
+
+    #[test]
+    fn test_parse_curve() {
+        let gamma = [b'c', b'u', b'r', b'v', 0, 0, 0, 0, 0, 0, 0, 1, 0x01, 0xcd];
+        let (curve, len) = parse_curve::<f64>(Reader::new(&gamma)).unwrap();
+        assert_eq!(curve, IccCurve::Gamma(1.80078125));
+        assert_eq!(len, 14);
+
+        let table = [b'c', b'u', b'r', b'v', 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0xff, 0xff];
+        let (curve, _) = parse_curve::<f64>(Reader::new(&table)).unwrap();
+        assert_eq!(curve, IccCurve::Table(LutEncoding::new(vec![0.0, 1.0])));
+
+        let para = [b'p', b'a', b'r', b'a', 0, 0, 0, 0, 0, 9, 0, 0, 0, 2, 0, 0];
+        assert!(parse_curve::<f64>(Reader::new(&para)).is_err());
+        assert!(parse_curve::<f64>(Reader::new(&para[..10])).is_err());
+    }
+}
@@ -0,0 +1,641 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use num;
+use channel::{FreeChannelScalar, PosNormalChannelScalar};
+use chromatic_adaptation::BradfordAdaptation;
+use color_space::{EncodedColorSpace, RgbPrimary};
+use encoding::ChannelDecoder;
+use lab::Lab;
+use linalg::Matrix3;
+use xyz::Xyz;
+use icc::{IccError, Signature};
+use icc::curve::IccCurve;
+use icc::lut::{LutDirection, LutTransform};
+use icc::parse::{self, Reader};
+
+/// The size of the fixed profile header in bytes.
+pub const HEADER_SIZE: usize = 128;
+
+/// How far a media white point may be from the PCS illuminant and still be treated as it,
+/// allowing for the rounding of s15Fixed16 values.
+const WHITE_POINT_TOLERANCE: f64 = 1e-3;
+
+/// The fixed 128 byte header at the start of every profile.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IccHeader<T> {
+    /// The size of the whole profile in bytes.
+    pub size: u32,
+    pub cmm: Signature,
+    /// The major, minor and bug fix version numbers.
+    pub version: (u8, u8, u8),
+    pub device_class: Signature,
+    /// The color space of device values, such as `Signature::RGB_DATA`.
+    pub color_space: Signature,
+    /// The profile connection space, either `Signature::XYZ_DATA` or `Signature::LAB_DATA`.
+    pub pcs: Signature,
+    /// The creation date as year, month, day, hours, minutes and seconds.
+    pub date_time: [u16; 6],
+    pub platform: Signature,
+    pub flags: u32,
+    pub manufacturer: Signature,
+    pub model: Signature,
+    pub attributes: u64,
+    pub rendering_intent: u32,
+    /// The PCS illuminant, which is always D50 in practice.
+    pub illuminant: Xyz<T>,
+    pub creator: Signature,
+    /// The MD5 profile identifier, or all zeros if it was not computed.
+    pub profile_id: [u8; 16],
+}
+
+impl<T> IccHeader<T>
+    where T: num::Float + FreeChannelScalar
+{
+    /// Construct a version 4.3 header with the D50 PCS illuminant.
+    ///
+    /// The creation date is given as year, month, day, hours, minutes and seconds, so that
+    /// writing the same profile twice gives the same bytes; pass `current_date_time()` to
+    /// date it now. The size and profile ID are filled in when the profile is written.
+    pub fn new(device_class: Signature,
+               color_space: Signature,
+               pcs: Signature,
+               date_time: [u16; 6])
+               -> Self {
+        let blank = Signature([0; 4]);
+        IccHeader {
+            size: 0,
+            cmm: blank,
+            version: (4, 3, 0),
+            device_class,
+            color_space,
+            pcs,
+            date_time,
+            platform: blank,
+            flags: 0,
+            manufacturer: blank,
+            model: blank,
+            attributes: 0,
+            rendering_intent: 0,
+            illuminant: Self::pcs_illuminant(),
+            creator: blank,
+            profile_id: [0; 16],
+        }
+    }
+
+    /// The D50 illuminant as defined by the ICC specification.
+    ///
+    /// This differs slightly from `white_point::D50`, and is what PCS values are relative to.
+    pub fn pcs_illuminant() -> Xyz<T> {
+        Xyz::from_channels(num::cast(0.9642).unwrap(),
+                           num::cast(1.0).unwrap(),
+                           num::cast(0.8249).unwrap())
+    }
+}
+
+/// Get the current UTC date and time as year, month, day, hours, minutes and seconds.
+pub fn current_date_time() -> [u16; 6] {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0);
+    let days = (seconds / 86400) as i64;
+    let time = seconds % 86400;
+
+    // Convert days since 1970-01-01 to a civil date, using eras of 400 years.
+    let shifted = days + 719468;
+    let era = shifted.div_euclid(146097);
+    let day_of_era = shifted - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 -
+                       day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    [year as u16,
+     month as u16,
+     day as u16,
+     (time / 3600) as u16,
+     (time / 60 % 60) as u16,
+     (time % 60) as u16]
+}
+
+/// The data of a single tag.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Tag<T> {
+    /// An `XYZ ` array.
+    Xyz(Vec<Xyz<T>>),
+    /// A `curv` or `para` tone curve.
+    Curve(IccCurve<T>),
+    /// An `sf32` array, such as the `chad` matrix.
+    S15Fixed16Array(Vec<T>),
+    /// An `mAB ` or `mBA ` lookup table.
+    Lut(LutTransform<T>),
+    /// A `text`, `desc` or `mluc` string.
+    Text(String),
+    /// Any other type, kept as its type signature and raw bytes.
+    Unknown(Signature, Vec<u8>),
+}
+
+impl<T: num::Float> Tag<T> {
+    /// Get the ICC type signature this tag is stored as.
+    pub fn type_signature(&self) -> Signature {
+        match *self {
+            Tag::Xyz(_) => Signature::XYZ_TYPE,
+            Tag::Curve(IccCurve::Parametric(_)) => Signature::PARAMETRIC_CURVE_TYPE,
+            Tag::Curve(_) => Signature::CURVE_TYPE,
+            Tag::S15Fixed16Array(_) => Signature::S15_FIXED16_ARRAY_TYPE,
+            Tag::Lut(ref lut) => {
+                match lut.direction() {
+                    LutDirection::AToB => Signature::LUT_A_TO_B_TYPE,
+                    LutDirection::BToA => Signature::LUT_B_TO_A_TYPE,
+                }
+            }
+            Tag::Text(_) => Signature::TEXT_TYPE,
+            Tag::Unknown(sig, _) => sig,
+        }
+    }
+}
+
+/// An ICC color profile.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IccProfile<T> {
+    header: IccHeader<T>,
+    tags: Vec<(Signature, Tag<T>)>,
+}
+
+impl<T> IccProfile<T>
+    where T: num::Float + FreeChannelScalar + PosNormalChannelScalar
+{
+    /// Construct a profile from a header and a list of tags.
+    pub fn new(header: IccHeader<T>, tags: Vec<(Signature, Tag<T>)>) -> Self {
+        IccProfile { header, tags }
+    }
+
+    /// Read a profile from its binary representation.
+    pub fn parse(data: &[u8]) -> Result<Self, IccError> {
+        let reader = Reader::new(data);
+        let header = Self::parse_header(reader).map_err(IccError::InvalidHeader)?;
+        if header.size as usize > data.len() {
+            return Err(IccError::InvalidHeader("profile is larger than the data"));
+        }
+        let reader = Reader::new(&data[..header.size as usize]);
+
+        let count = reader.u32(HEADER_SIZE).map_err(IccError::InvalidHeader)? as usize;
+        let mut tags = Vec::with_capacity(count.min(256));
+        for i in 0..count {
+            let entry = HEADER_SIZE + 4 + 12 * i;
+            let (sig, offset, size) = (|| {
+                    Ok((reader.signature(entry)?,
+                        reader.u32(entry + 4)? as usize,
+                        reader.u32(entry + 8)? as usize))
+                })()
+                .map_err(IccError::InvalidHeader)?;
+
+            let tag = reader.bytes(offset, size)
+                .and_then(|_| reader.sub(offset))
+                .and_then(|tag_data| parse::parse_tag(tag_data, size))
+                .map_err(|reason| IccError::InvalidTag(sig, reason))?;
+            tags.push((sig, tag));
+        }
+
+        Ok(IccProfile { header, tags })
+    }
+
+    fn parse_header(reader: Reader) -> parse::ParseResult<IccHeader<T>> {
+        if reader.len() < HEADER_SIZE + 4 {
+            return Err("profile is too short");
+        }
+        if reader.signature(36)? != Signature::MAGIC {
+            return Err("missing 'acsp' signature");
+        }
+
+        let mut date_time = [0; 6];
+        for (i, field) in date_time.iter_mut().enumerate() {
+            *field = reader.u16(24 + 2 * i)?;
+        }
+        let mut profile_id = [0; 16];
+        profile_id.copy_from_slice(reader.bytes(84, 16)?);
+        let minor = reader.u8(9)?;
+
+        Ok(IccHeader {
+            size: reader.u32(0)?,
+            cmm: reader.signature(4)?,
+            version: (reader.u8(8)?, minor >> 4, minor & 0x0f),
+            device_class: reader.signature(12)?,
+            color_space: reader.signature(16)?,
+            pcs: reader.signature(20)?,
+            date_time,
+            platform: reader.signature(40)?,
+            flags: reader.u32(44)?,
+            manufacturer: reader.signature(48)?,
+            model: reader.signature(52)?,
+            attributes: reader.u64(56)?,
+            rendering_intent: reader.u32(64)?,
+            illuminant: reader.xyz(68)?,
+            creator: reader.signature(80)?,
+            profile_id,
+        })
+    }
+
+    pub fn header(&self) -> &IccHeader<T> {
+        &self.header
+    }
+
+    /// Get the tags in the order they appear in the tag table.
+    pub fn tags(&self) -> &[(Signature, Tag<T>)] {
+        &self.tags
+    }
+
+    pub fn tag(&self, sig: Signature) -> Option<&Tag<T>> {
+        self.tags.iter().find(|&&(tag_sig, _)| tag_sig == sig).map(|(_, tag)| tag)
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        match self.tag(Signature::PROFILE_DESCRIPTION) {
+            Some(Tag::Text(text)) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn copyright(&self) -> Option<&str> {
+        match self.tag(Signature::COPYRIGHT) {
+            Some(Tag::Text(text)) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Get the value of a single `XYZ ` tag.
+    pub fn xyz_tag(&self, sig: Signature) -> Result<Xyz<T>, IccError> {
+        match self.tag(sig) {
+            Some(Tag::Xyz(values)) => {
+                values.first().cloned().ok_or(IccError::InvalidTag(sig, "no XYZ values"))
+            }
+            Some(tag) => Err(IccError::UnsupportedTagType(sig, tag.type_signature())),
+            None => Err(IccError::MissingTag(sig)),
+        }
+    }
+
+    /// Get a tone curve tag.
+    pub fn curve_tag(&self, sig: Signature) -> Result<&IccCurve<T>, IccError> {
+        match self.tag(sig) {
+            Some(Tag::Curve(curve)) => Ok(curve),
+            Some(tag) => Err(IccError::UnsupportedTagType(sig, tag.type_signature())),
+            None => Err(IccError::MissingTag(sig)),
+        }
+    }
+
+    pub fn media_white_point(&self) -> Result<Xyz<T>, IccError> {
+        self.xyz_tag(Signature::MEDIA_WHITE_POINT)
+    }
+
+    /// Get the `chad` matrix, which adapts the native white point to the PCS illuminant.
+    pub fn chromatic_adaptation(&self) -> Result<Option<Matrix3<T>>, IccError> {
+        let sig = Signature::CHROMATIC_ADAPTATION;
+        match self.tag(sig) {
+            Some(Tag::S15Fixed16Array(values)) => {
+                if values.len() != 9 {
+                    return Err(IccError::InvalidTag(sig, "expected nine matrix entries"));
+                }
+                let mut matrix = [T::zero(); 9];
+                matrix.copy_from_slice(values);
+                Ok(Some(Matrix3::new(matrix)))
+            }
+            Some(tag) => Err(IccError::UnsupportedTagType(sig, tag.type_signature())),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the PCS-relative RGB to `Xyz` matrix built from the colorant tags.
+    pub fn colorant_matrix(&self) -> Result<Matrix3<T>, IccError> {
+        let red = self.xyz_tag(Signature::RED_COLORANT)?;
+        let green = self.xyz_tag(Signature::GREEN_COLORANT)?;
+        let blue = self.xyz_tag(Signature::BLUE_COLORANT)?;
+
+        Ok(Matrix3::new([red.x(), green.x(), blue.x(), red.y(), green.y(), blue.y(), red.z(),
+                         green.z(), blue.z()]))
+    }
+
+    /// Build the color space described by a matrix/TRC RGB profile.
+    ///
+    /// The colorants in a profile are adapted to the PCS illuminant. If the profile has a
+    /// `chad` tag, the adaptation is undone so that the color space has its native white
+    /// point and primaries. Otherwise, if the media white point differs from the PCS
+    /// illuminant, the colorants are adapted back to it with a Bradford transform, as
+    /// version 2 profiles assume. All three tone curves must be the same, as the color space
+    /// applies a single encoding to every channel.
+    pub fn to_color_space(&self) -> Result<EncodedColorSpace<T, IccCurve<T>>, IccError> {
+        if self.header.color_space != Signature::RGB_DATA {
+            return Err(IccError::UnsupportedProfile("only RGB profiles describe a color space"));
+        }
+
+        let red_trc = self.curve_tag(Signature::RED_TRC)?;
+        let green_trc = self.curve_tag(Signature::GREEN_TRC)?;
+        let blue_trc = self.curve_tag(Signature::BLUE_TRC)?;
+        if red_trc != green_trc || red_trc != blue_trc {
+            return Err(IccError::UnsupportedProfile("the tone curves differ between channels"));
+        }
+
+        let mut transform = self.colorant_matrix()?;
+        if let Some(chad) = self.chromatic_adaptation()? {
+            let inverse = chad.inverse()
+                .ok_or(IccError::InvalidTag(Signature::CHROMATIC_ADAPTATION,
+                                            "matrix is singular"))?;
+            transform = inverse * transform;
+        } else if let Ok(white) = self.media_white_point() {
+            // Version 2 display profiles adapt their colorants to D50 with Bradford without
+            // recording it, and give the native white point in `wtpt`.
+            let illuminant = self.header.illuminant;
+            let tolerance: T = num::cast(WHITE_POINT_TOLERANCE).unwrap();
+            if (white.x() - illuminant.x()).abs() > tolerance ||
+               (white.y() - illuminant.y()).abs() > tolerance ||
+               (white.z() - illuminant.z()).abs() > tolerance {
+                let one = T::one();
+                let (x, y, z) = transform.transform_vector((one, one, one));
+                let source = Xyz::from_channels(x, y, z);
+                transform = *BradfordAdaptation::new(&source, &white).transform() * transform;
+            }
+        }
+        let inverse = transform.inverse()
+            .ok_or(IccError::UnsupportedProfile("the colorants are linearly dependent"))?;
+
+        let one = T::one();
+        let (wx, wy, wz) = transform.transform_vector((one, one, one));
+        let m = transform.as_slice();
+        let primary = |x: T, y: T, z: T| {
+            let sum = x + y + z;
+            RgbPrimary::new(x / sum, y / sum)
+        };
+
+        Ok(EncodedColorSpace::new_with_transforms(primary(m[0], m[3], m[6]),
+                                                  primary(m[1], m[4], m[7]),
+                                                  primary(m[2], m[5], m[8]),
+                                                  Xyz::from_channels(wx, wy, wz),
+                                                  red_trc.clone(),
+                                                  transform,
+                                                  inverse))
+    }
+
+    /// Get the tone curve of a gray profile.
+    pub fn gray_trc(&self) -> Result<&IccCurve<T>, IccError> {
+        if self.header.color_space != Signature::GRAY_DATA {
+            return Err(IccError::UnsupportedProfile("not a gray profile"));
+        }
+        self.curve_tag(Signature::GRAY_TRC)
+    }
+
+    /// Convert a gray value to PCS `Xyz` using the gray tone curve.
+    pub fn gray_to_xyz(&self, gray: T) -> Result<Xyz<T>, IccError> {
+        let luminance = self.gray_trc()?.decode_channel(gray);
+        let white = self.header.illuminant;
+        Ok(Xyz::from_channels(white.x() * luminance,
+                              white.y() * luminance,
+                              white.z() * luminance))
+    }
+
+    /// Get the device to PCS lookup table for a rendering intent.
+    ///
+    /// As in the ICC specification, the perceptual table is used if there is none for the
+    /// requested intent.
+    pub fn a_to_b(&self, intent: u32) -> Result<&LutTransform<T>, IccError> {
+        let sigs = [Signature::A_TO_B0, Signature::A_TO_B1, Signature::A_TO_B2];
+        self.lut_tag(&sigs, intent)
+    }
+
+    /// Get the PCS to device lookup table for a rendering intent.
+    pub fn b_to_a(&self, intent: u32) -> Result<&LutTransform<T>, IccError> {
+        let sigs = [Signature::B_TO_A0, Signature::B_TO_A1, Signature::B_TO_A2];
+        self.lut_tag(&sigs, intent)
+    }
+
+    fn lut_tag(&self, sigs: &[Signature; 3], intent: u32) -> Result<&LutTransform<T>, IccError> {
+        let sig = match sigs.get(intent as usize) {
+            Some(&sig) if self.tag(sig).is_some() => sig,
+            _ => sigs[0],
+        };
+        match self.tag(sig) {
+            Some(Tag::Lut(lut)) => Ok(lut),
+            Some(tag) => Err(IccError::UnsupportedTagType(sig, tag.type_signature())),
+            None => Err(IccError::MissingTag(sig)),
+        }
+    }
+
+    /// Convert normalized PCS values, as produced by an A-to-B table, to `Xyz`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` does not have three entries.
+    pub fn pcs_to_xyz(&self, values: &[T]) -> Xyz<T> {
+        assert_eq!(values.len(), 3);
+        if self.header.pcs == Signature::LAB_DATA {
+            let (l, a, b) = (values[0] * num::cast(100.0).unwrap(),
+                             values[1] * num::cast(255.0).unwrap() - num::cast(128.0).unwrap(),
+                             values[2] * num::cast(255.0).unwrap() - num::cast(128.0).unwrap());
+            Lab::from_channels(l, a, b).to_xyz(&self.header.illuminant)
+        } else {
+            let scale = Self::xyz_pcs_scale();
+            Xyz::from_channels(values[0] * scale, values[1] * scale, values[2] * scale)
+        }
+    }
+
+    /// Convert `Xyz` to normalized PCS values, as consumed by a B-to-A table.
+    pub fn xyz_to_pcs(&self, xyz: &Xyz<T>) -> [T; 3] {
+        if self.header.pcs == Signature::LAB_DATA {
+            let lab = Lab::from_xyz(xyz, &self.header.illuminant);
+            let offset: T = num::cast(128.0).unwrap();
+            let range: T = num::cast(255.0).unwrap();
+            [lab.L() / num::cast(100.0).unwrap(),
+             (lab.a() + offset) / range,
+             (lab.b() + offset) / range]
+        } else {
+            let scale = Self::xyz_pcs_scale();
+            [xyz.x() / scale, xyz.y() / scale, xyz.z() / scale]
+        }
+    }
+
+    /// The XYZ value encoded by a normalized PCS value of 1.0.
+    fn xyz_pcs_scale() -> T {
+        num::cast(65535.0 / 32768.0).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use color_space::color_space::{ColorSpace, ColorSpaceEncoding, ColorToXyz};
+    use color_space::presets::*;
+    use encoding::{ChannelEncoder, ParametricCurveEncoding};
+    use rgb::Rgb;
+    use test::icc_test_data::*;
+    use white_point::*;
+
+    #[test]
+    fn test_header() {
+        let data = build_srgb_profile();
+        let profile = IccProfile::<f64>::parse(&data).unwrap();
+        let header = profile.header();
+
+        assert_eq!(header.size as usize, data.len());
+        assert_eq!(header.version, (4, 3, 0));
+        assert_eq!(header.device_class, Signature::DISPLAY_CLASS);
+        assert_eq!(header.color_space, Signature::RGB_DATA);
+        assert_eq!(header.pcs, Signature::XYZ_DATA);
+        assert_eq!(header.date_time, [2024, 5, 17, 12, 30, 0]);
+        assert_relative_eq!(header.illuminant,
+                            Xyz::from_channels(0.9642, 1.0, 0.8249),
+                            epsilon = 1e-4);
+        assert_eq!(profile.description(), Some("sRGB test profile"));
+        assert_eq!(profile.copyright(), Some("No copyright"));
+        assert_eq!(profile.tags().len(), 10);
+    }
+
+    #[test]
+    fn test_matrix_trc() {
+        let profile = IccProfile::<f64>::parse(&build_srgb_profile()).unwrap();
+        assert_relative_eq!(profile.media_white_point().unwrap(),
+                            D50::get_xyz(),
+                            epsilon = 1e-4);
+
+        let space = profile.to_color_space().unwrap();
+        let srgb = sRgb::get_color_space();
+        assert_relative_eq!(*space.get_xyz_transform(),
+                            *srgb.get_xyz_transform(),
+                            epsilon = 1e-4);
+        assert_relative_eq!(space.white_point(), D65::get_xyz(), epsilon = 1e-4);
+        let (rx, ry) = space.red_primary().to_tuple();
+        assert_relative_eq!(rx, 0.64, epsilon = 1e-4);
+        assert_relative_eq!(ry, 0.33, epsilon = 1e-4);
+
+        let c1 = Rgb::from_channels(0.8, 0.3, 0.1);
+        assert_relative_eq!(space.color_to_xyz(&c1), srgb.color_to_xyz(&c1), epsilon = 1e-4);
+        assert_eq!(*profile.curve_tag(Signature::GREEN_TRC).unwrap(),
+                   IccCurve::Parametric(ParametricCurveEncoding::from_params(3,
+                                                                             &profile_srgb_params())
+                       .unwrap()));
+    }
+
+    #[test]
+    fn test_v2_profile() {
+        let profile = IccProfile::<f64>::parse(&build_v2_gamma_profile()).unwrap();
+        assert_eq!(profile.header().version, (2, 1, 0));
+        assert_eq!(profile.description(), Some("Gamma 2.2 test profile"));
+        assert_eq!(profile.chromatic_adaptation(), Ok(None));
+
+        let space = profile.to_color_space().unwrap();
+        assert_relative_eq!(space.white_point(), D50::get_xyz(), epsilon = 1e-4);
+        assert_eq!(*space.encoding(), IccCurve::Gamma(2.19921875));
+        assert_eq!(profile.gray_trc(), Err(IccError::UnsupportedProfile("not a gray profile")));
+    }
+
+    #[test]
+    fn test_v2_media_white_point() {
+        let profile = IccProfile::<f64>::parse(&build_v2_srgb_profile()).unwrap();
+        assert_eq!(profile.chromatic_adaptation(), Ok(None));
+        assert_relative_eq!(profile.media_white_point().unwrap(),
+                            D65::get_xyz(),
+                            epsilon = 1e-4);
+
+        let space = profile.to_color_space().unwrap();
+        let srgb = sRgb::get_color_space();
+        assert_relative_eq!(space.white_point(), D65::get_xyz(), epsilon = 1e-4);
+        assert_relative_eq!(*space.get_xyz_transform(),
+                            *srgb.get_xyz_transform(),
+                            epsilon = 1e-4);
+        let (gx, gy) = space.green_primary().to_tuple();
+        assert_relative_eq!(gx, 0.30, epsilon = 1e-4);
+        assert_relative_eq!(gy, 0.60, epsilon = 1e-4);
+
+        let c1 = Rgb::from_channels(0.8, 0.3, 0.1);
+        assert_relative_eq!(space.color_to_xyz(&c1), srgb.color_to_xyz(&c1), epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_gray_profile() {
+        let profile = IccProfile::<f64>::parse(&build_gray_profile()).unwrap();
+        let curve = profile.gray_trc().unwrap();
+        match *curve {
+            IccCurve::Table(ref table) => assert_eq!(table.table().len(), 256),
+            _ => panic!("expected a table"),
+        }
+        assert_relative_eq!(curve.decode_channel(0.5), 0.21404114, epsilon = 1e-4);
+        assert_relative_eq!(curve.encode_channel(0.21404114), 0.5, epsilon = 1e-3);
+        assert_relative_eq!(profile.gray_to_xyz(1.0).unwrap(),
+                            profile.header().illuminant,
+                            epsilon = 1e-4);
+        assert_eq!(profile.to_color_space(),
+                   Err(IccError::UnsupportedProfile("only RGB profiles describe a color space")));
+    }
+
+    #[test]
+    fn test_lut_profile() {
+        let profile = IccProfile::<f64>::parse(&build_lut_profile()).unwrap();
+        assert_eq!(profile.header().pcs, Signature::LAB_DATA);
+
+        let a_to_b = profile.a_to_b(1).unwrap();
+        assert_eq!(a_to_b.direction(), LutDirection::AToB);
+        assert_eq!(a_to_b.input_channels(), 3);
+        assert_eq!(a_to_b.output_channels(), 3);
+        assert_eq!(a_to_b.clut().unwrap().grid_points(), &[2, 2, 2]);
+
+        let pcs = a_to_b.apply(&[0.2, 0.4, 0.6]);
+        assert_relative_eq!(pcs[0], 0.28, epsilon = 1e-4);
+        assert_relative_eq!(pcs[1], 0.18, epsilon = 1e-4);
+        assert_relative_eq!(pcs[2], 0.12, epsilon = 1e-4);
+
+        let white = profile.pcs_to_xyz(&[1.0, 128.0 / 255.0, 128.0 / 255.0]);
+        assert_relative_eq!(white, profile.header().illuminant, epsilon = 1e-6);
+        let round_trip = profile.xyz_to_pcs(&Xyz::from_channels(0.3, 0.4, 0.5));
+        assert_relative_eq!(profile.pcs_to_xyz(&round_trip),
+                            Xyz::from_channels(0.3, 0.4, 0.5),
+                            epsilon = 1e-6);
+
+        let b_to_a = profile.b_to_a(0).unwrap();
+        assert_eq!(b_to_a.direction(), LutDirection::BToA);
+        assert_eq!(b_to_a.output_channels(), 1);
+        assert_relative_eq!(b_to_a.apply(&[0.25, 0.49, 0.81])[0], 0.7, epsilon = 1e-3);
+
+        assert_eq!(profile.to_color_space().unwrap_err(),
+                   IccError::MissingTag(Signature::RED_TRC));
+        assert_eq!(profile.curve_tag(Signature::A_TO_B0).unwrap_err(),
+                   IccError::UnsupportedTagType(Signature::A_TO_B0, Signature::LUT_A_TO_B_TYPE));
+    }
+
+    #[test]
+    fn test_xyz_pcs() {
+        let profile = IccProfile::<f64>::parse(&build_srgb_profile()).unwrap();
+        let pcs = profile.xyz_to_pcs(&D50::get_xyz());
+        assert_relative_eq!(pcs[1], 32768.0 / 65535.0, epsilon = 1e-6);
+        assert_relative_eq!(profile.pcs_to_xyz(&pcs), D50::get_xyz(), epsilon = 1e-6);
+        assert!(profile.a_to_b(0).is_err());
+    }
+
+    #[test]
+    fn test_errors() {
+        let data = build_srgb_profile();
+        assert_eq!(IccProfile::<f64>::parse(&data[..100]),
+                   Err(IccError::InvalidHeader("profile is too short")));
+        assert_eq!(IccProfile::<f64>::parse(&data[..data.len() - 4]),
+                   Err(IccError::InvalidHeader("profile is larger than the data")));
+
+        let mut bad_magic = data.clone();
+        bad_magic[36] = b'x';
+        assert_eq!(IccProfile::<f64>::parse(&bad_magic),
+                   Err(IccError::InvalidHeader("missing 'acsp' signature")));
+
+        let mut bad_offset = data.clone();
+        bad_offset[HEADER_SIZE + 8..HEADER_SIZE + 12].copy_from_slice(&[0, 0xff, 0, 0]);
+        match IccProfile::<f64>::parse(&bad_offset) {
+            Err(IccError::InvalidTag(_, _)) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        let unknown = IccProfile::<f64>::parse(&build_lut_profile()).unwrap();
+        match unknown.tag(Signature(*b"zzzz")) {
+            Some(&Tag::Unknown(sig, ref bytes)) => {
+                assert_eq!(sig, Signature(*b"zzzz"));
+                assert_eq!(bytes.len(), 12);
+            }
+            other => panic!("unexpected tag {:?}", other),
+        }
+    }
+}
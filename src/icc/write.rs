@@ -0,0 +1,451 @@
+use num;
+use channel::{FreeChannelScalar, PosNormalChannelScalar};
+use chromatic_adaptation::BradfordAdaptation;
+use color_space::EncodedColorSpace;
+use color_space::color_space::{ColorSpace, ColorSpaceEncoding};
+use encoding::ColorEncoding;
+use xyz::Xyz;
+use icc::Signature;
+use icc::curve::{IccCurve, ToIccCurve};
+use icc::lut::{LutDirection, LutTransform};
+use icc::md5::md5;
+use icc::parse::align4;
+use icc::profile::{HEADER_SIZE, IccHeader, IccProfile, Tag};
+
+impl<T> IccProfile<T>
+    where T: num::Float + FreeChannelScalar + PosNormalChannelScalar
+{
+    /// Build a version 4 matrix/TRC display profile describing a color space.
+    ///
+    /// The colorants are adapted from the white point of the color space to the PCS
+    /// illuminant with a Bradford transform, which is recorded in the `chad` tag. The
+    /// encoding becomes the tone curve of all three channels. The profile is dated
+    /// `date_time`, as in `IccHeader::new`.
+    pub fn from_color_space<E>(space: &EncodedColorSpace<T, E>,
+                               description: &str,
+                               copyright: &str,
+                               date_time: [u16; 6])
+                               -> Self
+        where E: ColorEncoding + ToIccCurve<T>
+    {
+        let header = IccHeader::new(Signature::DISPLAY_CLASS,
+                                    Signature::RGB_DATA,
+                                    Signature::XYZ_DATA,
+                                    date_time);
+        let illuminant = header.illuminant;
+        let adaptation = BradfordAdaptation::new(&space.white_point(), &illuminant);
+        let colorants = *adaptation.transform() * *space.get_xyz_transform();
+        let m = colorants.as_slice();
+        let curve = space.encoding().to_icc_curve();
+
+        let tags = vec![(Signature::PROFILE_DESCRIPTION, Tag::Text(description.to_string())),
+                        (Signature::COPYRIGHT, Tag::Text(copyright.to_string())),
+                        (Signature::MEDIA_WHITE_POINT, Tag::Xyz(vec![illuminant])),
+                        (Signature::CHROMATIC_ADAPTATION,
+                         Tag::S15Fixed16Array(adaptation.transform().as_slice().to_vec())),
+                        (Signature::RED_COLORANT,
+                         Tag::Xyz(vec![Xyz::from_channels(m[0], m[3], m[6])])),
+                        (Signature::GREEN_COLORANT,
+                         Tag::Xyz(vec![Xyz::from_channels(m[1], m[4], m[7])])),
+                        (Signature::BLUE_COLORANT,
+                         Tag::Xyz(vec![Xyz::from_channels(m[2], m[5], m[8])])),
+                        (Signature::RED_TRC, Tag::Curve(curve.clone())),
+                        (Signature::GREEN_TRC, Tag::Curve(curve.clone())),
+                        (Signature::BLUE_TRC, Tag::Curve(curve))];
+
+        IccProfile::new(header, tags)
+    }
+
+    /// Write the profile in its binary representation.
+    ///
+    /// The size in the header is recomputed, and so is the profile ID of version 4 profiles.
+    /// Earlier versions reserve those bytes, so they are left zero. Tags with identical data
+    /// share a single copy of it, as the three tone curves of a matrix/TRC profile usually do.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let header = self.header();
+        let tags = self.tags();
+
+        let mut out = vec![0u8; HEADER_SIZE];
+        write_header(&mut out, header);
+        push_u32(&mut out, tags.len() as u32);
+        let table_start = out.len();
+        out.resize(table_start + 12 * tags.len(), 0);
+
+        let mut written: Vec<(Vec<u8>, usize)> = Vec::new();
+        for (i, &(sig, ref tag)) in tags.iter().enumerate() {
+            let data = write_tag(tag, sig, header.version.0);
+            let offset = match written.iter().find(|(prev, _)| *prev == data) {
+                Some(&(_, offset)) => offset,
+                None => {
+                    pad(&mut out);
+                    let offset = out.len();
+                    out.extend_from_slice(&data);
+                    written.push((data.clone(), offset));
+                    offset
+                }
+            };
+
+            let entry = table_start + 12 * i;
+            out[entry..entry + 4].copy_from_slice(&sig.0);
+            out[entry + 4..entry + 8].copy_from_slice(&be32(offset as u32));
+            out[entry + 8..entry + 12].copy_from_slice(&be32(data.len() as u32));
+        }
+        pad(&mut out);
+
+        let size = out.len() as u32;
+        out[0..4].copy_from_slice(&be32(size));
+        let id = if header.version.0 >= 4 { compute_profile_id(&out) } else { [0; 16] };
+        out[84..100].copy_from_slice(&id);
+        out
+    }
+}
+
+/// Compute the MD5 profile ID of a complete profile.
+///
+/// As the ICC specification requires, the profile flags, rendering intent and profile ID
+/// fields of the header are treated as zero.
+pub fn compute_profile_id(data: &[u8]) -> [u8; 16] {
+    let mut copy = data.to_vec();
+    for range in [44..48, 64..68, 84..100].iter() {
+        if let Some(field) = copy.get_mut(range.clone()) {
+            for byte in field.iter_mut() {
+                *byte = 0;
+            }
+        }
+    }
+    md5(&copy)
+}
+
+fn be32(val: u32) -> [u8; 4] {
+    val.to_be_bytes()
+}
+
+fn push_u16(out: &mut Vec<u8>, val: u16) {
+    out.extend_from_slice(&val.to_be_bytes());
+}
+
+fn push_u32(out: &mut Vec<u8>, val: u32) {
+    out.extend_from_slice(&val.to_be_bytes());
+}
+
+fn push_s15_fixed16<T: num::Float>(out: &mut Vec<u8>, val: T) {
+    let scaled = (val * num::cast(65536.0).unwrap()).round();
+    let raw = num::cast::<_, i64>(scaled)
+        .unwrap_or(0)
+        .max(i64::from(i32::MIN))
+        .min(i64::from(i32::MAX)) as i32;
+    push_u32(out, raw as u32);
+}
+
+fn push_u16_normalized<T: num::Float>(out: &mut Vec<u8>, val: T) {
+    let clamped = val.max(T::zero()).min(T::one());
+    let scaled = (clamped * num::cast(65535.0).unwrap()).round();
+    push_u16(out, num::cast(scaled).unwrap());
+}
+
+fn push_xyz<T: num::Float + FreeChannelScalar>(out: &mut Vec<u8>, xyz: &Xyz<T>) {
+    push_s15_fixed16(out, xyz.x());
+    push_s15_fixed16(out, xyz.y());
+    push_s15_fixed16(out, xyz.z());
+}
+
+fn push_type(out: &mut Vec<u8>, sig: Signature) {
+    out.extend_from_slice(&sig.0);
+    out.extend_from_slice(&[0; 4]);
+}
+
+fn pad(out: &mut Vec<u8>) {
+    let len = align4(out.len());
+    out.resize(len, 0);
+}
+
+fn write_header<T>(out: &mut [u8], header: &IccHeader<T>)
+    where T: num::Float + FreeChannelScalar
+{
+    let mut fields = Vec::with_capacity(HEADER_SIZE);
+    push_u32(&mut fields, header.size);
+    fields.extend_from_slice(&header.cmm.0);
+    let (major, minor, bug_fix) = header.version;
+    fields.extend_from_slice(&[major, (minor << 4) | (bug_fix & 0x0f), 0, 0]);
+    fields.extend_from_slice(&header.device_class.0);
+    fields.extend_from_slice(&header.color_space.0);
+    fields.extend_from_slice(&header.pcs.0);
+    for &field in header.date_time.iter() {
+        push_u16(&mut fields, field);
+    }
+    fields.extend_from_slice(&Signature::MAGIC.0);
+    fields.extend_from_slice(&header.platform.0);
+    push_u32(&mut fields, header.flags);
+    fields.extend_from_slice(&header.manufacturer.0);
+    fields.extend_from_slice(&header.model.0);
+    fields.extend_from_slice(&header.attributes.to_be_bytes());
+    push_u32(&mut fields, header.rendering_intent);
+    push_xyz(&mut fields, &header.illuminant);
+    fields.extend_from_slice(&header.creator.0);
+    fields.extend_from_slice(&header.profile_id);
+
+    out[..fields.len()].copy_from_slice(&fields);
+}
+
+fn write_tag<T>(tag: &Tag<T>, sig: Signature, major_version: u8) -> Vec<u8>
+    where T: num::Float + FreeChannelScalar
+{
+    let mut out = Vec::new();
+    match *tag {
+        Tag::Xyz(ref values) => {
+            push_type(&mut out, Signature::XYZ_TYPE);
+            for xyz in values {
+                push_xyz(&mut out, xyz);
+            }
+        }
+        Tag::Curve(ref curve) => write_curve(&mut out, curve),
+        Tag::S15Fixed16Array(ref values) => {
+            push_type(&mut out, Signature::S15_FIXED16_ARRAY_TYPE);
+            for &val in values {
+                push_s15_fixed16(&mut out, val);
+            }
+        }
+        Tag::Lut(ref lut) => write_lut(&mut out, lut),
+        Tag::Text(ref text) => write_text(&mut out, text, sig, major_version),
+        Tag::Unknown(_, ref data) => out.extend_from_slice(data),
+    }
+    out
+}
+
+fn write_curve<T: num::Float>(out: &mut Vec<u8>, curve: &IccCurve<T>) {
+    match *curve {
+        IccCurve::Gamma(gamma) => {
+            push_type(out, Signature::CURVE_TYPE);
+            if gamma == T::one() {
+                push_u32(out, 0);
+            } else {
+                push_u32(out, 1);
+                let scaled = (gamma * num::cast(256.0).unwrap()).round();
+                push_u16(out, num::cast(scaled).unwrap_or(u16::MAX));
+            }
+        }
+        IccCurve::Parametric(ref curve) => {
+            push_type(out, Signature::PARAMETRIC_CURVE_TYPE);
+            push_u16(out, curve.function_type());
+            push_u16(out, 0);
+            for &param in curve.params() {
+                push_s15_fixed16(out, param);
+            }
+        }
+        IccCurve::Table(ref table) => {
+            push_type(out, Signature::CURVE_TYPE);
+            push_u32(out, table.table().len() as u32);
+            for &entry in table.table() {
+                push_u16_normalized(out, entry);
+            }
+        }
+    }
+}
+
+fn write_curves<T: num::Float>(out: &mut Vec<u8>, curves: &[IccCurve<T>]) {
+    for curve in curves {
+        write_curve(out, curve);
+        pad(out);
+    }
+}
+
+fn write_lut<T: num::Float>(out: &mut Vec<u8>, lut: &LutTransform<T>) {
+    let type_sig = match lut.direction() {
+        LutDirection::AToB => Signature::LUT_A_TO_B_TYPE,
+        LutDirection::BToA => Signature::LUT_B_TO_A_TYPE,
+    };
+    push_type(out, type_sig);
+    out.extend_from_slice(&[lut.input_channels() as u8, lut.output_channels() as u8, 0, 0]);
+    let offsets_start = out.len();
+    out.resize(offsets_start + 20, 0);
+
+    let mut offsets = [0u32; 5];
+    offsets[0] = out.len() as u32;
+    write_curves(out, lut.b_curves());
+    if let Some(&(ref matrix, offset)) = lut.matrix() {
+        offsets[1] = out.len() as u32;
+        for &val in matrix.as_slice().iter().chain(offset.iter()) {
+            push_s15_fixed16(out, val);
+        }
+    }
+    if let Some(curves) = lut.m_curves() {
+        offsets[2] = out.len() as u32;
+        write_curves(out, curves);
+    }
+    if let Some(clut) = lut.clut() {
+        offsets[3] = out.len() as u32;
+        let mut grid = [0u8; 16];
+        for (points, &count) in grid.iter_mut().zip(clut.grid_points()) {
+            *points = count as u8;
+        }
+        out.extend_from_slice(&grid);
+        out.extend_from_slice(&[2, 0, 0, 0]);
+        for &val in clut.values() {
+            push_u16_normalized(out, val);
+        }
+        pad(out);
+    }
+    if let Some(curves) = lut.a_curves() {
+        offsets[4] = out.len() as u32;
+        write_curves(out, curves);
+    }
+
+    for (i, &offset) in offsets.iter().enumerate() {
+        let start = offsets_start + 4 * i;
+        out[start..start + 4].copy_from_slice(&be32(offset));
+    }
+}
+
+fn write_text(out: &mut Vec<u8>, text: &str, sig: Signature, major_version: u8) {
+    if major_version >= 4 {
+        push_type(out, Signature::MULTI_LOCALIZED_UNICODE_TYPE);
+        let units: Vec<u16> = text.encode_utf16().collect();
+        push_u32(out, 1);
+        push_u32(out, 12);
+        out.extend_from_slice(b"enUS");
+        push_u32(out, 2 * units.len() as u32);
+        push_u32(out, 28);
+        for unit in units {
+            push_u16(out, unit);
+        }
+    } else if sig == Signature::PROFILE_DESCRIPTION {
+        push_type(out, Signature::TEXT_DESCRIPTION_TYPE);
+        let ascii: Vec<u8> = text.bytes().map(|b| if b.is_ascii() { b } else { b'?' }).collect();
+        push_u32(out, ascii.len() as u32 + 1);
+        out.extend_from_slice(&ascii);
+        out.push(0);
+        // Empty Unicode and ScriptCode descriptions.
+        out.extend_from_slice(&[0; 8]);
+        out.extend_from_slice(&[0; 3 + 67]);
+    } else {
+        push_type(out, Signature::TEXT_TYPE);
+        out.extend_from_slice(text.as_bytes());
+        out.push(0);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use color_space::color_space::ColorToXyz;
+    use color_space::presets::*;
+    use encoding::{ChannelDecoder, PqEncoding};
+    use rgb::Rgb;
+    use test::icc_test_data::*;
+
+    const DATE: [u16; 6] = [2024, 5, 17, 12, 30, 0];
+
+    fn round_trip<E>(space: &EncodedColorSpace<f64, E>)
+        where E: ColorEncoding + ToIccCurve<f64> + ChannelDecoder
+    {
+        let profile = IccProfile::from_color_space(space, "Round trip", "Public domain", DATE);
+        let bytes = profile.to_bytes();
+        let parsed = IccProfile::<f64>::parse(&bytes).unwrap();
+        let read = parsed.to_color_space().unwrap();
+
+        assert_relative_eq!(*read.get_xyz_transform(),
+                            *space.get_xyz_transform(),
+                            epsilon = 1e-4);
+        assert_relative_eq!(read.white_point(), space.white_point(), epsilon = 1e-4);
+        for &val in [0.0, 0.02, 0.3, 0.7, 1.0].iter() {
+            assert_relative_eq!(read.encoding().decode_channel(val),
+                                space.encoding().decode_channel(val),
+                                epsilon = 1e-4);
+        }
+
+        let c1 = Rgb::from_channels(0.8, 0.4, 0.1);
+        assert_relative_eq!(read.color_to_xyz(&c1), space.color_to_xyz(&c1), epsilon = 1e-4);
+        assert_eq!(parsed.description(), Some("Round trip"));
+        assert_eq!(parsed.copyright(), Some("Public domain"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        round_trip(&sRgb::get_color_space());
+        round_trip(&DciP3::get_color_space());
+        round_trip(&ProPhotoRgb::get_color_space());
+        round_trip(&Rec2020::get_color_space());
+        round_trip(&LinearSrgb::get_color_space());
+        round_trip(&AdobeRgb::get_color_space());
+
+        let rec2020 = LinearRec2020::get_color_space();
+        let pq = EncodedColorSpace::new(rec2020.red_primary(),
+                                        rec2020.green_primary(),
+                                        rec2020.blue_primary(),
+                                        rec2020.white_point(),
+                                        PqEncoding::new(10000.0));
+        round_trip(&pq);
+    }
+
+    #[test]
+    fn test_layout() {
+        let profile = IccProfile::<f64>::from_color_space(&sRgb::get_color_space(),
+                                                          "sRGB",
+                                                          "",
+                                                          DATE);
+        let bytes = profile.to_bytes();
+        assert_eq!(bytes.len() % 4, 0);
+        assert_eq!(&bytes[36..40], b"acsp");
+        assert_eq!(&bytes[8..10], &[4, 0x30]);
+
+        let parsed = IccProfile::<f64>::parse(&bytes).unwrap();
+        assert_eq!(parsed.header().size as usize, bytes.len());
+        assert_eq!(parsed.header().device_class, Signature::DISPLAY_CLASS);
+        assert_relative_eq!(parsed.media_white_point().unwrap(),
+                            IccHeader::pcs_illuminant(),
+                            epsilon = 1e-4);
+
+        // The three tone curves share one copy of their data.
+        let entry = |i: usize| &bytes[HEADER_SIZE + 4 + 12 * i + 4..HEADER_SIZE + 16 + 12 * i];
+        assert_eq!(entry(7), entry(8));
+        assert_eq!(entry(7), entry(9));
+        assert!(entry(6) != entry(7));
+    }
+
+    #[test]
+    fn test_profile_id() {
+        let bytes = IccProfile::<f64>::from_color_space(&AdobeRgb::get_color_space(),
+                                                        "Adobe",
+                                                        "",
+                                                        DATE)
+            .to_bytes();
+        let parsed = IccProfile::<f64>::parse(&bytes).unwrap();
+        assert_eq!(parsed.header().profile_id, compute_profile_id(&bytes));
+        assert!(parsed.header().profile_id != [0; 16]);
+
+        let mut intent = bytes.clone();
+        intent[67] = 1;
+        assert_eq!(compute_profile_id(&intent), parsed.header().profile_id);
+
+        let mut changed = bytes.clone();
+        let last = changed.len() - 1;
+        changed[last] ^= 0xff;
+        assert!(compute_profile_id(&changed) != parsed.header().profile_id);
+    }
+
+    #[test]
+    fn test_rewrite() {
+        for data in [build_srgb_profile(), build_v2_gamma_profile(), build_lut_profile()].iter() {
+            let parsed = IccProfile::<f64>::parse(data).unwrap();
+            let rewritten = IccProfile::<f64>::parse(&parsed.to_bytes()).unwrap();
+            assert_eq!(rewritten.tags(), parsed.tags());
+            assert_eq!(rewritten.header().version, parsed.header().version);
+            assert_eq!(rewritten.header().date_time, parsed.header().date_time);
+        }
+
+        // Version 2 profiles reserve the profile ID bytes.
+        let v2 = IccProfile::<f64>::parse(&build_v2_gamma_profile()).unwrap().to_bytes();
+        assert_eq!(&v2[84..100], &[0; 16]);
+    }
+
+    #[test]
+    fn test_reproducible() {
+        let space = sRgb::get_color_space();
+        let bytes = IccProfile::<f64>::from_color_space(&space, "sRGB", "", DATE).to_bytes();
+        assert_eq!(IccProfile::<f64>::from_color_space(&space, "sRGB", "", DATE).to_bytes(),
+                   bytes);
+        let parsed = IccProfile::<f64>::parse(&bytes).unwrap();
+        assert_eq!(parsed.header().date_time, DATE);
+        assert_eq!(parsed.to_bytes(), bytes);
+    }
+}
@@ -0,0 +1,261 @@
+use std::fmt;
+use std::mem;
+use std::slice;
+use num;
+use approx;
+use angle::{Deg, Angle, FromAngle, IntoAngle, Turns, Rad};
+use angle;
+use channel::{PosFreeChannel, FreeChannelScalar, AngularChannel,
+              AngularChannelScalar, ChannelFormatCast, ChannelCast, ColorChannel};
+use color::{Color, PolarColor, FromTuple, Lerp, Bounded, Flatten};
+use convert::{GetChroma, GetHue, FromColor};
+use ipt::Ipt;
+use xyz::Xyz;
+
+pub struct IchTag;
+
+/// The polar form of `Ipt`, with lightness `i`, chroma and hue angle.
+///
+/// Holding the hue and reducing the chroma keeps the perceived hue, which makes `Ich` a good
+/// space for gamut mapping.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Ich<T, A = Deg<T>> {
+    pub i: PosFreeChannel<T>,
+    pub chroma: PosFreeChannel<T>,
+    pub hue: AngularChannel<A>,
+}
+
+impl<T, A> Ich<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    pub fn from_channels(i: T, chroma: T, hue: A) -> Self {
+        Ich {
+            i: PosFreeChannel::new(i),
+            chroma: PosFreeChannel::new(chroma),
+            hue: AngularChannel::new(hue),
+        }
+    }
+
+    impl_color_color_cast_angular!(Ich {i, chroma, hue}, 
+        chan_traits={FreeChannelScalar});
+
+    pub fn i(&self) -> T {
+        self.i.0
+    }
+    pub fn chroma(&self) -> T {
+        self.chroma.0
+    }
+    pub fn hue(&self) -> A {
+        self.hue.0.clone()
+    }
+    pub fn i_mut(&mut self) -> &mut T {
+        &mut self.i.0
+    }
+    pub fn chroma_mut(&mut self) -> &mut T {
+        &mut self.chroma.0
+    }
+    pub fn hue_mut(&mut self) -> &mut A {
+        &mut self.hue.0
+    }
+    pub fn set_i(&mut self, val: T) {
+        self.i.0 = val;
+    }
+    pub fn set_chroma(&mut self, val: T) {
+        self.chroma.0 = val;
+    }
+    pub fn set_hue(&mut self, val: A) {
+        self.hue.0 = val;
+    }
+}
+
+impl<T, A> Color for Ich<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type Tag = IchTag;
+    type ChannelsTuple = (T, T, A);
+
+    fn num_channels() -> u32 {
+        3
+    }
+    fn to_tuple(self) -> Self::ChannelsTuple {
+        (self.i.0, self.chroma.0, self.hue.0)
+    }
+}
+
+impl<T, A> PolarColor for Ich<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type Angular = A;
+    type Cartesian = T;
+}
+
+impl<T, A> FromTuple for Ich<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    fn from_tuple(values: Self::ChannelsTuple) -> Self {
+        Ich::from_channels(values.0, values.1, values.2)
+    }
+}
+
+impl<T, A> Lerp for Ich<T, A>
+    where T: FreeChannelScalar + Lerp,
+          A: AngularChannelScalar + Lerp
+{
+    type Position = A::Position;
+
+    impl_color_lerp_angular!(Ich<T> {hue, i, chroma});
+}
+
+impl<T, A> Bounded for Ich<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_bounded!(Ich {i, chroma, hue});
+}
+
+impl<T, A> Flatten for Ich<T, A>
+    where T: FreeChannelScalar + num::Float,
+          A: AngularChannelScalar + Angle<Scalar = T> + FromAngle<Turns<T>>
+{
+    type ScalarFormat = T;
+
+    impl_color_as_slice!(T);
+
+    fn from_slice(vals: &[T]) -> Self {
+        Ich::from_channels(vals[0],
+                             vals[1],
+                             A::from_angle(angle::Turns(vals[2])))
+    }
+}
+
+impl<T, A> approx::ApproxEq for Ich<T, A>
+    where T: FreeChannelScalar + approx::ApproxEq<Epsilon = A::Epsilon>,
+          A: AngularChannelScalar + approx::ApproxEq,
+          A::Epsilon: Clone + num::Float
+{
+    impl_approx_eq!({i, chroma, hue});
+}
+
+impl<T, A> Default for Ich<T, A>
+    where T: FreeChannelScalar + num::Zero,
+          A: AngularChannelScalar + num::Zero
+{
+    impl_color_default!(Ich {hue: AngularChannel, 
+        i: PosFreeChannel, chroma: PosFreeChannel});
+}
+
+impl<T, A> fmt::Display for Ich<T, A>
+    where T: FreeChannelScalar + fmt::Display,
+          A: AngularChannelScalar + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ICh({}, {}, {})", self.i, self.chroma, self.hue)
+    }
+}
+
+impl<T, A> GetChroma for Ich<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type ChromaType = T;
+    fn get_chroma(&self) -> T {
+        self.chroma()
+    }
+}
+
+impl<T, A> GetHue for Ich<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_get_hue_angular!(Ich);
+}
+
+impl<T, A> FromColor<Ipt<T>> for Ich<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + FromAngle<Rad<T>> + Angle
+{
+    fn from_color(from: &Ipt<T>) -> Self {
+        let i = from.i();
+        let chroma = (from.p() * from.p() + from.t() * from.t()).sqrt();
+        let hue = A::from_angle(Rad::atan2(from.t(), from.p()));
+
+        Ich::from_channels(i, chroma, <A as Angle>::normalize(hue))
+    }
+}
+
+impl<T, A> FromColor<Ich<T, A>> for Ipt<T>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn from_color(from: &Ich<T, A>) -> Self {
+        let i = from.i();
+        let p = from.chroma() * from.hue().cos();
+        let t = from.chroma() * from.hue().sin();
+
+        Ipt::from_channels(i, p, t)
+    }
+}
+
+impl<T, A> FromColor<Xyz<T>> for Ich<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + FromAngle<Rad<T>> + Angle
+{
+    fn from_color(from: &Xyz<T>) -> Self {
+        Ich::from_color(&Ipt::from_color(from))
+    }
+}
+
+impl<T, A> FromColor<Ich<T, A>> for Xyz<T>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn from_color(from: &Ich<T, A>) -> Self {
+        Xyz::from_color(&Ipt::from_color(from))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ipt::Ipt;
+    use xyz::Xyz;
+
+    #[test]
+    fn test_construct() {
+        let c1 = Ich::from_channels(0.5, 0.2, Deg(90.0));
+        assert_relative_eq!(c1.i(), 0.5);
+        assert_relative_eq!(c1.chroma(), 0.2);
+        assert_relative_eq!(c1.hue(), Deg(90.0));
+        assert_eq!(c1.to_tuple(), (0.5, 0.2, Deg(90.0)));
+        assert_relative_eq!(Ich::from_tuple(c1.to_tuple()), c1);
+        assert_eq!(c1.get_hue::<Turns<_>>(), Turns(0.25));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let c1 = Ich::from_channels(0.2, 0.3, Deg(300.0));
+        let c2 = Ich::from_channels(0.6, 0.1, Deg(50.0));
+        assert_relative_eq!(c1.lerp(&c2, 0.0), c1);
+        assert_relative_eq!(c1.lerp(&c2, 1.0), c2);
+        assert_relative_eq!(c1.lerp(&c2, 0.5), Ich::from_channels(0.4, 0.2, Deg(355.0)));
+    }
+
+    #[test]
+    fn test_from_ipt() {
+        let c1 = Ipt::from_channels(0.5, 0.1, 0.1);
+        let t1 = Ich::from_color(&c1);
+        assert_relative_eq!(t1, Ich::from_channels(0.5, 0.141421, Deg(45.0)), epsilon=1e-6);
+        assert_relative_eq!(Ipt::from_color(&t1), c1, epsilon=1e-9);
+
+        let blue = Xyz::from_channels(0.1805, 0.0722, 0.9505);
+        let t2: Ich<f64> = Ich::from_color(&blue);
+        assert_relative_eq!(t2, Ich::from_channels(0.444325, 0.785149, Deg(252.424769)),
+            epsilon=1e-6);
+        assert_relative_eq!(Xyz::from_color(&t2), blue, epsilon=1e-6);
+    }
+}
@@ -0,0 +1,288 @@
+#![allow(non_snake_case)]
+
+use std::fmt;
+use std::mem;
+use std::slice;
+use num;
+use approx;
+use channel::{PosFreeChannel, FreeChannel, FreeChannelScalar, PosNormalChannelScalar,
+              ChannelFormatCast, ChannelCast, ColorChannel};
+use color::{Color, Bounded, Lerp, Flatten, FromTuple};
+use encoding::{PqEncoding, HlgEncoding, ChannelEncoder, ChannelDecoder, LinearColor,
+               LinearEncoding, EncodableColor};
+use linalg::Matrix3;
+use rgb::Rgb;
+
+pub struct ICtCpTag;
+
+/// The ICtCp color representation of ITU-R BT.2100, for HDR BT.2020 content.
+///
+/// `I` is intensity, `Ct` the blue-yellow and `Cp` the red-green chroma. The values depend on
+/// the transfer function the LMS cone responses were encoded with: `PqEncoding` or
+/// `HlgEncoding`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct ICtCp<T> {
+    pub I: PosFreeChannel<T>,
+    pub Ct: FreeChannel<T>,
+    pub Cp: FreeChannel<T>,
+}
+
+impl<T> ICtCp<T>
+    where T: FreeChannelScalar
+{
+    pub fn from_channels(I: T, Ct: T, Cp: T) -> Self {
+        ICtCp {
+            I: PosFreeChannel::new(I),
+            Ct: FreeChannel::new(Ct),
+            Cp: FreeChannel::new(Cp),
+        }
+    }
+
+    impl_color_color_cast_square!(ICtCp {I, Ct, Cp}, chan_traits={FreeChannelScalar});
+
+    pub fn I(&self) -> T {
+        self.I.0
+    }
+    pub fn Ct(&self) -> T {
+        self.Ct.0
+    }
+    pub fn Cp(&self) -> T {
+        self.Cp.0
+    }
+    pub fn I_mut(&mut self) -> &mut T {
+        &mut self.I.0
+    }
+    pub fn Ct_mut(&mut self) -> &mut T {
+        &mut self.Ct.0
+    }
+    pub fn Cp_mut(&mut self) -> &mut T {
+        &mut self.Cp.0
+    }
+    pub fn set_I(&mut self, val: T) {
+        self.I.0 = val;
+    }
+    pub fn set_Ct(&mut self, val: T) {
+        self.Ct.0 = val;
+    }
+    pub fn set_Cp(&mut self, val: T) {
+        self.Cp.0 = val;
+    }
+}
+
+impl<T> Color for ICtCp<T>
+    where T: FreeChannelScalar
+{
+    type Tag = ICtCpTag;
+    type ChannelsTuple = (T, T, T);
+
+    #[inline]
+    fn num_channels() -> u32 {
+        3
+    }
+    fn to_tuple(self) -> Self::ChannelsTuple {
+        (self.I.0, self.Ct.0, self.Cp.0)
+    }
+}
+
+impl<T> FromTuple for ICtCp<T>
+    where T: FreeChannelScalar
+{
+    fn from_tuple(values: (T, T, T)) -> Self {
+        ICtCp::from_channels(values.0, values.1, values.2)
+    }
+}
+
+impl<T> Bounded for ICtCp<T>
+    where T: FreeChannelScalar
+{
+    fn normalize(self) -> Self {
+        ICtCp::from_channels(self.I.normalize().0, self.Ct(), self.Cp())
+    }
+    fn is_normalized(&self) -> bool {
+        self.I.is_normalized()
+    }
+}
+
+impl<T> Lerp for ICtCp<T>
+    where T: FreeChannelScalar + Lerp
+{
+    type Position = <FreeChannel<T> as Lerp>::Position;
+    impl_color_lerp_square!(ICtCp {I, Ct, Cp});
+}
+
+impl<T> Flatten for ICtCp<T>
+    where T: FreeChannelScalar
+{
+    type ScalarFormat = T;
+
+    impl_color_as_slice!(T);
+    impl_color_from_slice_square!(ICtCp<T> {I:PosFreeChannel - 0, Ct:FreeChannel - 1,
+        Cp:FreeChannel - 2});
+}
+
+impl<T> approx::ApproxEq for ICtCp<T>
+    where T: FreeChannelScalar + approx::ApproxEq,
+          T::Epsilon: Clone
+{
+    impl_approx_eq!({I, Ct, Cp});
+}
+
+impl<T> Default for ICtCp<T>
+    where T: FreeChannelScalar
+{
+    impl_color_default!(ICtCp {I:PosFreeChannel, Ct:FreeChannel, Cp:FreeChannel});
+}
+
+impl<T> fmt::Display for ICtCp<T>
+    where T: FreeChannelScalar + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ICtCp({}, {}, {})", self.I, self.Ct, self.Cp)
+    }
+}
+
+/// A transfer function ICtCp can be built on.
+pub trait IctcpTransfer: ChannelEncoder + ChannelDecoder {
+    /// The transform from nonlinear L'M'S' to ICtCp, whose chroma rows depend on the
+    /// transfer function.
+    fn ictcp_from_lms<T: FreeChannelScalar>() -> Matrix3<T>;
+}
+
+impl<E: num::Float> IctcpTransfer for PqEncoding<E> {
+    fn ictcp_from_lms<T: FreeChannelScalar>() -> Matrix3<T> {
+        ictcp_matrix([2048.0, 2048.0, 0.0, 6610.0, -13613.0, 7003.0, 17933.0, -17390.0, -543.0])
+    }
+}
+
+impl<E: num::Float> IctcpTransfer for HlgEncoding<E> {
+    fn ictcp_from_lms<T: FreeChannelScalar>() -> Matrix3<T> {
+        ictcp_matrix([2048.0, 2048.0, 0.0, 3625.0, -7465.0, 3840.0, 9500.0, -9212.0, -288.0])
+    }
+}
+
+/// BT.2100 gives its matrices as integers in units of 1/4096.
+fn ictcp_matrix<T: FreeChannelScalar>(values: [f64; 9]) -> Matrix3<T> {
+    let mut m = [T::zero(); 9];
+    for (out, &v) in m.iter_mut().zip(values.iter()) {
+        *out = num::cast(v / 4096.0).unwrap();
+    }
+    Matrix3::new(m)
+}
+
+impl<T> ICtCp<T>
+    where T: FreeChannelScalar + PosNormalChannelScalar,
+          Rgb<T>: EncodableColor
+{
+    /// Convert from linear BT.2020 RGB.
+    ///
+    /// With `PqEncoding` the color is display light, where 1.0 is the encoding's peak
+    /// luminance. With `HlgEncoding` it is scene light in [0, 1].
+    pub fn from_rgb<E>(from: &LinearColor<Rgb<T>>, transfer: &E) -> Self
+        where E: IctcpTransfer
+    {
+        let rgb = (from.color().red(), from.color().green(), from.color().blue());
+        let (l, m, s) = Self::lms_from_rgb().transform_vector(rgb);
+        let lms = (transfer.encode_channel(l),
+                   transfer.encode_channel(m),
+                   transfer.encode_channel(s));
+        ICtCp::from_tuple(E::ictcp_from_lms::<T>().transform_vector(lms))
+    }
+
+    /// Convert to linear BT.2020 RGB, with the same transfer function the color was built on.
+    pub fn to_rgb<E>(&self, transfer: &E) -> LinearColor<Rgb<T>>
+        where E: IctcpTransfer
+    {
+        let inverse = E::ictcp_from_lms::<T>().inverse().unwrap();
+        let (l, m, s) = inverse.transform_vector(self.to_tuple());
+        let lms = (transfer.decode_channel(l),
+                   transfer.decode_channel(m),
+                   transfer.decode_channel(s));
+        let (r, g, b) = Self::lms_from_rgb().inverse().unwrap().transform_vector(lms);
+        LinearColor::new(Rgb::from_channels(r, g, b), LinearEncoding::new())
+    }
+
+    /// Get ΔE_ITP (ITU-R BT.2124) between two colors built on `PqEncoding`.
+    ///
+    /// A difference of 1 is about one just noticeable difference.
+    pub fn delta_e_itp(&self, other: &Self) -> T {
+        let half: T = num::cast(0.5).unwrap();
+        let di = self.I() - other.I();
+        let dt = half * (self.Ct() - other.Ct());
+        let dp = self.Cp() - other.Cp();
+        num::cast::<_, T>(720.0).unwrap() * (di * di + dt * dt + dp * dp).sqrt()
+    }
+
+    fn lms_from_rgb() -> Matrix3<T> {
+        ictcp_matrix([1688.0, 2146.0, 262.0, 683.0, 2951.0, 462.0, 99.0, 309.0, 3688.0])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn linear(r: f64, g: f64, b: f64) -> LinearColor<Rgb<f64>> {
+        LinearColor::new(Rgb::from_channels(r, g, b), LinearEncoding::new())
+    }
+
+    #[test]
+    fn test_construct() {
+        let c1 = ICtCp::from_channels(0.5, -0.1, 0.2);
+        assert_relative_eq!(c1.I(), 0.5);
+        assert_relative_eq!(c1.Ct(), -0.1);
+        assert_relative_eq!(c1.Cp(), 0.2);
+        assert_eq!(c1.to_tuple(), (0.5, -0.1, 0.2));
+        assert_relative_eq!(ICtCp::from_tuple(c1.to_tuple()), c1);
+        assert_eq!(c1.as_slice(), &[0.5, -0.1, 0.2]);
+        assert_relative_eq!(c1.lerp(&ICtCp::from_channels(0.7, 0.1, 0.0), 0.5),
+                            ICtCp::from_channels(0.6, 0.0, 0.1));
+    }
+
+    #[test]
+    fn test_pq() {
+        let pq = PqEncoding::<f64>::default();
+        let white = ICtCp::from_rgb(&linear(0.01, 0.01, 0.01), &pq);
+        assert_relative_eq!(white, ICtCp::from_channels(0.508078, 0.0, 0.0), epsilon=1e-6);
+        assert_relative_eq!(white.to_rgb(&pq), linear(0.01, 0.01, 0.01), epsilon=1e-12);
+
+        let c1 = linear(0.05, 0.02, 0.01);
+        let t1 = ICtCp::from_rgb(&c1, &pq);
+        assert_relative_eq!(t1, ICtCp::from_channels(0.612754, -0.077266, 0.141540),
+            epsilon=1e-6);
+        assert_relative_eq!(t1.to_rgb(&pq), c1, epsilon=1e-12);
+
+        // A lower peak luminance scales the same linear values down.
+        let pq1000 = PqEncoding::new(1000.0);
+        let t2 = ICtCp::from_rgb(&c1, &pq1000);
+        assert_relative_eq!(t2, ICtCp::from_channels(0.385209, -0.061666, 0.119483),
+            epsilon=1e-6);
+        assert_relative_eq!(t2.to_rgb(&pq1000), c1, epsilon=1e-12);
+
+        let peak = ICtCp::from_rgb(&linear(1.0, 1.0, 1.0), &pq);
+        assert_relative_eq!(peak, ICtCp::from_channels(1.0, 0.0, 0.0), epsilon=1e-12);
+    }
+
+    #[test]
+    fn test_hlg() {
+        let hlg = HlgEncoding::new(1000.0);
+        let gray = ICtCp::from_rgb(&linear(0.5, 0.5, 0.5), &hlg);
+        assert_relative_eq!(gray, ICtCp::from_channels(0.871643, 0.0, 0.0), epsilon=1e-6);
+
+        let c1 = linear(0.6, 0.3, 0.1);
+        let t1 = ICtCp::from_rgb(&c1, &hlg);
+        assert_relative_eq!(t1, ICtCp::from_channels(0.812889, -0.142193, 0.114145),
+            epsilon=1e-6);
+        assert_relative_eq!(t1.to_rgb(&hlg), c1, epsilon=1e-12);
+    }
+
+    #[test]
+    fn test_delta_e_itp() {
+        let pq = PqEncoding::<f64>::default();
+        let c1 = ICtCp::from_rgb(&linear(0.01, 0.01, 0.01), &pq);
+        let c2 = ICtCp::from_rgb(&linear(0.0105, 0.01, 0.0095), &pq);
+        assert_relative_eq!(c1.delta_e_itp(&c2), 5.522624, epsilon=1e-6);
+        assert_relative_eq!(c2.delta_e_itp(&c1), 5.522624, epsilon=1e-6);
+        assert_relative_eq!(c1.delta_e_itp(&c1), 0.0);
+    }
+}
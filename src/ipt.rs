@@ -0,0 +1,236 @@
+use std::fmt;
+use std::mem;
+use std::slice;
+use num;
+use approx;
+use channel::{PosFreeChannel, FreeChannel, FreeChannelScalar, ChannelFormatCast, ChannelCast,
+              ColorChannel};
+use color::{Color, Bounded, Lerp, Flatten, FromTuple};
+use convert::FromColor;
+use linalg::Matrix3;
+use lms::{LmsModel, HuntPointerEstevez};
+use xyz::Xyz;
+
+pub struct IptTag;
+
+/// Ebner and Fairchild's IPT opponent space, with lightness `i` in [0, 1], the red-green
+/// axis `p` and the yellow-blue axis `t`.
+///
+/// IPT was fitted for hue uniformity, so lines of constant hue stay close to perceived
+/// constant hue, notably for blues where `Lchab` bends towards purple. It is defined for
+/// `Xyz` relative to a D65 white with Y = 1.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Ipt<T> {
+    pub i: PosFreeChannel<T>,
+    pub p: FreeChannel<T>,
+    pub t: FreeChannel<T>,
+}
+
+impl<T> Ipt<T>
+    where T: FreeChannelScalar
+{
+    pub fn from_channels(i: T, p: T, t: T) -> Self {
+        Ipt {
+            i: PosFreeChannel::new(i),
+            p: FreeChannel::new(p),
+            t: FreeChannel::new(t),
+        }
+    }
+
+    impl_color_color_cast_square!(Ipt {i, p, t}, chan_traits={FreeChannelScalar});
+
+    pub fn i(&self) -> T {
+        self.i.0
+    }
+    pub fn p(&self) -> T {
+        self.p.0
+    }
+    pub fn t(&self) -> T {
+        self.t.0
+    }
+    pub fn i_mut(&mut self) -> &mut T {
+        &mut self.i.0
+    }
+    pub fn p_mut(&mut self) -> &mut T {
+        &mut self.p.0
+    }
+    pub fn t_mut(&mut self) -> &mut T {
+        &mut self.t.0
+    }
+    pub fn set_i(&mut self, val: T) {
+        self.i.0 = val;
+    }
+    pub fn set_p(&mut self, val: T) {
+        self.p.0 = val;
+    }
+    pub fn set_t(&mut self, val: T) {
+        self.t.0 = val;
+    }
+}
+
+impl<T> Color for Ipt<T>
+    where T: FreeChannelScalar
+{
+    type Tag = IptTag;
+    type ChannelsTuple = (T, T, T);
+
+    #[inline]
+    fn num_channels() -> u32 {
+        3
+    }
+    fn to_tuple(self) -> Self::ChannelsTuple {
+        (self.i.0, self.p.0, self.t.0)
+    }
+}
+
+impl<T> FromTuple for Ipt<T>
+    where T: FreeChannelScalar
+{
+    fn from_tuple(values: (T, T, T)) -> Self {
+        Ipt::from_channels(values.0, values.1, values.2)
+    }
+}
+
+impl<T> Bounded for Ipt<T>
+    where T: FreeChannelScalar
+{
+    fn normalize(self) -> Self {
+        Ipt::from_channels(self.i.normalize().0, self.p(), self.t())
+    }
+    fn is_normalized(&self) -> bool {
+        self.i.is_normalized()
+    }
+}
+
+impl<T> Lerp for Ipt<T>
+    where T: FreeChannelScalar + Lerp
+{
+    type Position = <FreeChannel<T> as Lerp>::Position;
+    impl_color_lerp_square!(Ipt {i, p, t});
+}
+
+impl<T> Flatten for Ipt<T>
+    where T: FreeChannelScalar
+{
+    type ScalarFormat = T;
+
+    impl_color_as_slice!(T);
+    impl_color_from_slice_square!(Ipt<T> {i:PosFreeChannel - 0, p:FreeChannel - 1,
+        t:FreeChannel - 2});
+}
+
+impl<T> approx::ApproxEq for Ipt<T>
+    where T: FreeChannelScalar + approx::ApproxEq,
+          T::Epsilon: Clone
+{
+    impl_approx_eq!({i, p, t});
+}
+
+impl<T> Default for Ipt<T>
+    where T: FreeChannelScalar
+{
+    impl_color_default!(Ipt {i:PosFreeChannel, p:FreeChannel, t:FreeChannel});
+}
+
+impl<T> fmt::Display for Ipt<T>
+    where T: FreeChannelScalar + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "IPT({}, {}, {})", self.i, self.p, self.t)
+    }
+}
+
+impl<T> Ipt<T>
+    where T: FreeChannelScalar
+{
+    fn ipt_from_lms() -> Matrix3<T> {
+        Matrix3::new([num::cast(0.4000).unwrap(),
+                      num::cast(0.4000).unwrap(),
+                      num::cast(0.2000).unwrap(),
+                      num::cast(4.4550).unwrap(),
+                      num::cast(-4.8510).unwrap(),
+                      num::cast(0.3960).unwrap(),
+                      num::cast(0.8056).unwrap(),
+                      num::cast(0.3572).unwrap(),
+                      num::cast(-1.1628).unwrap()])
+    }
+
+    fn compress(val: T) -> T {
+        val.signum() * val.abs().powf(num::cast(0.43).unwrap())
+    }
+
+    fn expand(val: T) -> T {
+        val.signum() * val.abs().powf(num::cast(1.0 / 0.43).unwrap())
+    }
+}
+
+impl<T> FromColor<Xyz<T>> for Ipt<T>
+    where T: FreeChannelScalar
+{
+    fn from_color(from: &Xyz<T>) -> Self {
+        let forward: Matrix3<T> = HuntPointerEstevez::forward_transform();
+        let lms = forward.transform_vector(from.to_tuple());
+        let lms = (Self::compress(lms.0), Self::compress(lms.1), Self::compress(lms.2));
+        Ipt::from_tuple(Self::ipt_from_lms().transform_vector(lms))
+    }
+}
+
+impl<T> FromColor<Ipt<T>> for Xyz<T>
+    where T: FreeChannelScalar
+{
+    fn from_color(from: &Ipt<T>) -> Self {
+        let inverse = Ipt::<T>::ipt_from_lms().inverse().unwrap();
+        let lms = inverse.transform_vector(from.to_tuple());
+        let lms = (Ipt::expand(lms.0), Ipt::expand(lms.1), Ipt::expand(lms.2));
+        let inverse: Matrix3<T> = HuntPointerEstevez::inverse_transform();
+        Xyz::from_tuple(inverse.transform_vector(lms))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use white_point::{NamedWhitePoint, D65};
+
+    #[test]
+    fn test_construct() {
+        let c1 = Ipt::from_channels(0.5, -0.2, 0.3);
+        assert_relative_eq!(c1.i(), 0.5);
+        assert_relative_eq!(c1.p(), -0.2);
+        assert_relative_eq!(c1.t(), 0.3);
+        assert_eq!(c1.to_tuple(), (0.5, -0.2, 0.3));
+        assert_relative_eq!(Ipt::from_tuple(c1.to_tuple()), c1);
+        assert_eq!(c1.as_slice(), &[0.5, -0.2, 0.3]);
+        assert_relative_eq!(Ipt::from_slice(c1.as_slice()), c1);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let c1 = Ipt::from_channels(0.2, 0.1, -0.4);
+        let c2 = Ipt::from_channels(0.6, -0.1, 0.0);
+        assert_relative_eq!(c1.lerp(&c2, 0.0), c1);
+        assert_relative_eq!(c1.lerp(&c2, 1.0), c2);
+        assert_relative_eq!(c1.lerp(&c2, 0.5), Ipt::from_channels(0.4, 0.0, -0.2));
+    }
+
+    #[test]
+    fn test_from_xyz() {
+        let c1 = Xyz::from_channels(0.20654008, 0.12197225, 0.05136952);
+        let t1 = Ipt::from_color(&c1);
+        assert_relative_eq!(t1, Ipt::from_channels(0.38426191, 0.38487306, 0.18886838),
+            epsilon=1e-8);
+        assert_relative_eq!(Xyz::from_color(&t1), c1, epsilon=1e-12);
+
+        let white = Ipt::from_color(&D65::get_xyz());
+        assert_relative_eq!(white, Ipt::from_channels(1.0, 0.0, 0.0), epsilon=1e-3);
+
+        let c2 = Xyz::from_channels(0.3, 0.22, 0.5);
+        let t2 = Ipt::from_color(&c2);
+        assert_relative_eq!(t2, Ipt::from_channels(0.564478, 0.169154, -0.215087), epsilon=1e-6);
+        assert_relative_eq!(Xyz::from_color(&t2), c2, epsilon=1e-12);
+
+        let black = Ipt::from_color(&Xyz::from_channels(0.0, 0.0, 0.0));
+        assert_relative_eq!(black, Ipt::from_channels(0.0, 0.0, 0.0));
+    }
+}
@@ -0,0 +1,301 @@
+#![allow(non_snake_case)]
+
+use std::fmt;
+use std::mem;
+use std::slice;
+use num;
+use approx;
+use channel::{PosFreeChannel, FreeChannel, FreeChannelScalar, ChannelFormatCast, ChannelCast,
+              ColorChannel};
+use color::{Color, Bounded, Lerp, Flatten, FromTuple};
+use convert::FromColor;
+use linalg::Matrix3;
+use xyz::Xyz;
+
+pub struct JzazbzTag;
+
+/// Safdar et al.'s Jzazbz, a perceptually uniform space for HDR and wide gamut content.
+///
+/// Unlike `Lab`, Jzazbz has no white point: it is computed from absolute `Xyz` in cd/m²,
+/// through the PQ curve, so it stays uniform up to 10000 cd/m². A diffuse white of
+/// 100 cd/m² has a `Jz` of about 0.167.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Jzazbz<T> {
+    pub Jz: PosFreeChannel<T>,
+    pub az: FreeChannel<T>,
+    pub bz: FreeChannel<T>,
+}
+
+impl<T> Jzazbz<T>
+    where T: FreeChannelScalar
+{
+    pub fn from_channels(Jz: T, az: T, bz: T) -> Self {
+        Jzazbz {
+            Jz: PosFreeChannel::new(Jz),
+            az: FreeChannel::new(az),
+            bz: FreeChannel::new(bz),
+        }
+    }
+
+    impl_color_color_cast_square!(Jzazbz {Jz, az, bz}, chan_traits={FreeChannelScalar});
+
+    pub fn Jz(&self) -> T {
+        self.Jz.0
+    }
+    pub fn az(&self) -> T {
+        self.az.0
+    }
+    pub fn bz(&self) -> T {
+        self.bz.0
+    }
+    pub fn Jz_mut(&mut self) -> &mut T {
+        &mut self.Jz.0
+    }
+    pub fn az_mut(&mut self) -> &mut T {
+        &mut self.az.0
+    }
+    pub fn bz_mut(&mut self) -> &mut T {
+        &mut self.bz.0
+    }
+    pub fn set_Jz(&mut self, val: T) {
+        self.Jz.0 = val;
+    }
+    pub fn set_az(&mut self, val: T) {
+        self.az.0 = val;
+    }
+    pub fn set_bz(&mut self, val: T) {
+        self.bz.0 = val;
+    }
+}
+
+impl<T> Color for Jzazbz<T>
+    where T: FreeChannelScalar
+{
+    type Tag = JzazbzTag;
+    type ChannelsTuple = (T, T, T);
+
+    #[inline]
+    fn num_channels() -> u32 {
+        3
+    }
+    fn to_tuple(self) -> Self::ChannelsTuple {
+        (self.Jz.0, self.az.0, self.bz.0)
+    }
+}
+
+impl<T> FromTuple for Jzazbz<T>
+    where T: FreeChannelScalar
+{
+    fn from_tuple(values: (T, T, T)) -> Self {
+        Jzazbz::from_channels(values.0, values.1, values.2)
+    }
+}
+
+impl<T> Bounded for Jzazbz<T>
+    where T: FreeChannelScalar
+{
+    fn normalize(self) -> Self {
+        Jzazbz::from_channels(self.Jz.normalize().0, self.az(), self.bz())
+    }
+    fn is_normalized(&self) -> bool {
+        self.Jz.is_normalized()
+    }
+}
+
+impl<T> Lerp for Jzazbz<T>
+    where T: FreeChannelScalar + Lerp
+{
+    type Position = <FreeChannel<T> as Lerp>::Position;
+    impl_color_lerp_square!(Jzazbz {Jz, az, bz});
+}
+
+impl<T> Flatten for Jzazbz<T>
+    where T: FreeChannelScalar
+{
+    type ScalarFormat = T;
+
+    impl_color_as_slice!(T);
+    impl_color_from_slice_square!(Jzazbz<T> {Jz:PosFreeChannel - 0, az:FreeChannel - 1,
+        bz:FreeChannel - 2});
+}
+
+impl<T> approx::ApproxEq for Jzazbz<T>
+    where T: FreeChannelScalar + approx::ApproxEq,
+          T::Epsilon: Clone
+{
+    impl_approx_eq!({Jz, az, bz});
+}
+
+impl<T> Default for Jzazbz<T>
+    where T: FreeChannelScalar
+{
+    impl_color_default!(Jzazbz {Jz:PosFreeChannel, az:FreeChannel, bz:FreeChannel});
+}
+
+impl<T> fmt::Display for Jzazbz<T>
+    where T: FreeChannelScalar + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Jzazbz({}, {}, {})", self.Jz, self.az, self.bz)
+    }
+}
+
+impl<T> Jzazbz<T>
+    where T: FreeChannelScalar
+{
+    /// Convert from absolute `Xyz` in cd/m².
+    pub fn from_xyz(from: &Xyz<T>) -> Jzazbz<T> {
+        let (b, g) = Self::premultipliers();
+        let x = b * from.x() - (b - T::one()) * from.z();
+        let y = g * from.y() - (g - T::one()) * from.x();
+        let (l, m, s) = Self::lms_from_xyz().transform_vector((x, y, from.z()));
+        let lms = (Self::pq(l), Self::pq(m), Self::pq(s));
+        let (iz, az, bz) = Self::izazbz_from_lms().transform_vector(lms);
+
+        let (d, d0) = Self::lightness_constants();
+        let Jz = (T::one() + d) * iz / (T::one() + d * iz) - d0;
+        Jzazbz::from_channels(Jz, az, bz)
+    }
+
+    /// Convert to absolute `Xyz` in cd/m².
+    pub fn to_xyz(&self) -> Xyz<T> {
+        let (d, d0) = Self::lightness_constants();
+        let jz = self.Jz() + d0;
+        let iz = jz / (T::one() + d - d * jz);
+        let inverse = Self::izazbz_from_lms().inverse().unwrap();
+        let (l, m, s) = inverse.transform_vector((iz, self.az(), self.bz()));
+        let lms = (Self::pq_inverse(l), Self::pq_inverse(m), Self::pq_inverse(s));
+        let (x, y, z) = Self::lms_from_xyz().inverse().unwrap().transform_vector(lms);
+
+        let (b, g) = Self::premultipliers();
+        let x = (x + (b - T::one()) * z) / b;
+        let y = (y + (g - T::one()) * x) / g;
+        Xyz::from_channels(x, y, z)
+    }
+
+    fn premultipliers() -> (T, T) {
+        (num::cast(1.15).unwrap(), num::cast(0.66).unwrap())
+    }
+
+    fn lightness_constants() -> (T, T) {
+        (num::cast(-0.56).unwrap(), num::cast(1.6295499532821566e-11).unwrap())
+    }
+
+    // The PQ curve of SMPTE ST 2084, with the exponent m2 raised to 1.7 * 2523 / 32.
+    fn pq_constants() -> (T, T, T, T, T) {
+        (num::cast(2610.0 / 16384.0).unwrap(),
+         num::cast(1.7 * 2523.0 / 32.0).unwrap(),
+         num::cast(3424.0 / 4096.0).unwrap(),
+         num::cast(2413.0 / 128.0).unwrap(),
+         num::cast(2392.0 / 128.0).unwrap())
+    }
+
+    fn pq(val: T) -> T {
+        let (n, p, c1, c2, c3) = Self::pq_constants();
+        let y = (val.abs() / num::cast(10000.0).unwrap()).powf(n);
+        let encoded = ((c1 + c2 * y) / (T::one() + c3 * y)).powf(p);
+        if val < T::zero() { -encoded } else { encoded }
+    }
+
+    fn pq_inverse(val: T) -> T {
+        let (n, p, c1, c2, c3) = Self::pq_constants();
+        let powed = val.abs().powf(T::one() / p);
+        let numerator = (powed - c1).max(T::zero());
+        let y = (numerator / (c2 - c3 * powed)).powf(T::one() / n);
+        val.signum() * y * num::cast(10000.0).unwrap()
+    }
+
+    fn lms_from_xyz() -> Matrix3<T> {
+        Matrix3::new([num::cast(0.41478972).unwrap(),
+                      num::cast(0.579999).unwrap(),
+                      num::cast(0.0146480).unwrap(),
+                      num::cast(-0.2015100).unwrap(),
+                      num::cast(1.120649).unwrap(),
+                      num::cast(0.0531008).unwrap(),
+                      num::cast(-0.0166008).unwrap(),
+                      num::cast(0.264800).unwrap(),
+                      num::cast(0.6684799).unwrap()])
+    }
+
+    fn izazbz_from_lms() -> Matrix3<T> {
+        Matrix3::new([num::cast(0.5).unwrap(),
+                      num::cast(0.5).unwrap(),
+                      num::cast(0.0).unwrap(),
+                      num::cast(3.524000).unwrap(),
+                      num::cast(-4.066708).unwrap(),
+                      num::cast(0.542708).unwrap(),
+                      num::cast(0.199076).unwrap(),
+                      num::cast(1.096799).unwrap(),
+                      num::cast(-1.295875).unwrap()])
+    }
+}
+
+impl<T> FromColor<Xyz<T>> for Jzazbz<T>
+    where T: FreeChannelScalar
+{
+    /// Convert from absolute `Xyz` in cd/m².
+    fn from_color(from: &Xyz<T>) -> Self {
+        Jzazbz::from_xyz(from)
+    }
+}
+
+impl<T> FromColor<Jzazbz<T>> for Xyz<T>
+    where T: FreeChannelScalar
+{
+    /// Convert to absolute `Xyz` in cd/m².
+    fn from_color(from: &Jzazbz<T>) -> Self {
+        from.to_xyz()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_construct() {
+        let c1 = Jzazbz::from_channels(0.15, -0.02, 0.05);
+        assert_relative_eq!(c1.Jz(), 0.15);
+        assert_relative_eq!(c1.az(), -0.02);
+        assert_relative_eq!(c1.bz(), 0.05);
+        assert_eq!(c1.to_tuple(), (0.15, -0.02, 0.05));
+        assert_relative_eq!(Jzazbz::from_tuple(c1.to_tuple()), c1);
+        assert_eq!(c1.as_slice(), &[0.15, -0.02, 0.05]);
+        assert_relative_eq!(Jzazbz::from_slice(c1.as_slice()), c1);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let c1 = Jzazbz::from_channels(0.1, 0.02, -0.04);
+        let c2 = Jzazbz::from_channels(0.3, -0.02, 0.0);
+        assert_relative_eq!(c1.lerp(&c2, 0.0), c1);
+        assert_relative_eq!(c1.lerp(&c2, 1.0), c2);
+        assert_relative_eq!(c1.lerp(&c2, 0.5), Jzazbz::from_channels(0.2, 0.0, -0.02));
+    }
+
+    #[test]
+    fn test_from_xyz() {
+        let c1 = Xyz::from_channels(0.20654008, 0.12197225, 0.05136952);
+        let t1 = Jzazbz::from_xyz(&c1);
+        assert_relative_eq!(t1, Jzazbz::from_channels(0.00535048, 0.00924302, 0.00526007),
+            epsilon=1e-8);
+        assert_relative_eq!(t1.to_xyz(), c1, epsilon=1e-9);
+
+        let c2 = Xyz::from_channels(95.047, 100.0, 108.883);
+        let t2 = Jzazbz::from_color(&c2);
+        assert_relative_eq!(t2, Jzazbz::from_channels(0.167174, -0.000134, -0.000082),
+            epsilon=1e-6);
+        assert_relative_eq!(Xyz::from_color(&t2), c2, epsilon=1e-9);
+
+        // Lightness keeps growing well past SDR white.
+        let c3 = Xyz::from_channels(950.47, 1000.0, 1088.83);
+        let t3 = Jzazbz::from_color(&c3);
+        assert_relative_eq!(t3.Jz(), 0.409124, epsilon=1e-6);
+        assert_relative_eq!(Xyz::from_color(&t3), c3, epsilon=1e-8);
+
+        let black = Jzazbz::from_xyz(&Xyz::from_channels(0.0, 0.0, 0.0));
+        assert_relative_eq!(black, Jzazbz::from_channels(0.0, 0.0, 0.0), epsilon=1e-12);
+        assert_relative_eq!(black.to_xyz(), Xyz::from_channels(0.0, 0.0, 0.0), epsilon=1e-12);
+    }
+}
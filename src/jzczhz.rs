@@ -0,0 +1,261 @@
+#![allow(non_snake_case)]
+
+use std::fmt;
+use std::mem;
+use std::slice;
+use num;
+use approx;
+use angle::{Deg, Angle, FromAngle, IntoAngle, Turns, Rad};
+use angle;
+use channel::{PosFreeChannel, FreeChannelScalar, AngularChannel,
+              AngularChannelScalar, ChannelFormatCast, ChannelCast, ColorChannel};
+use color::{Color, PolarColor, FromTuple, Lerp, Bounded, Flatten};
+use convert::{GetChroma, GetHue, FromColor};
+use jzazbz::Jzazbz;
+use xyz::Xyz;
+
+pub struct JzCzhzTag;
+
+/// The polar form of `Jzazbz`, with the same absolute lightness `Jz`, chroma `Cz` and
+/// hue angle `hz`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct JzCzhz<T, A = Deg<T>> {
+    pub Jz: PosFreeChannel<T>,
+    pub chroma: PosFreeChannel<T>,
+    pub hue: AngularChannel<A>,
+}
+
+impl<T, A> JzCzhz<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    pub fn from_channels(Jz: T, chroma: T, hue: A) -> Self {
+        JzCzhz {
+            Jz: PosFreeChannel::new(Jz),
+            chroma: PosFreeChannel::new(chroma),
+            hue: AngularChannel::new(hue),
+        }
+    }
+
+    impl_color_color_cast_angular!(JzCzhz {Jz, chroma, hue}, 
+        chan_traits={FreeChannelScalar});
+
+    pub fn Jz(&self) -> T {
+        self.Jz.0
+    }
+    pub fn chroma(&self) -> T {
+        self.chroma.0
+    }
+    pub fn hue(&self) -> A {
+        self.hue.0.clone()
+    }
+    pub fn Jz_mut(&mut self) -> &mut T {
+        &mut self.Jz.0
+    }
+    pub fn chroma_mut(&mut self) -> &mut T {
+        &mut self.chroma.0
+    }
+    pub fn hue_mut(&mut self) -> &mut A {
+        &mut self.hue.0
+    }
+    pub fn set_Jz(&mut self, val: T) {
+        self.Jz.0 = val;
+    }
+    pub fn set_chroma(&mut self, val: T) {
+        self.chroma.0 = val;
+    }
+    pub fn set_hue(&mut self, val: A) {
+        self.hue.0 = val;
+    }
+}
+
+impl<T, A> Color for JzCzhz<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type Tag = JzCzhzTag;
+    type ChannelsTuple = (T, T, A);
+
+    fn num_channels() -> u32 {
+        3
+    }
+    fn to_tuple(self) -> Self::ChannelsTuple {
+        (self.Jz.0, self.chroma.0, self.hue.0)
+    }
+}
+
+impl<T, A> PolarColor for JzCzhz<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type Angular = A;
+    type Cartesian = T;
+}
+
+impl<T, A> FromTuple for JzCzhz<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    fn from_tuple(values: Self::ChannelsTuple) -> Self {
+        JzCzhz::from_channels(values.0, values.1, values.2)
+    }
+}
+
+impl<T, A> Lerp for JzCzhz<T, A>
+    where T: FreeChannelScalar + Lerp,
+          A: AngularChannelScalar + Lerp
+{
+    type Position = A::Position;
+
+    impl_color_lerp_angular!(JzCzhz<T> {hue, Jz, chroma});
+}
+
+impl<T, A> Bounded for JzCzhz<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_bounded!(JzCzhz {Jz, chroma, hue});
+}
+
+impl<T, A> Flatten for JzCzhz<T, A>
+    where T: FreeChannelScalar + num::Float,
+          A: AngularChannelScalar + Angle<Scalar = T> + FromAngle<Turns<T>>
+{
+    type ScalarFormat = T;
+
+    impl_color_as_slice!(T);
+
+    fn from_slice(vals: &[T]) -> Self {
+        JzCzhz::from_channels(vals[0],
+                             vals[1],
+                             A::from_angle(angle::Turns(vals[2])))
+    }
+}
+
+impl<T, A> approx::ApproxEq for JzCzhz<T, A>
+    where T: FreeChannelScalar + approx::ApproxEq<Epsilon = A::Epsilon>,
+          A: AngularChannelScalar + approx::ApproxEq,
+          A::Epsilon: Clone + num::Float
+{
+    impl_approx_eq!({Jz, chroma, hue});
+}
+
+impl<T, A> Default for JzCzhz<T, A>
+    where T: FreeChannelScalar + num::Zero,
+          A: AngularChannelScalar + num::Zero
+{
+    impl_color_default!(JzCzhz {hue: AngularChannel, 
+        Jz: PosFreeChannel, chroma: PosFreeChannel});
+}
+
+impl<T, A> fmt::Display for JzCzhz<T, A>
+    where T: FreeChannelScalar + fmt::Display,
+          A: AngularChannelScalar + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "JzCzhz({}, {}, {})", self.Jz, self.chroma, self.hue)
+    }
+}
+
+impl<T, A> GetChroma for JzCzhz<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type ChromaType = T;
+    fn get_chroma(&self) -> T {
+        self.chroma()
+    }
+}
+
+impl<T, A> GetHue for JzCzhz<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_get_hue_angular!(JzCzhz);
+}
+
+impl<T, A> FromColor<Jzazbz<T>> for JzCzhz<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + FromAngle<Rad<T>> + Angle
+{
+    fn from_color(from: &Jzazbz<T>) -> Self {
+        let Jz = from.Jz();
+        let chroma = (from.az() * from.az() + from.bz() * from.bz()).sqrt();
+        let hue = A::from_angle(Rad::atan2(from.bz(), from.az()));
+
+        JzCzhz::from_channels(Jz, chroma, <A as Angle>::normalize(hue))
+    }
+}
+
+impl<T, A> FromColor<JzCzhz<T, A>> for Jzazbz<T>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn from_color(from: &JzCzhz<T, A>) -> Self {
+        let Jz = from.Jz();
+        let az = from.chroma() * from.hue().cos();
+        let bz = from.chroma() * from.hue().sin();
+
+        Jzazbz::from_channels(Jz, az, bz)
+    }
+}
+
+impl<T, A> FromColor<Xyz<T>> for JzCzhz<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + FromAngle<Rad<T>> + Angle
+{
+    fn from_color(from: &Xyz<T>) -> Self {
+        JzCzhz::from_color(&Jzazbz::from_color(from))
+    }
+}
+
+impl<T, A> FromColor<JzCzhz<T, A>> for Xyz<T>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn from_color(from: &JzCzhz<T, A>) -> Self {
+        Xyz::from_color(&Jzazbz::from_color(from))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use jzazbz::Jzazbz;
+    use xyz::Xyz;
+
+    #[test]
+    fn test_construct() {
+        let c1 = JzCzhz::from_channels(0.15, 0.02, Deg(90.0));
+        assert_relative_eq!(c1.Jz(), 0.15);
+        assert_relative_eq!(c1.chroma(), 0.02);
+        assert_relative_eq!(c1.hue(), Deg(90.0));
+        assert_eq!(c1.to_tuple(), (0.15, 0.02, Deg(90.0)));
+        assert_relative_eq!(JzCzhz::from_tuple(c1.to_tuple()), c1);
+        assert_eq!(c1.get_hue::<Turns<_>>(), Turns(0.25));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let c1 = JzCzhz::from_channels(0.1, 0.03, Deg(300.0));
+        let c2 = JzCzhz::from_channels(0.3, 0.01, Deg(50.0));
+        assert_relative_eq!(c1.lerp(&c2, 0.0), c1);
+        assert_relative_eq!(c1.lerp(&c2, 1.0), c2);
+        assert_relative_eq!(c1.lerp(&c2, 0.5), JzCzhz::from_channels(0.2, 0.02, Deg(355.0)));
+    }
+
+    #[test]
+    fn test_from_jzazbz() {
+        let c1 = Jzazbz::from_channels(0.1, 0.01, 0.01);
+        let t1 = JzCzhz::from_color(&c1);
+        assert_relative_eq!(t1, JzCzhz::from_channels(0.1, 0.0141421, Deg(45.0)), epsilon=1e-6);
+        assert_relative_eq!(Jzazbz::from_color(&t1), c1, epsilon=1e-9);
+
+        let c2 = Xyz::from_channels(30.0, 22.0, 50.0);
+        let t2: JzCzhz<f64> = JzCzhz::from_color(&c2);
+        assert_relative_eq!(t2, JzCzhz::from_channels(0.094564, 0.058778, Deg(305.969776)),
+            epsilon=1e-6);
+        assert_relative_eq!(Xyz::from_color(&t2), c2, epsilon=1e-9);
+    }
+}
@@ -199,6 +199,56 @@ impl<T, A> FromColor<Lchuv<T, A>> for Luv<T>
     }
 }
 
+impl<T, A> Lchuv<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    /// The largest chroma in the sRGB gamut at lightness `L` and `hue`, relative to D65.
+    pub fn srgb_max_chroma(L: T, hue: A) -> T {
+        let (sin, cos) = hue.sin_cos();
+        Self::srgb_bounds(L)
+            .iter()
+            .filter_map(|&(slope, intercept)| {
+                let length = intercept / (sin - slope * cos);
+                if length.is_finite() && length >= T::zero() { Some(length) } else { None }
+            })
+            .fold(T::infinity(), |min, length| min.min(length))
+    }
+
+    /// The largest chroma in the sRGB gamut at lightness `L` for every hue.
+    pub fn srgb_max_safe_chroma(L: T) -> T {
+        Self::srgb_bounds(L)
+            .iter()
+            .map(|&(slope, intercept)| intercept.abs() / (slope * slope + T::one()).sqrt())
+            .fold(T::infinity(), |min, distance| min.min(distance))
+    }
+
+    /// The lines bounding the sRGB gamut in the uv plane at lightness `L`, as slopes and
+    /// intercepts: where each of the red, green and blue channels reaches 0 and 1.
+    fn srgb_bounds(L: T) -> [(T, T); 6] {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        let srgb_from_xyz = [[3.240969941904521, -1.537383177570093, -0.498610760293],
+                             [-0.96924363628087, 1.87596750150772, 0.041555057407175],
+                             [0.055630079696993, -0.20397695888897, 1.056971514242878]];
+        let sub1 = (L + c(16.0)).powi(3) / c(1560896.0);
+        let sub2 = if sub1 > c(0.0088564516) { sub1 } else { L / c(903.2962962) };
+
+        let mut bounds = [(T::zero(), T::zero()); 6];
+        for (i, row) in srgb_from_xyz.iter().enumerate() {
+            let (m1, m2, m3) = (c(row[0]), c(row[1]), c(row[2]));
+            for (j, &t) in [0.0, 1.0].iter().enumerate() {
+                let t = c(t);
+                let top1 = (c(284517.0) * m1 - c(94839.0) * m3) * sub2;
+                let top2 = (c(838422.0) * m3 + c(769860.0) * m2 + c(731718.0) * m1) * L * sub2 -
+                           c(769860.0) * t * L;
+                let bottom = (c(632260.0) * m3 - c(126452.0) * m2) * sub2 + c(126452.0) * t;
+                bounds[2 * i + j] = (top1 / bottom, top2 / bottom);
+            }
+        }
+        bounds
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -339,4 +389,12 @@ mod test {
             Lchuv::from_channels(88.0f32, 31.0f32, Turns(0.5f32)));
         assert_relative_eq!(c1.color_cast::<f32, Rad<f32>>().color_cast(), c1);
     }
+
+    #[test]
+    fn test_srgb_max_chroma() {
+        assert_relative_eq!(Lchuv::srgb_max_chroma(50.0, Deg(120.0)), 68.875423, epsilon=1e-5);
+        assert_relative_eq!(Lchuv::<f64>::srgb_max_safe_chroma(50.0), 39.403126, epsilon=1e-5);
+        assert!(Lchuv::<f64>::srgb_max_safe_chroma(50.0)
+            <= Lchuv::srgb_max_chroma(50.0, Deg(120.0)));
+    }
 }
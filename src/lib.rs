@@ -11,15 +11,23 @@ pub mod convert;
 pub mod color;
 pub mod chromaticity;
 pub mod linalg;
+pub mod chromatic_adaptation;
+pub mod difference;
+pub mod cam;
 
 pub mod white_point;
+pub mod spectrum;
 
 pub mod encoding;
 pub mod color_space;
+pub mod icc;
+pub mod css;
+pub mod named;
 
 pub mod alpha;
 
 pub mod rgb;
+pub mod cmyk;
 pub mod rgi;
 pub mod hsv;
 pub mod hsl;
@@ -34,6 +42,18 @@ pub mod lchab;
 pub mod luv;
 pub mod lchuv;
 pub mod lms;
+pub mod oklab;
+pub mod oklch;
+pub mod okhsv;
+pub mod okhsl;
+pub mod hct;
+pub mod jzazbz;
+pub mod jzczhz;
+pub mod ictcp;
+pub mod ipt;
+pub mod ich;
+pub mod hsluv;
+pub mod hpluv;
 
 #[cfg(test)]
 pub mod test;
@@ -32,10 +32,27 @@ pub struct CieCam2002;
 pub struct CieCam97s;
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Bradford;
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VonKries;
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Sharp;
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cat16;
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct XyzScaling;
+/// The Hunt-Pointer-Estevez cone space normalized to D65, as used by IPT.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HuntPointerEstevez;
+
+pub type Cat02 = CieCam2002;
 
 pub type LmsCam2002<T> = Lms<T, CieCam2002>;
 pub type LmsCam97s<T> = Lms<T, CieCam97s>;
 pub type LmsBradford<T> = Lms<T, Bradford>;
+pub type LmsVonKries<T> = Lms<T, VonKries>;
+pub type LmsSharp<T> = Lms<T, Sharp>;
+pub type LmsCat16<T> = Lms<T, Cat16>;
+pub type LmsHuntPointerEstevez<T> = Lms<T, HuntPointerEstevez>;
 
 impl<T, Model> Lms<T, Model>
     where T: FreeChannelScalar,
@@ -282,6 +299,123 @@ impl<T> LmsModel<T> for Bradford
     }
 }
 
+impl<T> LmsModel<T> for VonKries
+    where T: FreeChannelScalar
+{
+    fn forward_transform() -> Matrix3<T> {
+        Matrix3::<T>::new([num::cast(0.40024).unwrap(),
+                           num::cast(0.70760).unwrap(),
+                           num::cast(-0.08081).unwrap(),
+                           num::cast(-0.22630).unwrap(),
+                           num::cast(1.16532).unwrap(),
+                           num::cast(0.04570).unwrap(),
+                           num::cast(0.0).unwrap(),
+                           num::cast(0.0).unwrap(),
+                           num::cast(0.91822).unwrap()])
+    }
+
+    fn inverse_transform() -> Matrix3<T> {
+        Matrix3::<T>::new([num::cast(1.8599364).unwrap(),
+                           num::cast(-1.1293816).unwrap(),
+                           num::cast(0.2198974).unwrap(),
+                           num::cast(0.3611914).unwrap(),
+                           num::cast(0.6388125).unwrap(),
+                           num::cast(-0.0000064).unwrap(),
+                           num::cast(0.0).unwrap(),
+                           num::cast(0.0).unwrap(),
+                           num::cast(1.0890636).unwrap()])
+    }
+}
+
+impl<T> LmsModel<T> for Sharp
+    where T: FreeChannelScalar
+{
+    fn forward_transform() -> Matrix3<T> {
+        Matrix3::<T>::new([num::cast(1.2694).unwrap(),
+                           num::cast(-0.0988).unwrap(),
+                           num::cast(-0.1706).unwrap(),
+                           num::cast(-0.8364).unwrap(),
+                           num::cast(1.8006).unwrap(),
+                           num::cast(0.0357).unwrap(),
+                           num::cast(0.0297).unwrap(),
+                           num::cast(-0.0315).unwrap(),
+                           num::cast(1.0018).unwrap()])
+    }
+
+    fn inverse_transform() -> Matrix3<T> {
+        Matrix3::<T>::new([num::cast(0.8156333).unwrap(),
+                           num::cast(0.0471548).unwrap(),
+                           num::cast(0.1372166).unwrap(),
+                           num::cast(0.3791144).unwrap(),
+                           num::cast(0.5769424).unwrap(),
+                           num::cast(0.0440009).unwrap(),
+                           num::cast(-0.0122601).unwrap(),
+                           num::cast(0.0167431).unwrap(),
+                           num::cast(0.9955188).unwrap()])
+    }
+}
+
+impl<T> LmsModel<T> for Cat16
+    where T: FreeChannelScalar
+{
+    fn forward_transform() -> Matrix3<T> {
+        Matrix3::<T>::new([num::cast(0.401288).unwrap(),
+                           num::cast(0.650173).unwrap(),
+                           num::cast(-0.051461).unwrap(),
+                           num::cast(-0.250268).unwrap(),
+                           num::cast(1.204414).unwrap(),
+                           num::cast(0.045854).unwrap(),
+                           num::cast(-0.002079).unwrap(),
+                           num::cast(0.048952).unwrap(),
+                           num::cast(0.953127).unwrap()])
+    }
+
+    fn inverse_transform() -> Matrix3<T> {
+        Matrix3::<T>::new([num::cast(1.8620679).unwrap(),
+                           num::cast(-1.0112546).unwrap(),
+                           num::cast(0.1491868).unwrap(),
+                           num::cast(0.3875265).unwrap(),
+                           num::cast(0.6214474).unwrap(),
+                           num::cast(-0.0089740).unwrap(),
+                           num::cast(-0.0158415).unwrap(),
+                           num::cast(-0.0341229).unwrap(),
+                           num::cast(1.0499644).unwrap()])
+    }
+}
+
+impl<T> LmsModel<T> for XyzScaling
+    where T: FreeChannelScalar
+{
+    fn forward_transform() -> Matrix3<T> {
+        Matrix3::identity()
+    }
+
+    fn inverse_transform() -> Matrix3<T> {
+        Matrix3::identity()
+    }
+}
+
+impl<T> LmsModel<T> for HuntPointerEstevez
+    where T: FreeChannelScalar
+{
+    fn forward_transform() -> Matrix3<T> {
+        Matrix3::<T>::new([num::cast(0.4002).unwrap(),
+                           num::cast(0.7075).unwrap(),
+                           num::cast(-0.0807).unwrap(),
+                           num::cast(-0.2280).unwrap(),
+                           num::cast(1.1500).unwrap(),
+                           num::cast(0.0612).unwrap(),
+                           num::cast(0.0).unwrap(),
+                           num::cast(0.0).unwrap(),
+                           num::cast(0.9184).unwrap()])
+    }
+
+    // Only the forward matrix is published to four places, so invert it exactly.
+    fn inverse_transform() -> Matrix3<T> {
+        Self::forward_transform().inverse().unwrap()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -344,6 +478,35 @@ mod test {
         let t4 = Lms::<_, Bradford>::from_color(&c4);
         assert_relative_eq!(t4, Lms::from_channels(0.1621, 0.38603, 0.6050), epsilon=1e-4);
         assert_relative_eq!(Xyz::from_color(&t4), c4, epsilon=1e-4);
+
+        let c5 = Xyz::from_channels(0.2, 0.3, 0.6);
+        let t5 = LmsVonKries::from_color(&c5);
+        assert_relative_eq!(t5, Lms::from_channels(0.243842, 0.331756, 0.550932), epsilon=1e-4);
+        assert_relative_eq!(Xyz::from_color(&t5), c5, epsilon=1e-4);
+
+        let c6 = Xyz::from_channels(0.6, 0.4, 0.5);
+        let t6 = LmsCat16::from_color(&c6);
+        assert_relative_eq!(t6, Lms::from_channels(0.475112, 0.354532, 0.494897), epsilon=1e-4);
+        assert_relative_eq!(Xyz::from_color(&t6), c6, epsilon=1e-4);
+
+        let c7 = Xyz::from_channels(0.6, 0.4, 0.5);
+        let t7 = LmsSharp::from_color(&c7);
+        assert_relative_eq!(t7, Lms::from_channels(0.63682, 0.23625, 0.50612), epsilon=1e-4);
+        assert_relative_eq!(Xyz::from_color(&t7), c7, epsilon=1e-4);
+        assert_relative_eq!(Xyz::from_color(&Lms::<_, XyzScaling>::from_color(&c7)), c7);
+    }
+
+    #[test]
+    fn test_hunt_pointer_estevez() {
+        let c1 = Xyz::from_channels(0.6, 0.4, 0.5);
+        let t1 = LmsHuntPointerEstevez::from_color(&c1);
+        assert_relative_eq!(t1, Lms::from_channels(0.482770, 0.353800, 0.459200), epsilon=1e-6);
+        assert_relative_eq!(Xyz::from_color(&t1), c1, epsilon=1e-12);
+
+        let forward: Matrix3<f64> = HuntPointerEstevez::forward_transform();
+        let inverse: Matrix3<f64> = HuntPointerEstevez::inverse_transform();
+        assert_relative_eq!(inverse.as_slice()[0], 1.8502430, epsilon=1e-6);
+        assert_relative_eq!(forward * inverse, Matrix3::identity(), epsilon=1e-12);
     }
 
     #[test]
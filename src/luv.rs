@@ -135,7 +135,7 @@ impl<T> fmt::Display for Luv<T>
 }
 
 impl<T> Luv<T>
-    where T: FreeChannelScalar + fmt::Display
+    where T: FreeChannelScalar
 {
     pub fn from_xyz(from: &Xyz<T>, wp: &Xyz<T>) -> Self {
         let epsilon: T = num::cast(1e-8).unwrap();
@@ -185,7 +185,6 @@ impl<T> Luv<T>
                  (self.v() + num::cast::<_, T>(13.0).unwrap() * self.L() * v0 + epsilon) -
                  num::cast::<_, T>(5.0).unwrap());
 
-        println!("{} {} {} {}", a, b, c, d);
         let X;
         if a != c {
             X = (d - b) / (a - c);
@@ -0,0 +1,408 @@
+//! Named colors.
+//!
+//! The constants in this module are the 148 named colors of CSS Color Level 4, which include
+//! the 147 SVG 1.1 color keywords. The `x11` module holds the X11 `rgb.txt` set, where a few
+//! names such as `GRAY` and `GREEN` have different values.
+//!
+//! Names can be looked up case-insensitively with `from_name`, and `nearest` finds the named
+//! color that looks closest to any sRGB color, measured as the distance in `Lab`.
+
+use channel::{ChannelFormatCast, PosNormalBoundedChannel, PosNormalChannelScalar};
+use color_space::color_space::ColorToXyz;
+use color_space::presets::{NamedColorSpace, sRgb};
+use difference::ColorDifference;
+use lab::Lab;
+use rgb::Rgb;
+use white_point::NamedWhitePoint;
+use white_point::deg_2::D65;
+
+pub mod x11;
+
+const fn rgb(red: u8, green: u8, blue: u8) -> Rgb<u8> {
+    Rgb {
+        red: PosNormalBoundedChannel(red),
+        green: PosNormalBoundedChannel(green),
+        blue: PosNormalBoundedChannel(blue),
+    }
+}
+
+/// Look up a CSS named color, ignoring case.
+pub fn from_name(name: &str) -> Option<Rgb<u8>> {
+    find(COLORS, &name.to_ascii_lowercase())
+}
+
+/// Find the CSS named color closest to an sRGB color.
+pub fn nearest<T>(color: &Rgb<T>) -> (&'static str, Rgb<u8>)
+    where T: PosNormalChannelScalar + ChannelFormatCast<f64>
+{
+    nearest_in(COLORS, color)
+}
+
+/// Find the entry of a table of named sRGB colors closest to `color`.
+///
+/// Colors are compared by their CIE76 distance in `Lab` relative to D65. The first of several
+/// equally close entries is returned, so the table order decides between aliases such as
+/// `aqua` and `cyan`.
+pub fn nearest_in<T>(table: &[(&'static str, Rgb<u8>)], color: &Rgb<T>) -> (&'static str, Rgb<u8>)
+    where T: PosNormalChannelScalar + ChannelFormatCast<f64>
+{
+    let space = sRgb::get_color_space();
+    let white = D65::get_xyz();
+    let to_lab = |color: &Rgb<f64>| Lab::from_xyz(&space.color_to_xyz(color), &white);
+
+    let target = to_lab(&color.color_cast());
+    let mut best = table[0];
+    let mut best_distance = target.delta_e(&to_lab(&best.1.color_cast()));
+    for &(name, named) in &table[1..] {
+        let dist = target.delta_e(&to_lab(&named.color_cast()));
+        if dist < best_distance {
+            best = (name, named);
+            best_distance = dist;
+        }
+    }
+    best
+}
+
+fn find(table: &[(&'static str, Rgb<u8>)], name: &str) -> Option<Rgb<u8>> {
+    table.binary_search_by(|&(entry, _)| entry.cmp(name))
+        .ok()
+        .map(|index| table[index].1)
+}
+
+pub const ALICEBLUE: Rgb<u8> = rgb(240, 248, 255);
+pub const ANTIQUEWHITE: Rgb<u8> = rgb(250, 235, 215);
+pub const AQUA: Rgb<u8> = rgb(0, 255, 255);
+pub const AQUAMARINE: Rgb<u8> = rgb(127, 255, 212);
+pub const AZURE: Rgb<u8> = rgb(240, 255, 255);
+pub const BEIGE: Rgb<u8> = rgb(245, 245, 220);
+pub const BISQUE: Rgb<u8> = rgb(255, 228, 196);
+pub const BLACK: Rgb<u8> = rgb(0, 0, 0);
+pub const BLANCHEDALMOND: Rgb<u8> = rgb(255, 235, 205);
+pub const BLUE: Rgb<u8> = rgb(0, 0, 255);
+pub const BLUEVIOLET: Rgb<u8> = rgb(138, 43, 226);
+pub const BROWN: Rgb<u8> = rgb(165, 42, 42);
+pub const BURLYWOOD: Rgb<u8> = rgb(222, 184, 135);
+pub const CADETBLUE: Rgb<u8> = rgb(95, 158, 160);
+pub const CHARTREUSE: Rgb<u8> = rgb(127, 255, 0);
+pub const CHOCOLATE: Rgb<u8> = rgb(210, 105, 30);
+pub const CORAL: Rgb<u8> = rgb(255, 127, 80);
+pub const CORNFLOWERBLUE: Rgb<u8> = rgb(100, 149, 237);
+pub const CORNSILK: Rgb<u8> = rgb(255, 248, 220);
+pub const CRIMSON: Rgb<u8> = rgb(220, 20, 60);
+pub const CYAN: Rgb<u8> = rgb(0, 255, 255);
+pub const DARKBLUE: Rgb<u8> = rgb(0, 0, 139);
+pub const DARKCYAN: Rgb<u8> = rgb(0, 139, 139);
+pub const DARKGOLDENROD: Rgb<u8> = rgb(184, 134, 11);
+pub const DARKGRAY: Rgb<u8> = rgb(169, 169, 169);
+pub const DARKGREEN: Rgb<u8> = rgb(0, 100, 0);
+pub const DARKGREY: Rgb<u8> = rgb(169, 169, 169);
+pub const DARKKHAKI: Rgb<u8> = rgb(189, 183, 107);
+pub const DARKMAGENTA: Rgb<u8> = rgb(139, 0, 139);
+pub const DARKOLIVEGREEN: Rgb<u8> = rgb(85, 107, 47);
+pub const DARKORANGE: Rgb<u8> = rgb(255, 140, 0);
+pub const DARKORCHID: Rgb<u8> = rgb(153, 50, 204);
+pub const DARKRED: Rgb<u8> = rgb(139, 0, 0);
+pub const DARKSALMON: Rgb<u8> = rgb(233, 150, 122);
+pub const DARKSEAGREEN: Rgb<u8> = rgb(143, 188, 143);
+pub const DARKSLATEBLUE: Rgb<u8> = rgb(72, 61, 139);
+pub const DARKSLATEGRAY: Rgb<u8> = rgb(47, 79, 79);
+pub const DARKSLATEGREY: Rgb<u8> = rgb(47, 79, 79);
+pub const DARKTURQUOISE: Rgb<u8> = rgb(0, 206, 209);
+pub const DARKVIOLET: Rgb<u8> = rgb(148, 0, 211);
+pub const DEEPPINK: Rgb<u8> = rgb(255, 20, 147);
+pub const DEEPSKYBLUE: Rgb<u8> = rgb(0, 191, 255);
+pub const DIMGRAY: Rgb<u8> = rgb(105, 105, 105);
+pub const DIMGREY: Rgb<u8> = rgb(105, 105, 105);
+pub const DODGERBLUE: Rgb<u8> = rgb(30, 144, 255);
+pub const FIREBRICK: Rgb<u8> = rgb(178, 34, 34);
+pub const FLORALWHITE: Rgb<u8> = rgb(255, 250, 240);
+pub const FORESTGREEN: Rgb<u8> = rgb(34, 139, 34);
+pub const FUCHSIA: Rgb<u8> = rgb(255, 0, 255);
+pub const GAINSBORO: Rgb<u8> = rgb(220, 220, 220);
+pub const GHOSTWHITE: Rgb<u8> = rgb(248, 248, 255);
+pub const GOLD: Rgb<u8> = rgb(255, 215, 0);
+pub const GOLDENROD: Rgb<u8> = rgb(218, 165, 32);
+pub const GRAY: Rgb<u8> = rgb(128, 128, 128);
+pub const GREEN: Rgb<u8> = rgb(0, 128, 0);
+pub const GREENYELLOW: Rgb<u8> = rgb(173, 255, 47);
+pub const GREY: Rgb<u8> = rgb(128, 128, 128);
+pub const HONEYDEW: Rgb<u8> = rgb(240, 255, 240);
+pub const HOTPINK: Rgb<u8> = rgb(255, 105, 180);
+pub const INDIANRED: Rgb<u8> = rgb(205, 92, 92);
+pub const INDIGO: Rgb<u8> = rgb(75, 0, 130);
+pub const IVORY: Rgb<u8> = rgb(255, 255, 240);
+pub const KHAKI: Rgb<u8> = rgb(240, 230, 140);
+pub const LAVENDER: Rgb<u8> = rgb(230, 230, 250);
+pub const LAVENDERBLUSH: Rgb<u8> = rgb(255, 240, 245);
+pub const LAWNGREEN: Rgb<u8> = rgb(124, 252, 0);
+pub const LEMONCHIFFON: Rgb<u8> = rgb(255, 250, 205);
+pub const LIGHTBLUE: Rgb<u8> = rgb(173, 216, 230);
+pub const LIGHTCORAL: Rgb<u8> = rgb(240, 128, 128);
+pub const LIGHTCYAN: Rgb<u8> = rgb(224, 255, 255);
+pub const LIGHTGOLDENRODYELLOW: Rgb<u8> = rgb(250, 250, 210);
+pub const LIGHTGRAY: Rgb<u8> = rgb(211, 211, 211);
+pub const LIGHTGREEN: Rgb<u8> = rgb(144, 238, 144);
+pub const LIGHTGREY: Rgb<u8> = rgb(211, 211, 211);
+pub const LIGHTPINK: Rgb<u8> = rgb(255, 182, 193);
+pub const LIGHTSALMON: Rgb<u8> = rgb(255, 160, 122);
+pub const LIGHTSEAGREEN: Rgb<u8> = rgb(32, 178, 170);
+pub const LIGHTSKYBLUE: Rgb<u8> = rgb(135, 206, 250);
+pub const LIGHTSLATEGRAY: Rgb<u8> = rgb(119, 136, 153);
+pub const LIGHTSLATEGREY: Rgb<u8> = rgb(119, 136, 153);
+pub const LIGHTSTEELBLUE: Rgb<u8> = rgb(176, 196, 222);
+pub const LIGHTYELLOW: Rgb<u8> = rgb(255, 255, 224);
+pub const LIME: Rgb<u8> = rgb(0, 255, 0);
+pub const LIMEGREEN: Rgb<u8> = rgb(50, 205, 50);
+pub const LINEN: Rgb<u8> = rgb(250, 240, 230);
+pub const MAGENTA: Rgb<u8> = rgb(255, 0, 255);
+pub const MAROON: Rgb<u8> = rgb(128, 0, 0);
+pub const MEDIUMAQUAMARINE: Rgb<u8> = rgb(102, 205, 170);
+pub const MEDIUMBLUE: Rgb<u8> = rgb(0, 0, 205);
+pub const MEDIUMORCHID: Rgb<u8> = rgb(186, 85, 211);
+pub const MEDIUMPURPLE: Rgb<u8> = rgb(147, 112, 219);
+pub const MEDIUMSEAGREEN: Rgb<u8> = rgb(60, 179, 113);
+pub const MEDIUMSLATEBLUE: Rgb<u8> = rgb(123, 104, 238);
+pub const MEDIUMSPRINGGREEN: Rgb<u8> = rgb(0, 250, 154);
+pub const MEDIUMTURQUOISE: Rgb<u8> = rgb(72, 209, 204);
+pub const MEDIUMVIOLETRED: Rgb<u8> = rgb(199, 21, 133);
+pub const MIDNIGHTBLUE: Rgb<u8> = rgb(25, 25, 112);
+pub const MINTCREAM: Rgb<u8> = rgb(245, 255, 250);
+pub const MISTYROSE: Rgb<u8> = rgb(255, 228, 225);
+pub const MOCCASIN: Rgb<u8> = rgb(255, 228, 181);
+pub const NAVAJOWHITE: Rgb<u8> = rgb(255, 222, 173);
+pub const NAVY: Rgb<u8> = rgb(0, 0, 128);
+pub const OLDLACE: Rgb<u8> = rgb(253, 245, 230);
+pub const OLIVE: Rgb<u8> = rgb(128, 128, 0);
+pub const OLIVEDRAB: Rgb<u8> = rgb(107, 142, 35);
+pub const ORANGE: Rgb<u8> = rgb(255, 165, 0);
+pub const ORANGERED: Rgb<u8> = rgb(255, 69, 0);
+pub const ORCHID: Rgb<u8> = rgb(218, 112, 214);
+pub const PALEGOLDENROD: Rgb<u8> = rgb(238, 232, 170);
+pub const PALEGREEN: Rgb<u8> = rgb(152, 251, 152);
+pub const PALETURQUOISE: Rgb<u8> = rgb(175, 238, 238);
+pub const PALEVIOLETRED: Rgb<u8> = rgb(219, 112, 147);
+pub const PAPAYAWHIP: Rgb<u8> = rgb(255, 239, 213);
+pub const PEACHPUFF: Rgb<u8> = rgb(255, 218, 185);
+pub const PERU: Rgb<u8> = rgb(205, 133, 63);
+pub const PINK: Rgb<u8> = rgb(255, 192, 203);
+pub const PLUM: Rgb<u8> = rgb(221, 160, 221);
+pub const POWDERBLUE: Rgb<u8> = rgb(176, 224, 230);
+pub const PURPLE: Rgb<u8> = rgb(128, 0, 128);
+pub const REBECCAPURPLE: Rgb<u8> = rgb(102, 51, 153);
+pub const RED: Rgb<u8> = rgb(255, 0, 0);
+pub const ROSYBROWN: Rgb<u8> = rgb(188, 143, 143);
+pub const ROYALBLUE: Rgb<u8> = rgb(65, 105, 225);
+pub const SADDLEBROWN: Rgb<u8> = rgb(139, 69, 19);
+pub const SALMON: Rgb<u8> = rgb(250, 128, 114);
+pub const SANDYBROWN: Rgb<u8> = rgb(244, 164, 96);
+pub const SEAGREEN: Rgb<u8> = rgb(46, 139, 87);
+pub const SEASHELL: Rgb<u8> = rgb(255, 245, 238);
+pub const SIENNA: Rgb<u8> = rgb(160, 82, 45);
+pub const SILVER: Rgb<u8> = rgb(192, 192, 192);
+pub const SKYBLUE: Rgb<u8> = rgb(135, 206, 235);
+pub const SLATEBLUE: Rgb<u8> = rgb(106, 90, 205);
+pub const SLATEGRAY: Rgb<u8> = rgb(112, 128, 144);
+pub const SLATEGREY: Rgb<u8> = rgb(112, 128, 144);
+pub const SNOW: Rgb<u8> = rgb(255, 250, 250);
+pub const SPRINGGREEN: Rgb<u8> = rgb(0, 255, 127);
+pub const STEELBLUE: Rgb<u8> = rgb(70, 130, 180);
+pub const TAN: Rgb<u8> = rgb(210, 180, 140);
+pub const TEAL: Rgb<u8> = rgb(0, 128, 128);
+pub const THISTLE: Rgb<u8> = rgb(216, 191, 216);
+pub const TOMATO: Rgb<u8> = rgb(255, 99, 71);
+pub const TURQUOISE: Rgb<u8> = rgb(64, 224, 208);
+pub const VIOLET: Rgb<u8> = rgb(238, 130, 238);
+pub const WHEAT: Rgb<u8> = rgb(245, 222, 179);
+pub const WHITE: Rgb<u8> = rgb(255, 255, 255);
+pub const WHITESMOKE: Rgb<u8> = rgb(245, 245, 245);
+pub const YELLOW: Rgb<u8> = rgb(255, 255, 0);
+pub const YELLOWGREEN: Rgb<u8> = rgb(154, 205, 50);
+
+/// All CSS named colors with their lowercase names, sorted by name.
+pub static COLORS: &[(&str, Rgb<u8>)] = &[
+    ("aliceblue", ALICEBLUE),
+    ("antiquewhite", ANTIQUEWHITE),
+    ("aqua", AQUA),
+    ("aquamarine", AQUAMARINE),
+    ("azure", AZURE),
+    ("beige", BEIGE),
+    ("bisque", BISQUE),
+    ("black", BLACK),
+    ("blanchedalmond", BLANCHEDALMOND),
+    ("blue", BLUE),
+    ("blueviolet", BLUEVIOLET),
+    ("brown", BROWN),
+    ("burlywood", BURLYWOOD),
+    ("cadetblue", CADETBLUE),
+    ("chartreuse", CHARTREUSE),
+    ("chocolate", CHOCOLATE),
+    ("coral", CORAL),
+    ("cornflowerblue", CORNFLOWERBLUE),
+    ("cornsilk", CORNSILK),
+    ("crimson", CRIMSON),
+    ("cyan", CYAN),
+    ("darkblue", DARKBLUE),
+    ("darkcyan", DARKCYAN),
+    ("darkgoldenrod", DARKGOLDENROD),
+    ("darkgray", DARKGRAY),
+    ("darkgreen", DARKGREEN),
+    ("darkgrey", DARKGREY),
+    ("darkkhaki", DARKKHAKI),
+    ("darkmagenta", DARKMAGENTA),
+    ("darkolivegreen", DARKOLIVEGREEN),
+    ("darkorange", DARKORANGE),
+    ("darkorchid", DARKORCHID),
+    ("darkred", DARKRED),
+    ("darksalmon", DARKSALMON),
+    ("darkseagreen", DARKSEAGREEN),
+    ("darkslateblue", DARKSLATEBLUE),
+    ("darkslategray", DARKSLATEGRAY),
+    ("darkslategrey", DARKSLATEGREY),
+    ("darkturquoise", DARKTURQUOISE),
+    ("darkviolet", DARKVIOLET),
+    ("deeppink", DEEPPINK),
+    ("deepskyblue", DEEPSKYBLUE),
+    ("dimgray", DIMGRAY),
+    ("dimgrey", DIMGREY),
+    ("dodgerblue", DODGERBLUE),
+    ("firebrick", FIREBRICK),
+    ("floralwhite", FLORALWHITE),
+    ("forestgreen", FORESTGREEN),
+    ("fuchsia", FUCHSIA),
+    ("gainsboro", GAINSBORO),
+    ("ghostwhite", GHOSTWHITE),
+    ("gold", GOLD),
+    ("goldenrod", GOLDENROD),
+    ("gray", GRAY),
+    ("green", GREEN),
+    ("greenyellow", GREENYELLOW),
+    ("grey", GREY),
+    ("honeydew", HONEYDEW),
+    ("hotpink", HOTPINK),
+    ("indianred", INDIANRED),
+    ("indigo", INDIGO),
+    ("ivory", IVORY),
+    ("khaki", KHAKI),
+    ("lavender", LAVENDER),
+    ("lavenderblush", LAVENDERBLUSH),
+    ("lawngreen", LAWNGREEN),
+    ("lemonchiffon", LEMONCHIFFON),
+    ("lightblue", LIGHTBLUE),
+    ("lightcoral", LIGHTCORAL),
+    ("lightcyan", LIGHTCYAN),
+    ("lightgoldenrodyellow", LIGHTGOLDENRODYELLOW),
+    ("lightgray", LIGHTGRAY),
+    ("lightgreen", LIGHTGREEN),
+    ("lightgrey", LIGHTGREY),
+    ("lightpink", LIGHTPINK),
+    ("lightsalmon", LIGHTSALMON),
+    ("lightseagreen", LIGHTSEAGREEN),
+    ("lightskyblue", LIGHTSKYBLUE),
+    ("lightslategray", LIGHTSLATEGRAY),
+    ("lightslategrey", LIGHTSLATEGREY),
+    ("lightsteelblue", LIGHTSTEELBLUE),
+    ("lightyellow", LIGHTYELLOW),
+    ("lime", LIME),
+    ("limegreen", LIMEGREEN),
+    ("linen", LINEN),
+    ("magenta", MAGENTA),
+    ("maroon", MAROON),
+    ("mediumaquamarine", MEDIUMAQUAMARINE),
+    ("mediumblue", MEDIUMBLUE),
+    ("mediumorchid", MEDIUMORCHID),
+    ("mediumpurple", MEDIUMPURPLE),
+    ("mediumseagreen", MEDIUMSEAGREEN),
+    ("mediumslateblue", MEDIUMSLATEBLUE),
+    ("mediumspringgreen", MEDIUMSPRINGGREEN),
+    ("mediumturquoise", MEDIUMTURQUOISE),
+    ("mediumvioletred", MEDIUMVIOLETRED),
+    ("midnightblue", MIDNIGHTBLUE),
+    ("mintcream", MINTCREAM),
+    ("mistyrose", MISTYROSE),
+    ("moccasin", MOCCASIN),
+    ("navajowhite", NAVAJOWHITE),
+    ("navy", NAVY),
+    ("oldlace", OLDLACE),
+    ("olive", OLIVE),
+    ("olivedrab", OLIVEDRAB),
+    ("orange", ORANGE),
+    ("orangered", ORANGERED),
+    ("orchid", ORCHID),
+    ("palegoldenrod", PALEGOLDENROD),
+    ("palegreen", PALEGREEN),
+    ("paleturquoise", PALETURQUOISE),
+    ("palevioletred", PALEVIOLETRED),
+    ("papayawhip", PAPAYAWHIP),
+    ("peachpuff", PEACHPUFF),
+    ("peru", PERU),
+    ("pink", PINK),
+    ("plum", PLUM),
+    ("powderblue", POWDERBLUE),
+    ("purple", PURPLE),
+    ("rebeccapurple", REBECCAPURPLE),
+    ("red", RED),
+    ("rosybrown", ROSYBROWN),
+    ("royalblue", ROYALBLUE),
+    ("saddlebrown", SADDLEBROWN),
+    ("salmon", SALMON),
+    ("sandybrown", SANDYBROWN),
+    ("seagreen", SEAGREEN),
+    ("seashell", SEASHELL),
+    ("sienna", SIENNA),
+    ("silver", SILVER),
+    ("skyblue", SKYBLUE),
+    ("slateblue", SLATEBLUE),
+    ("slategray", SLATEGRAY),
+    ("slategrey", SLATEGREY),
+    ("snow", SNOW),
+    ("springgreen", SPRINGGREEN),
+    ("steelblue", STEELBLUE),
+    ("tan", TAN),
+    ("teal", TEAL),
+    ("thistle", THISTLE),
+    ("tomato", TOMATO),
+    ("turquoise", TURQUOISE),
+    ("violet", VIOLET),
+    ("wheat", WHEAT),
+    ("white", WHITE),
+    ("whitesmoke", WHITESMOKE),
+    ("yellow", YELLOW),
+    ("yellowgreen", YELLOWGREEN),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tables() {
+        assert_eq!(COLORS.len(), 148);
+        assert!(COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(REBECCAPURPLE, Rgb::from_channels(102, 51, 153));
+        assert_eq!(GRAY, GREY);
+        assert_eq!(GREEN, Rgb::from_channels(0, 128, 0));
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(from_name("cornflowerblue"), Some(CORNFLOWERBLUE));
+        assert_eq!(from_name("CornflowerBlue"), Some(CORNFLOWERBLUE));
+        assert_eq!(from_name("LIGHTGOLDENRODYELLOW"), Some(rgb(250, 250, 210)));
+        assert_eq!(from_name("cornflower blue"), None);
+        assert_eq!(from_name("navyblue"), None);
+        assert_eq!(from_name(""), None);
+    }
+
+    #[test]
+    fn test_nearest() {
+        assert_eq!(nearest(&TOMATO), ("tomato", TOMATO));
+        assert_eq!(nearest(&CYAN), ("aqua", AQUA));
+        assert_eq!(nearest(&Rgb::from_channels(1.0, 0.02, 0.01)).0, "red");
+        assert_eq!(nearest(&Rgb::from_channels(250u8, 128, 114)).0, "salmon");
+        assert_eq!(nearest(&Rgb::from_channels(0.5f32, 0.5, 0.52)).0, "gray");
+
+        // Closest in raw RGB is darkslategray (47, 79, 79), but in Lab it is teal.
+        let color = Rgb::from_channels(20u8, 100, 100);
+        assert_eq!(nearest(&color).0, "teal");
+    }
+}
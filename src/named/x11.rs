@@ -0,0 +1,1421 @@
+//! The X11 color names from `rgb.txt`.
+//!
+//! Names are stored lowercase without the spaces `rgb.txt` allows, so `ghost white` and
+//! `GhostWhite` are both `ghostwhite`. Where X11 and CSS disagree, the `web` and `x11`
+//! prefixed names select a definition explicitly.
+
+use channel::{ChannelFormatCast, PosNormalChannelScalar};
+use rgb::Rgb;
+use named::{find, nearest_in, rgb};
+
+/// Look up an X11 named color, ignoring case and spaces.
+pub fn from_name(name: &str) -> Option<Rgb<u8>> {
+    let key: String = name.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    find(COLORS, &key)
+}
+
+/// Find the X11 named color closest to an sRGB color.
+pub fn nearest<T>(color: &Rgb<T>) -> (&'static str, Rgb<u8>)
+    where T: PosNormalChannelScalar + ChannelFormatCast<f64>
+{
+    nearest_in(COLORS, color)
+}
+
+pub const ALICEBLUE: Rgb<u8> = rgb(240, 248, 255);
+pub const ANTIQUEWHITE: Rgb<u8> = rgb(250, 235, 215);
+pub const ANTIQUEWHITE1: Rgb<u8> = rgb(255, 239, 219);
+pub const ANTIQUEWHITE2: Rgb<u8> = rgb(238, 223, 204);
+pub const ANTIQUEWHITE3: Rgb<u8> = rgb(205, 192, 176);
+pub const ANTIQUEWHITE4: Rgb<u8> = rgb(139, 131, 120);
+pub const AQUA: Rgb<u8> = rgb(0, 255, 255);
+pub const AQUAMARINE: Rgb<u8> = rgb(127, 255, 212);
+pub const AQUAMARINE1: Rgb<u8> = rgb(127, 255, 212);
+pub const AQUAMARINE2: Rgb<u8> = rgb(118, 238, 198);
+pub const AQUAMARINE3: Rgb<u8> = rgb(102, 205, 170);
+pub const AQUAMARINE4: Rgb<u8> = rgb(69, 139, 116);
+pub const AZURE: Rgb<u8> = rgb(240, 255, 255);
+pub const AZURE1: Rgb<u8> = rgb(240, 255, 255);
+pub const AZURE2: Rgb<u8> = rgb(224, 238, 238);
+pub const AZURE3: Rgb<u8> = rgb(193, 205, 205);
+pub const AZURE4: Rgb<u8> = rgb(131, 139, 139);
+pub const BEIGE: Rgb<u8> = rgb(245, 245, 220);
+pub const BISQUE: Rgb<u8> = rgb(255, 228, 196);
+pub const BISQUE1: Rgb<u8> = rgb(255, 228, 196);
+pub const BISQUE2: Rgb<u8> = rgb(238, 213, 183);
+pub const BISQUE3: Rgb<u8> = rgb(205, 183, 158);
+pub const BISQUE4: Rgb<u8> = rgb(139, 125, 107);
+pub const BLACK: Rgb<u8> = rgb(0, 0, 0);
+pub const BLANCHEDALMOND: Rgb<u8> = rgb(255, 235, 205);
+pub const BLUE: Rgb<u8> = rgb(0, 0, 255);
+pub const BLUE1: Rgb<u8> = rgb(0, 0, 255);
+pub const BLUE2: Rgb<u8> = rgb(0, 0, 238);
+pub const BLUE3: Rgb<u8> = rgb(0, 0, 205);
+pub const BLUE4: Rgb<u8> = rgb(0, 0, 139);
+pub const BLUEVIOLET: Rgb<u8> = rgb(138, 43, 226);
+pub const BROWN: Rgb<u8> = rgb(165, 42, 42);
+pub const BROWN1: Rgb<u8> = rgb(255, 64, 64);
+pub const BROWN2: Rgb<u8> = rgb(238, 59, 59);
+pub const BROWN3: Rgb<u8> = rgb(205, 51, 51);
+pub const BROWN4: Rgb<u8> = rgb(139, 35, 35);
+pub const BURLYWOOD: Rgb<u8> = rgb(222, 184, 135);
+pub const BURLYWOOD1: Rgb<u8> = rgb(255, 211, 155);
+pub const BURLYWOOD2: Rgb<u8> = rgb(238, 197, 145);
+pub const BURLYWOOD3: Rgb<u8> = rgb(205, 170, 125);
+pub const BURLYWOOD4: Rgb<u8> = rgb(139, 115, 85);
+pub const CADETBLUE: Rgb<u8> = rgb(95, 158, 160);
+pub const CADETBLUE1: Rgb<u8> = rgb(152, 245, 255);
+pub const CADETBLUE2: Rgb<u8> = rgb(142, 229, 238);
+pub const CADETBLUE3: Rgb<u8> = rgb(122, 197, 205);
+pub const CADETBLUE4: Rgb<u8> = rgb(83, 134, 139);
+pub const CHARTREUSE: Rgb<u8> = rgb(127, 255, 0);
+pub const CHARTREUSE1: Rgb<u8> = rgb(127, 255, 0);
+pub const CHARTREUSE2: Rgb<u8> = rgb(118, 238, 0);
+pub const CHARTREUSE3: Rgb<u8> = rgb(102, 205, 0);
+pub const CHARTREUSE4: Rgb<u8> = rgb(69, 139, 0);
+pub const CHOCOLATE: Rgb<u8> = rgb(210, 105, 30);
+pub const CHOCOLATE1: Rgb<u8> = rgb(255, 127, 36);
+pub const CHOCOLATE2: Rgb<u8> = rgb(238, 118, 33);
+pub const CHOCOLATE3: Rgb<u8> = rgb(205, 102, 29);
+pub const CHOCOLATE4: Rgb<u8> = rgb(139, 69, 19);
+pub const CORAL: Rgb<u8> = rgb(255, 127, 80);
+pub const CORAL1: Rgb<u8> = rgb(255, 114, 86);
+pub const CORAL2: Rgb<u8> = rgb(238, 106, 80);
+pub const CORAL3: Rgb<u8> = rgb(205, 91, 69);
+pub const CORAL4: Rgb<u8> = rgb(139, 62, 47);
+pub const CORNFLOWERBLUE: Rgb<u8> = rgb(100, 149, 237);
+pub const CORNSILK: Rgb<u8> = rgb(255, 248, 220);
+pub const CORNSILK1: Rgb<u8> = rgb(255, 248, 220);
+pub const CORNSILK2: Rgb<u8> = rgb(238, 232, 205);
+pub const CORNSILK3: Rgb<u8> = rgb(205, 200, 177);
+pub const CORNSILK4: Rgb<u8> = rgb(139, 136, 120);
+pub const CRIMSON: Rgb<u8> = rgb(220, 20, 60);
+pub const CYAN: Rgb<u8> = rgb(0, 255, 255);
+pub const CYAN1: Rgb<u8> = rgb(0, 255, 255);
+pub const CYAN2: Rgb<u8> = rgb(0, 238, 238);
+pub const CYAN3: Rgb<u8> = rgb(0, 205, 205);
+pub const CYAN4: Rgb<u8> = rgb(0, 139, 139);
+pub const DARKBLUE: Rgb<u8> = rgb(0, 0, 139);
+pub const DARKCYAN: Rgb<u8> = rgb(0, 139, 139);
+pub const DARKGOLDENROD: Rgb<u8> = rgb(184, 134, 11);
+pub const DARKGOLDENROD1: Rgb<u8> = rgb(255, 185, 15);
+pub const DARKGOLDENROD2: Rgb<u8> = rgb(238, 173, 14);
+pub const DARKGOLDENROD3: Rgb<u8> = rgb(205, 149, 12);
+pub const DARKGOLDENROD4: Rgb<u8> = rgb(139, 101, 8);
+pub const DARKGRAY: Rgb<u8> = rgb(169, 169, 169);
+pub const DARKGREEN: Rgb<u8> = rgb(0, 100, 0);
+pub const DARKGREY: Rgb<u8> = rgb(169, 169, 169);
+pub const DARKKHAKI: Rgb<u8> = rgb(189, 183, 107);
+pub const DARKMAGENTA: Rgb<u8> = rgb(139, 0, 139);
+pub const DARKOLIVEGREEN: Rgb<u8> = rgb(85, 107, 47);
+pub const DARKOLIVEGREEN1: Rgb<u8> = rgb(202, 255, 112);
+pub const DARKOLIVEGREEN2: Rgb<u8> = rgb(188, 238, 104);
+pub const DARKOLIVEGREEN3: Rgb<u8> = rgb(162, 205, 90);
+pub const DARKOLIVEGREEN4: Rgb<u8> = rgb(110, 139, 61);
+pub const DARKORANGE: Rgb<u8> = rgb(255, 140, 0);
+pub const DARKORANGE1: Rgb<u8> = rgb(255, 127, 0);
+pub const DARKORANGE2: Rgb<u8> = rgb(238, 118, 0);
+pub const DARKORANGE3: Rgb<u8> = rgb(205, 102, 0);
+pub const DARKORANGE4: Rgb<u8> = rgb(139, 69, 0);
+pub const DARKORCHID: Rgb<u8> = rgb(153, 50, 204);
+pub const DARKORCHID1: Rgb<u8> = rgb(191, 62, 255);
+pub const DARKORCHID2: Rgb<u8> = rgb(178, 58, 238);
+pub const DARKORCHID3: Rgb<u8> = rgb(154, 50, 205);
+pub const DARKORCHID4: Rgb<u8> = rgb(104, 34, 139);
+pub const DARKRED: Rgb<u8> = rgb(139, 0, 0);
+pub const DARKSALMON: Rgb<u8> = rgb(233, 150, 122);
+pub const DARKSEAGREEN: Rgb<u8> = rgb(143, 188, 143);
+pub const DARKSEAGREEN1: Rgb<u8> = rgb(193, 255, 193);
+pub const DARKSEAGREEN2: Rgb<u8> = rgb(180, 238, 180);
+pub const DARKSEAGREEN3: Rgb<u8> = rgb(155, 205, 155);
+pub const DARKSEAGREEN4: Rgb<u8> = rgb(105, 139, 105);
+pub const DARKSLATEBLUE: Rgb<u8> = rgb(72, 61, 139);
+pub const DARKSLATEGRAY: Rgb<u8> = rgb(47, 79, 79);
+pub const DARKSLATEGRAY1: Rgb<u8> = rgb(151, 255, 255);
+pub const DARKSLATEGRAY2: Rgb<u8> = rgb(141, 238, 238);
+pub const DARKSLATEGRAY3: Rgb<u8> = rgb(121, 205, 205);
+pub const DARKSLATEGRAY4: Rgb<u8> = rgb(82, 139, 139);
+pub const DARKSLATEGREY: Rgb<u8> = rgb(47, 79, 79);
+pub const DARKTURQUOISE: Rgb<u8> = rgb(0, 206, 209);
+pub const DARKVIOLET: Rgb<u8> = rgb(148, 0, 211);
+pub const DEEPPINK: Rgb<u8> = rgb(255, 20, 147);
+pub const DEEPPINK1: Rgb<u8> = rgb(255, 20, 147);
+pub const DEEPPINK2: Rgb<u8> = rgb(238, 18, 137);
+pub const DEEPPINK3: Rgb<u8> = rgb(205, 16, 118);
+pub const DEEPPINK4: Rgb<u8> = rgb(139, 10, 80);
+pub const DEEPSKYBLUE: Rgb<u8> = rgb(0, 191, 255);
+pub const DEEPSKYBLUE1: Rgb<u8> = rgb(0, 191, 255);
+pub const DEEPSKYBLUE2: Rgb<u8> = rgb(0, 178, 238);
+pub const DEEPSKYBLUE3: Rgb<u8> = rgb(0, 154, 205);
+pub const DEEPSKYBLUE4: Rgb<u8> = rgb(0, 104, 139);
+pub const DIMGRAY: Rgb<u8> = rgb(105, 105, 105);
+pub const DIMGREY: Rgb<u8> = rgb(105, 105, 105);
+pub const DODGERBLUE: Rgb<u8> = rgb(30, 144, 255);
+pub const DODGERBLUE1: Rgb<u8> = rgb(30, 144, 255);
+pub const DODGERBLUE2: Rgb<u8> = rgb(28, 134, 238);
+pub const DODGERBLUE3: Rgb<u8> = rgb(24, 116, 205);
+pub const DODGERBLUE4: Rgb<u8> = rgb(16, 78, 139);
+pub const FIREBRICK: Rgb<u8> = rgb(178, 34, 34);
+pub const FIREBRICK1: Rgb<u8> = rgb(255, 48, 48);
+pub const FIREBRICK2: Rgb<u8> = rgb(238, 44, 44);
+pub const FIREBRICK3: Rgb<u8> = rgb(205, 38, 38);
+pub const FIREBRICK4: Rgb<u8> = rgb(139, 26, 26);
+pub const FLORALWHITE: Rgb<u8> = rgb(255, 250, 240);
+pub const FORESTGREEN: Rgb<u8> = rgb(34, 139, 34);
+pub const FUCHSIA: Rgb<u8> = rgb(255, 0, 255);
+pub const GAINSBORO: Rgb<u8> = rgb(220, 220, 220);
+pub const GHOSTWHITE: Rgb<u8> = rgb(248, 248, 255);
+pub const GOLD: Rgb<u8> = rgb(255, 215, 0);
+pub const GOLD1: Rgb<u8> = rgb(255, 215, 0);
+pub const GOLD2: Rgb<u8> = rgb(238, 201, 0);
+pub const GOLD3: Rgb<u8> = rgb(205, 173, 0);
+pub const GOLD4: Rgb<u8> = rgb(139, 117, 0);
+pub const GOLDENROD: Rgb<u8> = rgb(218, 165, 32);
+pub const GOLDENROD1: Rgb<u8> = rgb(255, 193, 37);
+pub const GOLDENROD2: Rgb<u8> = rgb(238, 180, 34);
+pub const GOLDENROD3: Rgb<u8> = rgb(205, 155, 29);
+pub const GOLDENROD4: Rgb<u8> = rgb(139, 105, 20);
+pub const GRAY: Rgb<u8> = rgb(190, 190, 190);
+pub const GRAY0: Rgb<u8> = rgb(0, 0, 0);
+pub const GRAY1: Rgb<u8> = rgb(3, 3, 3);
+pub const GRAY10: Rgb<u8> = rgb(26, 26, 26);
+pub const GRAY100: Rgb<u8> = rgb(255, 255, 255);
+pub const GRAY11: Rgb<u8> = rgb(28, 28, 28);
+pub const GRAY12: Rgb<u8> = rgb(31, 31, 31);
+pub const GRAY13: Rgb<u8> = rgb(33, 33, 33);
+pub const GRAY14: Rgb<u8> = rgb(36, 36, 36);
+pub const GRAY15: Rgb<u8> = rgb(38, 38, 38);
+pub const GRAY16: Rgb<u8> = rgb(41, 41, 41);
+pub const GRAY17: Rgb<u8> = rgb(43, 43, 43);
+pub const GRAY18: Rgb<u8> = rgb(46, 46, 46);
+pub const GRAY19: Rgb<u8> = rgb(48, 48, 48);
+pub const GRAY2: Rgb<u8> = rgb(5, 5, 5);
+pub const GRAY20: Rgb<u8> = rgb(51, 51, 51);
+pub const GRAY21: Rgb<u8> = rgb(54, 54, 54);
+pub const GRAY22: Rgb<u8> = rgb(56, 56, 56);
+pub const GRAY23: Rgb<u8> = rgb(59, 59, 59);
+pub const GRAY24: Rgb<u8> = rgb(61, 61, 61);
+pub const GRAY25: Rgb<u8> = rgb(64, 64, 64);
+pub const GRAY26: Rgb<u8> = rgb(66, 66, 66);
+pub const GRAY27: Rgb<u8> = rgb(69, 69, 69);
+pub const GRAY28: Rgb<u8> = rgb(71, 71, 71);
+pub const GRAY29: Rgb<u8> = rgb(74, 74, 74);
+pub const GRAY3: Rgb<u8> = rgb(8, 8, 8);
+pub const GRAY30: Rgb<u8> = rgb(77, 77, 77);
+pub const GRAY31: Rgb<u8> = rgb(79, 79, 79);
+pub const GRAY32: Rgb<u8> = rgb(82, 82, 82);
+pub const GRAY33: Rgb<u8> = rgb(84, 84, 84);
+pub const GRAY34: Rgb<u8> = rgb(87, 87, 87);
+pub const GRAY35: Rgb<u8> = rgb(89, 89, 89);
+pub const GRAY36: Rgb<u8> = rgb(92, 92, 92);
+pub const GRAY37: Rgb<u8> = rgb(94, 94, 94);
+pub const GRAY38: Rgb<u8> = rgb(97, 97, 97);
+pub const GRAY39: Rgb<u8> = rgb(99, 99, 99);
+pub const GRAY4: Rgb<u8> = rgb(10, 10, 10);
+pub const GRAY40: Rgb<u8> = rgb(102, 102, 102);
+pub const GRAY41: Rgb<u8> = rgb(105, 105, 105);
+pub const GRAY42: Rgb<u8> = rgb(107, 107, 107);
+pub const GRAY43: Rgb<u8> = rgb(110, 110, 110);
+pub const GRAY44: Rgb<u8> = rgb(112, 112, 112);
+pub const GRAY45: Rgb<u8> = rgb(115, 115, 115);
+pub const GRAY46: Rgb<u8> = rgb(117, 117, 117);
+pub const GRAY47: Rgb<u8> = rgb(120, 120, 120);
+pub const GRAY48: Rgb<u8> = rgb(122, 122, 122);
+pub const GRAY49: Rgb<u8> = rgb(125, 125, 125);
+pub const GRAY5: Rgb<u8> = rgb(13, 13, 13);
+pub const GRAY50: Rgb<u8> = rgb(127, 127, 127);
+pub const GRAY51: Rgb<u8> = rgb(130, 130, 130);
+pub const GRAY52: Rgb<u8> = rgb(133, 133, 133);
+pub const GRAY53: Rgb<u8> = rgb(135, 135, 135);
+pub const GRAY54: Rgb<u8> = rgb(138, 138, 138);
+pub const GRAY55: Rgb<u8> = rgb(140, 140, 140);
+pub const GRAY56: Rgb<u8> = rgb(143, 143, 143);
+pub const GRAY57: Rgb<u8> = rgb(145, 145, 145);
+pub const GRAY58: Rgb<u8> = rgb(148, 148, 148);
+pub const GRAY59: Rgb<u8> = rgb(150, 150, 150);
+pub const GRAY6: Rgb<u8> = rgb(15, 15, 15);
+pub const GRAY60: Rgb<u8> = rgb(153, 153, 153);
+pub const GRAY61: Rgb<u8> = rgb(156, 156, 156);
+pub const GRAY62: Rgb<u8> = rgb(158, 158, 158);
+pub const GRAY63: Rgb<u8> = rgb(161, 161, 161);
+pub const GRAY64: Rgb<u8> = rgb(163, 163, 163);
+pub const GRAY65: Rgb<u8> = rgb(166, 166, 166);
+pub const GRAY66: Rgb<u8> = rgb(168, 168, 168);
+pub const GRAY67: Rgb<u8> = rgb(171, 171, 171);
+pub const GRAY68: Rgb<u8> = rgb(173, 173, 173);
+pub const GRAY69: Rgb<u8> = rgb(176, 176, 176);
+pub const GRAY7: Rgb<u8> = rgb(18, 18, 18);
+pub const GRAY70: Rgb<u8> = rgb(179, 179, 179);
+pub const GRAY71: Rgb<u8> = rgb(181, 181, 181);
+pub const GRAY72: Rgb<u8> = rgb(184, 184, 184);
+pub const GRAY73: Rgb<u8> = rgb(186, 186, 186);
+pub const GRAY74: Rgb<u8> = rgb(189, 189, 189);
+pub const GRAY75: Rgb<u8> = rgb(191, 191, 191);
+pub const GRAY76: Rgb<u8> = rgb(194, 194, 194);
+pub const GRAY77: Rgb<u8> = rgb(196, 196, 196);
+pub const GRAY78: Rgb<u8> = rgb(199, 199, 199);
+pub const GRAY79: Rgb<u8> = rgb(201, 201, 201);
+pub const GRAY8: Rgb<u8> = rgb(20, 20, 20);
+pub const GRAY80: Rgb<u8> = rgb(204, 204, 204);
+pub const GRAY81: Rgb<u8> = rgb(207, 207, 207);
+pub const GRAY82: Rgb<u8> = rgb(209, 209, 209);
+pub const GRAY83: Rgb<u8> = rgb(212, 212, 212);
+pub const GRAY84: Rgb<u8> = rgb(214, 214, 214);
+pub const GRAY85: Rgb<u8> = rgb(217, 217, 217);
+pub const GRAY86: Rgb<u8> = rgb(219, 219, 219);
+pub const GRAY87: Rgb<u8> = rgb(222, 222, 222);
+pub const GRAY88: Rgb<u8> = rgb(224, 224, 224);
+pub const GRAY89: Rgb<u8> = rgb(227, 227, 227);
+pub const GRAY9: Rgb<u8> = rgb(23, 23, 23);
+pub const GRAY90: Rgb<u8> = rgb(229, 229, 229);
+pub const GRAY91: Rgb<u8> = rgb(232, 232, 232);
+pub const GRAY92: Rgb<u8> = rgb(235, 235, 235);
+pub const GRAY93: Rgb<u8> = rgb(237, 237, 237);
+pub const GRAY94: Rgb<u8> = rgb(240, 240, 240);
+pub const GRAY95: Rgb<u8> = rgb(242, 242, 242);
+pub const GRAY96: Rgb<u8> = rgb(245, 245, 245);
+pub const GRAY97: Rgb<u8> = rgb(247, 247, 247);
+pub const GRAY98: Rgb<u8> = rgb(250, 250, 250);
+pub const GRAY99: Rgb<u8> = rgb(252, 252, 252);
+pub const GREEN: Rgb<u8> = rgb(0, 255, 0);
+pub const GREEN1: Rgb<u8> = rgb(0, 255, 0);
+pub const GREEN2: Rgb<u8> = rgb(0, 238, 0);
+pub const GREEN3: Rgb<u8> = rgb(0, 205, 0);
+pub const GREEN4: Rgb<u8> = rgb(0, 139, 0);
+pub const GREENYELLOW: Rgb<u8> = rgb(173, 255, 47);
+pub const GREY: Rgb<u8> = rgb(190, 190, 190);
+pub const GREY0: Rgb<u8> = rgb(0, 0, 0);
+pub const GREY1: Rgb<u8> = rgb(3, 3, 3);
+pub const GREY10: Rgb<u8> = rgb(26, 26, 26);
+pub const GREY100: Rgb<u8> = rgb(255, 255, 255);
+pub const GREY11: Rgb<u8> = rgb(28, 28, 28);
+pub const GREY12: Rgb<u8> = rgb(31, 31, 31);
+pub const GREY13: Rgb<u8> = rgb(33, 33, 33);
+pub const GREY14: Rgb<u8> = rgb(36, 36, 36);
+pub const GREY15: Rgb<u8> = rgb(38, 38, 38);
+pub const GREY16: Rgb<u8> = rgb(41, 41, 41);
+pub const GREY17: Rgb<u8> = rgb(43, 43, 43);
+pub const GREY18: Rgb<u8> = rgb(46, 46, 46);
+pub const GREY19: Rgb<u8> = rgb(48, 48, 48);
+pub const GREY2: Rgb<u8> = rgb(5, 5, 5);
+pub const GREY20: Rgb<u8> = rgb(51, 51, 51);
+pub const GREY21: Rgb<u8> = rgb(54, 54, 54);
+pub const GREY22: Rgb<u8> = rgb(56, 56, 56);
+pub const GREY23: Rgb<u8> = rgb(59, 59, 59);
+pub const GREY24: Rgb<u8> = rgb(61, 61, 61);
+pub const GREY25: Rgb<u8> = rgb(64, 64, 64);
+pub const GREY26: Rgb<u8> = rgb(66, 66, 66);
+pub const GREY27: Rgb<u8> = rgb(69, 69, 69);
+pub const GREY28: Rgb<u8> = rgb(71, 71, 71);
+pub const GREY29: Rgb<u8> = rgb(74, 74, 74);
+pub const GREY3: Rgb<u8> = rgb(8, 8, 8);
+pub const GREY30: Rgb<u8> = rgb(77, 77, 77);
+pub const GREY31: Rgb<u8> = rgb(79, 79, 79);
+pub const GREY32: Rgb<u8> = rgb(82, 82, 82);
+pub const GREY33: Rgb<u8> = rgb(84, 84, 84);
+pub const GREY34: Rgb<u8> = rgb(87, 87, 87);
+pub const GREY35: Rgb<u8> = rgb(89, 89, 89);
+pub const GREY36: Rgb<u8> = rgb(92, 92, 92);
+pub const GREY37: Rgb<u8> = rgb(94, 94, 94);
+pub const GREY38: Rgb<u8> = rgb(97, 97, 97);
+pub const GREY39: Rgb<u8> = rgb(99, 99, 99);
+pub const GREY4: Rgb<u8> = rgb(10, 10, 10);
+pub const GREY40: Rgb<u8> = rgb(102, 102, 102);
+pub const GREY41: Rgb<u8> = rgb(105, 105, 105);
+pub const GREY42: Rgb<u8> = rgb(107, 107, 107);
+pub const GREY43: Rgb<u8> = rgb(110, 110, 110);
+pub const GREY44: Rgb<u8> = rgb(112, 112, 112);
+pub const GREY45: Rgb<u8> = rgb(115, 115, 115);
+pub const GREY46: Rgb<u8> = rgb(117, 117, 117);
+pub const GREY47: Rgb<u8> = rgb(120, 120, 120);
+pub const GREY48: Rgb<u8> = rgb(122, 122, 122);
+pub const GREY49: Rgb<u8> = rgb(125, 125, 125);
+pub const GREY5: Rgb<u8> = rgb(13, 13, 13);
+pub const GREY50: Rgb<u8> = rgb(127, 127, 127);
+pub const GREY51: Rgb<u8> = rgb(130, 130, 130);
+pub const GREY52: Rgb<u8> = rgb(133, 133, 133);
+pub const GREY53: Rgb<u8> = rgb(135, 135, 135);
+pub const GREY54: Rgb<u8> = rgb(138, 138, 138);
+pub const GREY55: Rgb<u8> = rgb(140, 140, 140);
+pub const GREY56: Rgb<u8> = rgb(143, 143, 143);
+pub const GREY57: Rgb<u8> = rgb(145, 145, 145);
+pub const GREY58: Rgb<u8> = rgb(148, 148, 148);
+pub const GREY59: Rgb<u8> = rgb(150, 150, 150);
+pub const GREY6: Rgb<u8> = rgb(15, 15, 15);
+pub const GREY60: Rgb<u8> = rgb(153, 153, 153);
+pub const GREY61: Rgb<u8> = rgb(156, 156, 156);
+pub const GREY62: Rgb<u8> = rgb(158, 158, 158);
+pub const GREY63: Rgb<u8> = rgb(161, 161, 161);
+pub const GREY64: Rgb<u8> = rgb(163, 163, 163);
+pub const GREY65: Rgb<u8> = rgb(166, 166, 166);
+pub const GREY66: Rgb<u8> = rgb(168, 168, 168);
+pub const GREY67: Rgb<u8> = rgb(171, 171, 171);
+pub const GREY68: Rgb<u8> = rgb(173, 173, 173);
+pub const GREY69: Rgb<u8> = rgb(176, 176, 176);
+pub const GREY7: Rgb<u8> = rgb(18, 18, 18);
+pub const GREY70: Rgb<u8> = rgb(179, 179, 179);
+pub const GREY71: Rgb<u8> = rgb(181, 181, 181);
+pub const GREY72: Rgb<u8> = rgb(184, 184, 184);
+pub const GREY73: Rgb<u8> = rgb(186, 186, 186);
+pub const GREY74: Rgb<u8> = rgb(189, 189, 189);
+pub const GREY75: Rgb<u8> = rgb(191, 191, 191);
+pub const GREY76: Rgb<u8> = rgb(194, 194, 194);
+pub const GREY77: Rgb<u8> = rgb(196, 196, 196);
+pub const GREY78: Rgb<u8> = rgb(199, 199, 199);
+pub const GREY79: Rgb<u8> = rgb(201, 201, 201);
+pub const GREY8: Rgb<u8> = rgb(20, 20, 20);
+pub const GREY80: Rgb<u8> = rgb(204, 204, 204);
+pub const GREY81: Rgb<u8> = rgb(207, 207, 207);
+pub const GREY82: Rgb<u8> = rgb(209, 209, 209);
+pub const GREY83: Rgb<u8> = rgb(212, 212, 212);
+pub const GREY84: Rgb<u8> = rgb(214, 214, 214);
+pub const GREY85: Rgb<u8> = rgb(217, 217, 217);
+pub const GREY86: Rgb<u8> = rgb(219, 219, 219);
+pub const GREY87: Rgb<u8> = rgb(222, 222, 222);
+pub const GREY88: Rgb<u8> = rgb(224, 224, 224);
+pub const GREY89: Rgb<u8> = rgb(227, 227, 227);
+pub const GREY9: Rgb<u8> = rgb(23, 23, 23);
+pub const GREY90: Rgb<u8> = rgb(229, 229, 229);
+pub const GREY91: Rgb<u8> = rgb(232, 232, 232);
+pub const GREY92: Rgb<u8> = rgb(235, 235, 235);
+pub const GREY93: Rgb<u8> = rgb(237, 237, 237);
+pub const GREY94: Rgb<u8> = rgb(240, 240, 240);
+pub const GREY95: Rgb<u8> = rgb(242, 242, 242);
+pub const GREY96: Rgb<u8> = rgb(245, 245, 245);
+pub const GREY97: Rgb<u8> = rgb(247, 247, 247);
+pub const GREY98: Rgb<u8> = rgb(250, 250, 250);
+pub const GREY99: Rgb<u8> = rgb(252, 252, 252);
+pub const HONEYDEW: Rgb<u8> = rgb(240, 255, 240);
+pub const HONEYDEW1: Rgb<u8> = rgb(240, 255, 240);
+pub const HONEYDEW2: Rgb<u8> = rgb(224, 238, 224);
+pub const HONEYDEW3: Rgb<u8> = rgb(193, 205, 193);
+pub const HONEYDEW4: Rgb<u8> = rgb(131, 139, 131);
+pub const HOTPINK: Rgb<u8> = rgb(255, 105, 180);
+pub const HOTPINK1: Rgb<u8> = rgb(255, 110, 180);
+pub const HOTPINK2: Rgb<u8> = rgb(238, 106, 167);
+pub const HOTPINK3: Rgb<u8> = rgb(205, 96, 144);
+pub const HOTPINK4: Rgb<u8> = rgb(139, 58, 98);
+pub const INDIANRED: Rgb<u8> = rgb(205, 92, 92);
+pub const INDIANRED1: Rgb<u8> = rgb(255, 106, 106);
+pub const INDIANRED2: Rgb<u8> = rgb(238, 99, 99);
+pub const INDIANRED3: Rgb<u8> = rgb(205, 85, 85);
+pub const INDIANRED4: Rgb<u8> = rgb(139, 58, 58);
+pub const INDIGO: Rgb<u8> = rgb(75, 0, 130);
+pub const IVORY: Rgb<u8> = rgb(255, 255, 240);
+pub const IVORY1: Rgb<u8> = rgb(255, 255, 240);
+pub const IVORY2: Rgb<u8> = rgb(238, 238, 224);
+pub const IVORY3: Rgb<u8> = rgb(205, 205, 193);
+pub const IVORY4: Rgb<u8> = rgb(139, 139, 131);
+pub const KHAKI: Rgb<u8> = rgb(240, 230, 140);
+pub const KHAKI1: Rgb<u8> = rgb(255, 246, 143);
+pub const KHAKI2: Rgb<u8> = rgb(238, 230, 133);
+pub const KHAKI3: Rgb<u8> = rgb(205, 198, 115);
+pub const KHAKI4: Rgb<u8> = rgb(139, 134, 78);
+pub const LAVENDER: Rgb<u8> = rgb(230, 230, 250);
+pub const LAVENDERBLUSH: Rgb<u8> = rgb(255, 240, 245);
+pub const LAVENDERBLUSH1: Rgb<u8> = rgb(255, 240, 245);
+pub const LAVENDERBLUSH2: Rgb<u8> = rgb(238, 224, 229);
+pub const LAVENDERBLUSH3: Rgb<u8> = rgb(205, 193, 197);
+pub const LAVENDERBLUSH4: Rgb<u8> = rgb(139, 131, 134);
+pub const LAWNGREEN: Rgb<u8> = rgb(124, 252, 0);
+pub const LEMONCHIFFON: Rgb<u8> = rgb(255, 250, 205);
+pub const LEMONCHIFFON1: Rgb<u8> = rgb(255, 250, 205);
+pub const LEMONCHIFFON2: Rgb<u8> = rgb(238, 233, 191);
+pub const LEMONCHIFFON3: Rgb<u8> = rgb(205, 201, 165);
+pub const LEMONCHIFFON4: Rgb<u8> = rgb(139, 137, 112);
+pub const LIGHTBLUE: Rgb<u8> = rgb(173, 216, 230);
+pub const LIGHTBLUE1: Rgb<u8> = rgb(191, 239, 255);
+pub const LIGHTBLUE2: Rgb<u8> = rgb(178, 223, 238);
+pub const LIGHTBLUE3: Rgb<u8> = rgb(154, 192, 205);
+pub const LIGHTBLUE4: Rgb<u8> = rgb(104, 131, 139);
+pub const LIGHTCORAL: Rgb<u8> = rgb(240, 128, 128);
+pub const LIGHTCYAN: Rgb<u8> = rgb(224, 255, 255);
+pub const LIGHTCYAN1: Rgb<u8> = rgb(224, 255, 255);
+pub const LIGHTCYAN2: Rgb<u8> = rgb(209, 238, 238);
+pub const LIGHTCYAN3: Rgb<u8> = rgb(180, 205, 205);
+pub const LIGHTCYAN4: Rgb<u8> = rgb(122, 139, 139);
+pub const LIGHTGOLDENROD: Rgb<u8> = rgb(238, 221, 130);
+pub const LIGHTGOLDENROD1: Rgb<u8> = rgb(255, 236, 139);
+pub const LIGHTGOLDENROD2: Rgb<u8> = rgb(238, 220, 130);
+pub const LIGHTGOLDENROD3: Rgb<u8> = rgb(205, 190, 112);
+pub const LIGHTGOLDENROD4: Rgb<u8> = rgb(139, 129, 76);
+pub const LIGHTGOLDENRODYELLOW: Rgb<u8> = rgb(250, 250, 210);
+pub const LIGHTGRAY: Rgb<u8> = rgb(211, 211, 211);
+pub const LIGHTGREEN: Rgb<u8> = rgb(144, 238, 144);
+pub const LIGHTGREY: Rgb<u8> = rgb(211, 211, 211);
+pub const LIGHTPINK: Rgb<u8> = rgb(255, 182, 193);
+pub const LIGHTPINK1: Rgb<u8> = rgb(255, 174, 185);
+pub const LIGHTPINK2: Rgb<u8> = rgb(238, 162, 173);
+pub const LIGHTPINK3: Rgb<u8> = rgb(205, 140, 149);
+pub const LIGHTPINK4: Rgb<u8> = rgb(139, 95, 101);
+pub const LIGHTSALMON: Rgb<u8> = rgb(255, 160, 122);
+pub const LIGHTSALMON1: Rgb<u8> = rgb(255, 160, 122);
+pub const LIGHTSALMON2: Rgb<u8> = rgb(238, 149, 114);
+pub const LIGHTSALMON3: Rgb<u8> = rgb(205, 129, 98);
+pub const LIGHTSALMON4: Rgb<u8> = rgb(139, 87, 66);
+pub const LIGHTSEAGREEN: Rgb<u8> = rgb(32, 178, 170);
+pub const LIGHTSKYBLUE: Rgb<u8> = rgb(135, 206, 250);
+pub const LIGHTSKYBLUE1: Rgb<u8> = rgb(176, 226, 255);
+pub const LIGHTSKYBLUE2: Rgb<u8> = rgb(164, 211, 238);
+pub const LIGHTSKYBLUE3: Rgb<u8> = rgb(141, 182, 205);
+pub const LIGHTSKYBLUE4: Rgb<u8> = rgb(96, 123, 139);
+pub const LIGHTSLATEBLUE: Rgb<u8> = rgb(132, 112, 255);
+pub const LIGHTSLATEGRAY: Rgb<u8> = rgb(119, 136, 153);
+pub const LIGHTSLATEGREY: Rgb<u8> = rgb(119, 136, 153);
+pub const LIGHTSTEELBLUE: Rgb<u8> = rgb(176, 196, 222);
+pub const LIGHTSTEELBLUE1: Rgb<u8> = rgb(202, 225, 255);
+pub const LIGHTSTEELBLUE2: Rgb<u8> = rgb(188, 210, 238);
+pub const LIGHTSTEELBLUE3: Rgb<u8> = rgb(162, 181, 205);
+pub const LIGHTSTEELBLUE4: Rgb<u8> = rgb(110, 123, 139);
+pub const LIGHTYELLOW: Rgb<u8> = rgb(255, 255, 224);
+pub const LIGHTYELLOW1: Rgb<u8> = rgb(255, 255, 224);
+pub const LIGHTYELLOW2: Rgb<u8> = rgb(238, 238, 209);
+pub const LIGHTYELLOW3: Rgb<u8> = rgb(205, 205, 180);
+pub const LIGHTYELLOW4: Rgb<u8> = rgb(139, 139, 122);
+pub const LIME: Rgb<u8> = rgb(0, 255, 0);
+pub const LIMEGREEN: Rgb<u8> = rgb(50, 205, 50);
+pub const LINEN: Rgb<u8> = rgb(250, 240, 230);
+pub const MAGENTA: Rgb<u8> = rgb(255, 0, 255);
+pub const MAGENTA1: Rgb<u8> = rgb(255, 0, 255);
+pub const MAGENTA2: Rgb<u8> = rgb(238, 0, 238);
+pub const MAGENTA3: Rgb<u8> = rgb(205, 0, 205);
+pub const MAGENTA4: Rgb<u8> = rgb(139, 0, 139);
+pub const MAROON: Rgb<u8> = rgb(176, 48, 96);
+pub const MAROON1: Rgb<u8> = rgb(255, 52, 179);
+pub const MAROON2: Rgb<u8> = rgb(238, 48, 167);
+pub const MAROON3: Rgb<u8> = rgb(205, 41, 144);
+pub const MAROON4: Rgb<u8> = rgb(139, 28, 98);
+pub const MEDIUMAQUAMARINE: Rgb<u8> = rgb(102, 205, 170);
+pub const MEDIUMBLUE: Rgb<u8> = rgb(0, 0, 205);
+pub const MEDIUMORCHID: Rgb<u8> = rgb(186, 85, 211);
+pub const MEDIUMORCHID1: Rgb<u8> = rgb(224, 102, 255);
+pub const MEDIUMORCHID2: Rgb<u8> = rgb(209, 95, 238);
+pub const MEDIUMORCHID3: Rgb<u8> = rgb(180, 82, 205);
+pub const MEDIUMORCHID4: Rgb<u8> = rgb(122, 55, 139);
+pub const MEDIUMPURPLE: Rgb<u8> = rgb(147, 112, 219);
+pub const MEDIUMPURPLE1: Rgb<u8> = rgb(171, 130, 255);
+pub const MEDIUMPURPLE2: Rgb<u8> = rgb(159, 121, 238);
+pub const MEDIUMPURPLE3: Rgb<u8> = rgb(137, 104, 205);
+pub const MEDIUMPURPLE4: Rgb<u8> = rgb(93, 71, 139);
+pub const MEDIUMSEAGREEN: Rgb<u8> = rgb(60, 179, 113);
+pub const MEDIUMSLATEBLUE: Rgb<u8> = rgb(123, 104, 238);
+pub const MEDIUMSPRINGGREEN: Rgb<u8> = rgb(0, 250, 154);
+pub const MEDIUMTURQUOISE: Rgb<u8> = rgb(72, 209, 204);
+pub const MEDIUMVIOLETRED: Rgb<u8> = rgb(199, 21, 133);
+pub const MIDNIGHTBLUE: Rgb<u8> = rgb(25, 25, 112);
+pub const MINTCREAM: Rgb<u8> = rgb(245, 255, 250);
+pub const MISTYROSE: Rgb<u8> = rgb(255, 228, 225);
+pub const MISTYROSE1: Rgb<u8> = rgb(255, 228, 225);
+pub const MISTYROSE2: Rgb<u8> = rgb(238, 213, 210);
+pub const MISTYROSE3: Rgb<u8> = rgb(205, 183, 181);
+pub const MISTYROSE4: Rgb<u8> = rgb(139, 125, 123);
+pub const MOCCASIN: Rgb<u8> = rgb(255, 228, 181);
+pub const NAVAJOWHITE: Rgb<u8> = rgb(255, 222, 173);
+pub const NAVAJOWHITE1: Rgb<u8> = rgb(255, 222, 173);
+pub const NAVAJOWHITE2: Rgb<u8> = rgb(238, 207, 161);
+pub const NAVAJOWHITE3: Rgb<u8> = rgb(205, 179, 139);
+pub const NAVAJOWHITE4: Rgb<u8> = rgb(139, 121, 94);
+pub const NAVY: Rgb<u8> = rgb(0, 0, 128);
+pub const NAVYBLUE: Rgb<u8> = rgb(0, 0, 128);
+pub const OLDLACE: Rgb<u8> = rgb(253, 245, 230);
+pub const OLIVE: Rgb<u8> = rgb(128, 128, 0);
+pub const OLIVEDRAB: Rgb<u8> = rgb(107, 142, 35);
+pub const OLIVEDRAB1: Rgb<u8> = rgb(192, 255, 62);
+pub const OLIVEDRAB2: Rgb<u8> = rgb(179, 238, 58);
+pub const OLIVEDRAB3: Rgb<u8> = rgb(154, 205, 50);
+pub const OLIVEDRAB4: Rgb<u8> = rgb(105, 139, 34);
+pub const ORANGE: Rgb<u8> = rgb(255, 165, 0);
+pub const ORANGE1: Rgb<u8> = rgb(255, 165, 0);
+pub const ORANGE2: Rgb<u8> = rgb(238, 154, 0);
+pub const ORANGE3: Rgb<u8> = rgb(205, 133, 0);
+pub const ORANGE4: Rgb<u8> = rgb(139, 90, 0);
+pub const ORANGERED: Rgb<u8> = rgb(255, 69, 0);
+pub const ORANGERED1: Rgb<u8> = rgb(255, 69, 0);
+pub const ORANGERED2: Rgb<u8> = rgb(238, 64, 0);
+pub const ORANGERED3: Rgb<u8> = rgb(205, 55, 0);
+pub const ORANGERED4: Rgb<u8> = rgb(139, 37, 0);
+pub const ORCHID: Rgb<u8> = rgb(218, 112, 214);
+pub const ORCHID1: Rgb<u8> = rgb(255, 131, 250);
+pub const ORCHID2: Rgb<u8> = rgb(238, 122, 233);
+pub const ORCHID3: Rgb<u8> = rgb(205, 105, 201);
+pub const ORCHID4: Rgb<u8> = rgb(139, 71, 137);
+pub const PALEGOLDENROD: Rgb<u8> = rgb(238, 232, 170);
+pub const PALEGREEN: Rgb<u8> = rgb(152, 251, 152);
+pub const PALEGREEN1: Rgb<u8> = rgb(154, 255, 154);
+pub const PALEGREEN2: Rgb<u8> = rgb(144, 238, 144);
+pub const PALEGREEN3: Rgb<u8> = rgb(124, 205, 124);
+pub const PALEGREEN4: Rgb<u8> = rgb(84, 139, 84);
+pub const PALETURQUOISE: Rgb<u8> = rgb(175, 238, 238);
+pub const PALETURQUOISE1: Rgb<u8> = rgb(187, 255, 255);
+pub const PALETURQUOISE2: Rgb<u8> = rgb(174, 238, 238);
+pub const PALETURQUOISE3: Rgb<u8> = rgb(150, 205, 205);
+pub const PALETURQUOISE4: Rgb<u8> = rgb(102, 139, 139);
+pub const PALEVIOLETRED: Rgb<u8> = rgb(219, 112, 147);
+pub const PALEVIOLETRED1: Rgb<u8> = rgb(255, 130, 171);
+pub const PALEVIOLETRED2: Rgb<u8> = rgb(238, 121, 159);
+pub const PALEVIOLETRED3: Rgb<u8> = rgb(205, 104, 137);
+pub const PALEVIOLETRED4: Rgb<u8> = rgb(139, 71, 93);
+pub const PAPAYAWHIP: Rgb<u8> = rgb(255, 239, 213);
+pub const PEACHPUFF: Rgb<u8> = rgb(255, 218, 185);
+pub const PEACHPUFF1: Rgb<u8> = rgb(255, 218, 185);
+pub const PEACHPUFF2: Rgb<u8> = rgb(238, 203, 173);
+pub const PEACHPUFF3: Rgb<u8> = rgb(205, 175, 149);
+pub const PEACHPUFF4: Rgb<u8> = rgb(139, 119, 101);
+pub const PERU: Rgb<u8> = rgb(205, 133, 63);
+pub const PINK: Rgb<u8> = rgb(255, 192, 203);
+pub const PINK1: Rgb<u8> = rgb(255, 181, 197);
+pub const PINK2: Rgb<u8> = rgb(238, 169, 184);
+pub const PINK3: Rgb<u8> = rgb(205, 145, 158);
+pub const PINK4: Rgb<u8> = rgb(139, 99, 108);
+pub const PLUM: Rgb<u8> = rgb(221, 160, 221);
+pub const PLUM1: Rgb<u8> = rgb(255, 187, 255);
+pub const PLUM2: Rgb<u8> = rgb(238, 174, 238);
+pub const PLUM3: Rgb<u8> = rgb(205, 150, 205);
+pub const PLUM4: Rgb<u8> = rgb(139, 102, 139);
+pub const POWDERBLUE: Rgb<u8> = rgb(176, 224, 230);
+pub const PURPLE: Rgb<u8> = rgb(160, 32, 240);
+pub const PURPLE1: Rgb<u8> = rgb(155, 48, 255);
+pub const PURPLE2: Rgb<u8> = rgb(145, 44, 238);
+pub const PURPLE3: Rgb<u8> = rgb(125, 38, 205);
+pub const PURPLE4: Rgb<u8> = rgb(85, 26, 139);
+pub const REBECCAPURPLE: Rgb<u8> = rgb(102, 51, 153);
+pub const RED: Rgb<u8> = rgb(255, 0, 0);
+pub const RED1: Rgb<u8> = rgb(255, 0, 0);
+pub const RED2: Rgb<u8> = rgb(238, 0, 0);
+pub const RED3: Rgb<u8> = rgb(205, 0, 0);
+pub const RED4: Rgb<u8> = rgb(139, 0, 0);
+pub const ROSYBROWN: Rgb<u8> = rgb(188, 143, 143);
+pub const ROSYBROWN1: Rgb<u8> = rgb(255, 193, 193);
+pub const ROSYBROWN2: Rgb<u8> = rgb(238, 180, 180);
+pub const ROSYBROWN3: Rgb<u8> = rgb(205, 155, 155);
+pub const ROSYBROWN4: Rgb<u8> = rgb(139, 105, 105);
+pub const ROYALBLUE: Rgb<u8> = rgb(65, 105, 225);
+pub const ROYALBLUE1: Rgb<u8> = rgb(72, 118, 255);
+pub const ROYALBLUE2: Rgb<u8> = rgb(67, 110, 238);
+pub const ROYALBLUE3: Rgb<u8> = rgb(58, 95, 205);
+pub const ROYALBLUE4: Rgb<u8> = rgb(39, 64, 139);
+pub const SADDLEBROWN: Rgb<u8> = rgb(139, 69, 19);
+pub const SALMON: Rgb<u8> = rgb(250, 128, 114);
+pub const SALMON1: Rgb<u8> = rgb(255, 140, 105);
+pub const SALMON2: Rgb<u8> = rgb(238, 130, 98);
+pub const SALMON3: Rgb<u8> = rgb(205, 112, 84);
+pub const SALMON4: Rgb<u8> = rgb(139, 76, 57);
+pub const SANDYBROWN: Rgb<u8> = rgb(244, 164, 96);
+pub const SEAGREEN: Rgb<u8> = rgb(46, 139, 87);
+pub const SEAGREEN1: Rgb<u8> = rgb(84, 255, 159);
+pub const SEAGREEN2: Rgb<u8> = rgb(78, 238, 148);
+pub const SEAGREEN3: Rgb<u8> = rgb(67, 205, 128);
+pub const SEAGREEN4: Rgb<u8> = rgb(46, 139, 87);
+pub const SEASHELL: Rgb<u8> = rgb(255, 245, 238);
+pub const SEASHELL1: Rgb<u8> = rgb(255, 245, 238);
+pub const SEASHELL2: Rgb<u8> = rgb(238, 229, 222);
+pub const SEASHELL3: Rgb<u8> = rgb(205, 197, 191);
+pub const SEASHELL4: Rgb<u8> = rgb(139, 134, 130);
+pub const SIENNA: Rgb<u8> = rgb(160, 82, 45);
+pub const SIENNA1: Rgb<u8> = rgb(255, 130, 71);
+pub const SIENNA2: Rgb<u8> = rgb(238, 121, 66);
+pub const SIENNA3: Rgb<u8> = rgb(205, 104, 57);
+pub const SIENNA4: Rgb<u8> = rgb(139, 71, 38);
+pub const SILVER: Rgb<u8> = rgb(192, 192, 192);
+pub const SKYBLUE: Rgb<u8> = rgb(135, 206, 235);
+pub const SKYBLUE1: Rgb<u8> = rgb(135, 206, 255);
+pub const SKYBLUE2: Rgb<u8> = rgb(126, 192, 238);
+pub const SKYBLUE3: Rgb<u8> = rgb(108, 166, 205);
+pub const SKYBLUE4: Rgb<u8> = rgb(74, 112, 139);
+pub const SLATEBLUE: Rgb<u8> = rgb(106, 90, 205);
+pub const SLATEBLUE1: Rgb<u8> = rgb(131, 111, 255);
+pub const SLATEBLUE2: Rgb<u8> = rgb(122, 103, 238);
+pub const SLATEBLUE3: Rgb<u8> = rgb(105, 89, 205);
+pub const SLATEBLUE4: Rgb<u8> = rgb(71, 60, 139);
+pub const SLATEGRAY: Rgb<u8> = rgb(112, 128, 144);
+pub const SLATEGRAY1: Rgb<u8> = rgb(198, 226, 255);
+pub const SLATEGRAY2: Rgb<u8> = rgb(185, 211, 238);
+pub const SLATEGRAY3: Rgb<u8> = rgb(159, 182, 205);
+pub const SLATEGRAY4: Rgb<u8> = rgb(108, 123, 139);
+pub const SLATEGREY: Rgb<u8> = rgb(112, 128, 144);
+pub const SNOW: Rgb<u8> = rgb(255, 250, 250);
+pub const SNOW1: Rgb<u8> = rgb(255, 250, 250);
+pub const SNOW2: Rgb<u8> = rgb(238, 233, 233);
+pub const SNOW3: Rgb<u8> = rgb(205, 201, 201);
+pub const SNOW4: Rgb<u8> = rgb(139, 137, 137);
+pub const SPRINGGREEN: Rgb<u8> = rgb(0, 255, 127);
+pub const SPRINGGREEN1: Rgb<u8> = rgb(0, 255, 127);
+pub const SPRINGGREEN2: Rgb<u8> = rgb(0, 238, 118);
+pub const SPRINGGREEN3: Rgb<u8> = rgb(0, 205, 102);
+pub const SPRINGGREEN4: Rgb<u8> = rgb(0, 139, 69);
+pub const STEELBLUE: Rgb<u8> = rgb(70, 130, 180);
+pub const STEELBLUE1: Rgb<u8> = rgb(99, 184, 255);
+pub const STEELBLUE2: Rgb<u8> = rgb(92, 172, 238);
+pub const STEELBLUE3: Rgb<u8> = rgb(79, 148, 205);
+pub const STEELBLUE4: Rgb<u8> = rgb(54, 100, 139);
+pub const TAN: Rgb<u8> = rgb(210, 180, 140);
+pub const TAN1: Rgb<u8> = rgb(255, 165, 79);
+pub const TAN2: Rgb<u8> = rgb(238, 154, 73);
+pub const TAN3: Rgb<u8> = rgb(205, 133, 63);
+pub const TAN4: Rgb<u8> = rgb(139, 90, 43);
+pub const TEAL: Rgb<u8> = rgb(0, 128, 128);
+pub const THISTLE: Rgb<u8> = rgb(216, 191, 216);
+pub const THISTLE1: Rgb<u8> = rgb(255, 225, 255);
+pub const THISTLE2: Rgb<u8> = rgb(238, 210, 238);
+pub const THISTLE3: Rgb<u8> = rgb(205, 181, 205);
+pub const THISTLE4: Rgb<u8> = rgb(139, 123, 139);
+pub const TOMATO: Rgb<u8> = rgb(255, 99, 71);
+pub const TOMATO1: Rgb<u8> = rgb(255, 99, 71);
+pub const TOMATO2: Rgb<u8> = rgb(238, 92, 66);
+pub const TOMATO3: Rgb<u8> = rgb(205, 79, 57);
+pub const TOMATO4: Rgb<u8> = rgb(139, 54, 38);
+pub const TURQUOISE: Rgb<u8> = rgb(64, 224, 208);
+pub const TURQUOISE1: Rgb<u8> = rgb(0, 245, 255);
+pub const TURQUOISE2: Rgb<u8> = rgb(0, 229, 238);
+pub const TURQUOISE3: Rgb<u8> = rgb(0, 197, 205);
+pub const TURQUOISE4: Rgb<u8> = rgb(0, 134, 139);
+pub const VIOLET: Rgb<u8> = rgb(238, 130, 238);
+pub const VIOLETRED: Rgb<u8> = rgb(208, 32, 144);
+pub const VIOLETRED1: Rgb<u8> = rgb(255, 62, 150);
+pub const VIOLETRED2: Rgb<u8> = rgb(238, 58, 140);
+pub const VIOLETRED3: Rgb<u8> = rgb(205, 50, 120);
+pub const VIOLETRED4: Rgb<u8> = rgb(139, 34, 82);
+pub const WEBGRAY: Rgb<u8> = rgb(128, 128, 128);
+pub const WEBGREEN: Rgb<u8> = rgb(0, 128, 0);
+pub const WEBGREY: Rgb<u8> = rgb(128, 128, 128);
+pub const WEBMAROON: Rgb<u8> = rgb(128, 0, 0);
+pub const WEBPURPLE: Rgb<u8> = rgb(128, 0, 128);
+pub const WHEAT: Rgb<u8> = rgb(245, 222, 179);
+pub const WHEAT1: Rgb<u8> = rgb(255, 231, 186);
+pub const WHEAT2: Rgb<u8> = rgb(238, 216, 174);
+pub const WHEAT3: Rgb<u8> = rgb(205, 186, 150);
+pub const WHEAT4: Rgb<u8> = rgb(139, 126, 102);
+pub const WHITE: Rgb<u8> = rgb(255, 255, 255);
+pub const WHITESMOKE: Rgb<u8> = rgb(245, 245, 245);
+pub const X11GRAY: Rgb<u8> = rgb(190, 190, 190);
+pub const X11GREEN: Rgb<u8> = rgb(0, 255, 0);
+pub const X11GREY: Rgb<u8> = rgb(190, 190, 190);
+pub const X11MAROON: Rgb<u8> = rgb(176, 48, 96);
+pub const X11PURPLE: Rgb<u8> = rgb(160, 32, 240);
+pub const YELLOW: Rgb<u8> = rgb(255, 255, 0);
+pub const YELLOW1: Rgb<u8> = rgb(255, 255, 0);
+pub const YELLOW2: Rgb<u8> = rgb(238, 238, 0);
+pub const YELLOW3: Rgb<u8> = rgb(205, 205, 0);
+pub const YELLOW4: Rgb<u8> = rgb(139, 139, 0);
+pub const YELLOWGREEN: Rgb<u8> = rgb(154, 205, 50);
+
+/// All X11 named colors with their normalized names, sorted by name.
+pub static COLORS: &[(&str, Rgb<u8>)] = &[
+    ("aliceblue", ALICEBLUE),
+    ("antiquewhite", ANTIQUEWHITE),
+    ("antiquewhite1", ANTIQUEWHITE1),
+    ("antiquewhite2", ANTIQUEWHITE2),
+    ("antiquewhite3", ANTIQUEWHITE3),
+    ("antiquewhite4", ANTIQUEWHITE4),
+    ("aqua", AQUA),
+    ("aquamarine", AQUAMARINE),
+    ("aquamarine1", AQUAMARINE1),
+    ("aquamarine2", AQUAMARINE2),
+    ("aquamarine3", AQUAMARINE3),
+    ("aquamarine4", AQUAMARINE4),
+    ("azure", AZURE),
+    ("azure1", AZURE1),
+    ("azure2", AZURE2),
+    ("azure3", AZURE3),
+    ("azure4", AZURE4),
+    ("beige", BEIGE),
+    ("bisque", BISQUE),
+    ("bisque1", BISQUE1),
+    ("bisque2", BISQUE2),
+    ("bisque3", BISQUE3),
+    ("bisque4", BISQUE4),
+    ("black", BLACK),
+    ("blanchedalmond", BLANCHEDALMOND),
+    ("blue", BLUE),
+    ("blue1", BLUE1),
+    ("blue2", BLUE2),
+    ("blue3", BLUE3),
+    ("blue4", BLUE4),
+    ("blueviolet", BLUEVIOLET),
+    ("brown", BROWN),
+    ("brown1", BROWN1),
+    ("brown2", BROWN2),
+    ("brown3", BROWN3),
+    ("brown4", BROWN4),
+    ("burlywood", BURLYWOOD),
+    ("burlywood1", BURLYWOOD1),
+    ("burlywood2", BURLYWOOD2),
+    ("burlywood3", BURLYWOOD3),
+    ("burlywood4", BURLYWOOD4),
+    ("cadetblue", CADETBLUE),
+    ("cadetblue1", CADETBLUE1),
+    ("cadetblue2", CADETBLUE2),
+    ("cadetblue3", CADETBLUE3),
+    ("cadetblue4", CADETBLUE4),
+    ("chartreuse", CHARTREUSE),
+    ("chartreuse1", CHARTREUSE1),
+    ("chartreuse2", CHARTREUSE2),
+    ("chartreuse3", CHARTREUSE3),
+    ("chartreuse4", CHARTREUSE4),
+    ("chocolate", CHOCOLATE),
+    ("chocolate1", CHOCOLATE1),
+    ("chocolate2", CHOCOLATE2),
+    ("chocolate3", CHOCOLATE3),
+    ("chocolate4", CHOCOLATE4),
+    ("coral", CORAL),
+    ("coral1", CORAL1),
+    ("coral2", CORAL2),
+    ("coral3", CORAL3),
+    ("coral4", CORAL4),
+    ("cornflowerblue", CORNFLOWERBLUE),
+    ("cornsilk", CORNSILK),
+    ("cornsilk1", CORNSILK1),
+    ("cornsilk2", CORNSILK2),
+    ("cornsilk3", CORNSILK3),
+    ("cornsilk4", CORNSILK4),
+    ("crimson", CRIMSON),
+    ("cyan", CYAN),
+    ("cyan1", CYAN1),
+    ("cyan2", CYAN2),
+    ("cyan3", CYAN3),
+    ("cyan4", CYAN4),
+    ("darkblue", DARKBLUE),
+    ("darkcyan", DARKCYAN),
+    ("darkgoldenrod", DARKGOLDENROD),
+    ("darkgoldenrod1", DARKGOLDENROD1),
+    ("darkgoldenrod2", DARKGOLDENROD2),
+    ("darkgoldenrod3", DARKGOLDENROD3),
+    ("darkgoldenrod4", DARKGOLDENROD4),
+    ("darkgray", DARKGRAY),
+    ("darkgreen", DARKGREEN),
+    ("darkgrey", DARKGREY),
+    ("darkkhaki", DARKKHAKI),
+    ("darkmagenta", DARKMAGENTA),
+    ("darkolivegreen", DARKOLIVEGREEN),
+    ("darkolivegreen1", DARKOLIVEGREEN1),
+    ("darkolivegreen2", DARKOLIVEGREEN2),
+    ("darkolivegreen3", DARKOLIVEGREEN3),
+    ("darkolivegreen4", DARKOLIVEGREEN4),
+    ("darkorange", DARKORANGE),
+    ("darkorange1", DARKORANGE1),
+    ("darkorange2", DARKORANGE2),
+    ("darkorange3", DARKORANGE3),
+    ("darkorange4", DARKORANGE4),
+    ("darkorchid", DARKORCHID),
+    ("darkorchid1", DARKORCHID1),
+    ("darkorchid2", DARKORCHID2),
+    ("darkorchid3", DARKORCHID3),
+    ("darkorchid4", DARKORCHID4),
+    ("darkred", DARKRED),
+    ("darksalmon", DARKSALMON),
+    ("darkseagreen", DARKSEAGREEN),
+    ("darkseagreen1", DARKSEAGREEN1),
+    ("darkseagreen2", DARKSEAGREEN2),
+    ("darkseagreen3", DARKSEAGREEN3),
+    ("darkseagreen4", DARKSEAGREEN4),
+    ("darkslateblue", DARKSLATEBLUE),
+    ("darkslategray", DARKSLATEGRAY),
+    ("darkslategray1", DARKSLATEGRAY1),
+    ("darkslategray2", DARKSLATEGRAY2),
+    ("darkslategray3", DARKSLATEGRAY3),
+    ("darkslategray4", DARKSLATEGRAY4),
+    ("darkslategrey", DARKSLATEGREY),
+    ("darkturquoise", DARKTURQUOISE),
+    ("darkviolet", DARKVIOLET),
+    ("deeppink", DEEPPINK),
+    ("deeppink1", DEEPPINK1),
+    ("deeppink2", DEEPPINK2),
+    ("deeppink3", DEEPPINK3),
+    ("deeppink4", DEEPPINK4),
+    ("deepskyblue", DEEPSKYBLUE),
+    ("deepskyblue1", DEEPSKYBLUE1),
+    ("deepskyblue2", DEEPSKYBLUE2),
+    ("deepskyblue3", DEEPSKYBLUE3),
+    ("deepskyblue4", DEEPSKYBLUE4),
+    ("dimgray", DIMGRAY),
+    ("dimgrey", DIMGREY),
+    ("dodgerblue", DODGERBLUE),
+    ("dodgerblue1", DODGERBLUE1),
+    ("dodgerblue2", DODGERBLUE2),
+    ("dodgerblue3", DODGERBLUE3),
+    ("dodgerblue4", DODGERBLUE4),
+    ("firebrick", FIREBRICK),
+    ("firebrick1", FIREBRICK1),
+    ("firebrick2", FIREBRICK2),
+    ("firebrick3", FIREBRICK3),
+    ("firebrick4", FIREBRICK4),
+    ("floralwhite", FLORALWHITE),
+    ("forestgreen", FORESTGREEN),
+    ("fuchsia", FUCHSIA),
+    ("gainsboro", GAINSBORO),
+    ("ghostwhite", GHOSTWHITE),
+    ("gold", GOLD),
+    ("gold1", GOLD1),
+    ("gold2", GOLD2),
+    ("gold3", GOLD3),
+    ("gold4", GOLD4),
+    ("goldenrod", GOLDENROD),
+    ("goldenrod1", GOLDENROD1),
+    ("goldenrod2", GOLDENROD2),
+    ("goldenrod3", GOLDENROD3),
+    ("goldenrod4", GOLDENROD4),
+    ("gray", GRAY),
+    ("gray0", GRAY0),
+    ("gray1", GRAY1),
+    ("gray10", GRAY10),
+    ("gray100", GRAY100),
+    ("gray11", GRAY11),
+    ("gray12", GRAY12),
+    ("gray13", GRAY13),
+    ("gray14", GRAY14),
+    ("gray15", GRAY15),
+    ("gray16", GRAY16),
+    ("gray17", GRAY17),
+    ("gray18", GRAY18),
+    ("gray19", GRAY19),
+    ("gray2", GRAY2),
+    ("gray20", GRAY20),
+    ("gray21", GRAY21),
+    ("gray22", GRAY22),
+    ("gray23", GRAY23),
+    ("gray24", GRAY24),
+    ("gray25", GRAY25),
+    ("gray26", GRAY26),
+    ("gray27", GRAY27),
+    ("gray28", GRAY28),
+    ("gray29", GRAY29),
+    ("gray3", GRAY3),
+    ("gray30", GRAY30),
+    ("gray31", GRAY31),
+    ("gray32", GRAY32),
+    ("gray33", GRAY33),
+    ("gray34", GRAY34),
+    ("gray35", GRAY35),
+    ("gray36", GRAY36),
+    ("gray37", GRAY37),
+    ("gray38", GRAY38),
+    ("gray39", GRAY39),
+    ("gray4", GRAY4),
+    ("gray40", GRAY40),
+    ("gray41", GRAY41),
+    ("gray42", GRAY42),
+    ("gray43", GRAY43),
+    ("gray44", GRAY44),
+    ("gray45", GRAY45),
+    ("gray46", GRAY46),
+    ("gray47", GRAY47),
+    ("gray48", GRAY48),
+    ("gray49", GRAY49),
+    ("gray5", GRAY5),
+    ("gray50", GRAY50),
+    ("gray51", GRAY51),
+    ("gray52", GRAY52),
+    ("gray53", GRAY53),
+    ("gray54", GRAY54),
+    ("gray55", GRAY55),
+    ("gray56", GRAY56),
+    ("gray57", GRAY57),
+    ("gray58", GRAY58),
+    ("gray59", GRAY59),
+    ("gray6", GRAY6),
+    ("gray60", GRAY60),
+    ("gray61", GRAY61),
+    ("gray62", GRAY62),
+    ("gray63", GRAY63),
+    ("gray64", GRAY64),
+    ("gray65", GRAY65),
+    ("gray66", GRAY66),
+    ("gray67", GRAY67),
+    ("gray68", GRAY68),
+    ("gray69", GRAY69),
+    ("gray7", GRAY7),
+    ("gray70", GRAY70),
+    ("gray71", GRAY71),
+    ("gray72", GRAY72),
+    ("gray73", GRAY73),
+    ("gray74", GRAY74),
+    ("gray75", GRAY75),
+    ("gray76", GRAY76),
+    ("gray77", GRAY77),
+    ("gray78", GRAY78),
+    ("gray79", GRAY79),
+    ("gray8", GRAY8),
+    ("gray80", GRAY80),
+    ("gray81", GRAY81),
+    ("gray82", GRAY82),
+    ("gray83", GRAY83),
+    ("gray84", GRAY84),
+    ("gray85", GRAY85),
+    ("gray86", GRAY86),
+    ("gray87", GRAY87),
+    ("gray88", GRAY88),
+    ("gray89", GRAY89),
+    ("gray9", GRAY9),
+    ("gray90", GRAY90),
+    ("gray91", GRAY91),
+    ("gray92", GRAY92),
+    ("gray93", GRAY93),
+    ("gray94", GRAY94),
+    ("gray95", GRAY95),
+    ("gray96", GRAY96),
+    ("gray97", GRAY97),
+    ("gray98", GRAY98),
+    ("gray99", GRAY99),
+    ("green", GREEN),
+    ("green1", GREEN1),
+    ("green2", GREEN2),
+    ("green3", GREEN3),
+    ("green4", GREEN4),
+    ("greenyellow", GREENYELLOW),
+    ("grey", GREY),
+    ("grey0", GREY0),
+    ("grey1", GREY1),
+    ("grey10", GREY10),
+    ("grey100", GREY100),
+    ("grey11", GREY11),
+    ("grey12", GREY12),
+    ("grey13", GREY13),
+    ("grey14", GREY14),
+    ("grey15", GREY15),
+    ("grey16", GREY16),
+    ("grey17", GREY17),
+    ("grey18", GREY18),
+    ("grey19", GREY19),
+    ("grey2", GREY2),
+    ("grey20", GREY20),
+    ("grey21", GREY21),
+    ("grey22", GREY22),
+    ("grey23", GREY23),
+    ("grey24", GREY24),
+    ("grey25", GREY25),
+    ("grey26", GREY26),
+    ("grey27", GREY27),
+    ("grey28", GREY28),
+    ("grey29", GREY29),
+    ("grey3", GREY3),
+    ("grey30", GREY30),
+    ("grey31", GREY31),
+    ("grey32", GREY32),
+    ("grey33", GREY33),
+    ("grey34", GREY34),
+    ("grey35", GREY35),
+    ("grey36", GREY36),
+    ("grey37", GREY37),
+    ("grey38", GREY38),
+    ("grey39", GREY39),
+    ("grey4", GREY4),
+    ("grey40", GREY40),
+    ("grey41", GREY41),
+    ("grey42", GREY42),
+    ("grey43", GREY43),
+    ("grey44", GREY44),
+    ("grey45", GREY45),
+    ("grey46", GREY46),
+    ("grey47", GREY47),
+    ("grey48", GREY48),
+    ("grey49", GREY49),
+    ("grey5", GREY5),
+    ("grey50", GREY50),
+    ("grey51", GREY51),
+    ("grey52", GREY52),
+    ("grey53", GREY53),
+    ("grey54", GREY54),
+    ("grey55", GREY55),
+    ("grey56", GREY56),
+    ("grey57", GREY57),
+    ("grey58", GREY58),
+    ("grey59", GREY59),
+    ("grey6", GREY6),
+    ("grey60", GREY60),
+    ("grey61", GREY61),
+    ("grey62", GREY62),
+    ("grey63", GREY63),
+    ("grey64", GREY64),
+    ("grey65", GREY65),
+    ("grey66", GREY66),
+    ("grey67", GREY67),
+    ("grey68", GREY68),
+    ("grey69", GREY69),
+    ("grey7", GREY7),
+    ("grey70", GREY70),
+    ("grey71", GREY71),
+    ("grey72", GREY72),
+    ("grey73", GREY73),
+    ("grey74", GREY74),
+    ("grey75", GREY75),
+    ("grey76", GREY76),
+    ("grey77", GREY77),
+    ("grey78", GREY78),
+    ("grey79", GREY79),
+    ("grey8", GREY8),
+    ("grey80", GREY80),
+    ("grey81", GREY81),
+    ("grey82", GREY82),
+    ("grey83", GREY83),
+    ("grey84", GREY84),
+    ("grey85", GREY85),
+    ("grey86", GREY86),
+    ("grey87", GREY87),
+    ("grey88", GREY88),
+    ("grey89", GREY89),
+    ("grey9", GREY9),
+    ("grey90", GREY90),
+    ("grey91", GREY91),
+    ("grey92", GREY92),
+    ("grey93", GREY93),
+    ("grey94", GREY94),
+    ("grey95", GREY95),
+    ("grey96", GREY96),
+    ("grey97", GREY97),
+    ("grey98", GREY98),
+    ("grey99", GREY99),
+    ("honeydew", HONEYDEW),
+    ("honeydew1", HONEYDEW1),
+    ("honeydew2", HONEYDEW2),
+    ("honeydew3", HONEYDEW3),
+    ("honeydew4", HONEYDEW4),
+    ("hotpink", HOTPINK),
+    ("hotpink1", HOTPINK1),
+    ("hotpink2", HOTPINK2),
+    ("hotpink3", HOTPINK3),
+    ("hotpink4", HOTPINK4),
+    ("indianred", INDIANRED),
+    ("indianred1", INDIANRED1),
+    ("indianred2", INDIANRED2),
+    ("indianred3", INDIANRED3),
+    ("indianred4", INDIANRED4),
+    ("indigo", INDIGO),
+    ("ivory", IVORY),
+    ("ivory1", IVORY1),
+    ("ivory2", IVORY2),
+    ("ivory3", IVORY3),
+    ("ivory4", IVORY4),
+    ("khaki", KHAKI),
+    ("khaki1", KHAKI1),
+    ("khaki2", KHAKI2),
+    ("khaki3", KHAKI3),
+    ("khaki4", KHAKI4),
+    ("lavender", LAVENDER),
+    ("lavenderblush", LAVENDERBLUSH),
+    ("lavenderblush1", LAVENDERBLUSH1),
+    ("lavenderblush2", LAVENDERBLUSH2),
+    ("lavenderblush3", LAVENDERBLUSH3),
+    ("lavenderblush4", LAVENDERBLUSH4),
+    ("lawngreen", LAWNGREEN),
+    ("lemonchiffon", LEMONCHIFFON),
+    ("lemonchiffon1", LEMONCHIFFON1),
+    ("lemonchiffon2", LEMONCHIFFON2),
+    ("lemonchiffon3", LEMONCHIFFON3),
+    ("lemonchiffon4", LEMONCHIFFON4),
+    ("lightblue", LIGHTBLUE),
+    ("lightblue1", LIGHTBLUE1),
+    ("lightblue2", LIGHTBLUE2),
+    ("lightblue3", LIGHTBLUE3),
+    ("lightblue4", LIGHTBLUE4),
+    ("lightcoral", LIGHTCORAL),
+    ("lightcyan", LIGHTCYAN),
+    ("lightcyan1", LIGHTCYAN1),
+    ("lightcyan2", LIGHTCYAN2),
+    ("lightcyan3", LIGHTCYAN3),
+    ("lightcyan4", LIGHTCYAN4),
+    ("lightgoldenrod", LIGHTGOLDENROD),
+    ("lightgoldenrod1", LIGHTGOLDENROD1),
+    ("lightgoldenrod2", LIGHTGOLDENROD2),
+    ("lightgoldenrod3", LIGHTGOLDENROD3),
+    ("lightgoldenrod4", LIGHTGOLDENROD4),
+    ("lightgoldenrodyellow", LIGHTGOLDENRODYELLOW),
+    ("lightgray", LIGHTGRAY),
+    ("lightgreen", LIGHTGREEN),
+    ("lightgrey", LIGHTGREY),
+    ("lightpink", LIGHTPINK),
+    ("lightpink1", LIGHTPINK1),
+    ("lightpink2", LIGHTPINK2),
+    ("lightpink3", LIGHTPINK3),
+    ("lightpink4", LIGHTPINK4),
+    ("lightsalmon", LIGHTSALMON),
+    ("lightsalmon1", LIGHTSALMON1),
+    ("lightsalmon2", LIGHTSALMON2),
+    ("lightsalmon3", LIGHTSALMON3),
+    ("lightsalmon4", LIGHTSALMON4),
+    ("lightseagreen", LIGHTSEAGREEN),
+    ("lightskyblue", LIGHTSKYBLUE),
+    ("lightskyblue1", LIGHTSKYBLUE1),
+    ("lightskyblue2", LIGHTSKYBLUE2),
+    ("lightskyblue3", LIGHTSKYBLUE3),
+    ("lightskyblue4", LIGHTSKYBLUE4),
+    ("lightslateblue", LIGHTSLATEBLUE),
+    ("lightslategray", LIGHTSLATEGRAY),
+    ("lightslategrey", LIGHTSLATEGREY),
+    ("lightsteelblue", LIGHTSTEELBLUE),
+    ("lightsteelblue1", LIGHTSTEELBLUE1),
+    ("lightsteelblue2", LIGHTSTEELBLUE2),
+    ("lightsteelblue3", LIGHTSTEELBLUE3),
+    ("lightsteelblue4", LIGHTSTEELBLUE4),
+    ("lightyellow", LIGHTYELLOW),
+    ("lightyellow1", LIGHTYELLOW1),
+    ("lightyellow2", LIGHTYELLOW2),
+    ("lightyellow3", LIGHTYELLOW3),
+    ("lightyellow4", LIGHTYELLOW4),
+    ("lime", LIME),
+    ("limegreen", LIMEGREEN),
+    ("linen", LINEN),
+    ("magenta", MAGENTA),
+    ("magenta1", MAGENTA1),
+    ("magenta2", MAGENTA2),
+    ("magenta3", MAGENTA3),
+    ("magenta4", MAGENTA4),
+    ("maroon", MAROON),
+    ("maroon1", MAROON1),
+    ("maroon2", MAROON2),
+    ("maroon3", MAROON3),
+    ("maroon4", MAROON4),
+    ("mediumaquamarine", MEDIUMAQUAMARINE),
+    ("mediumblue", MEDIUMBLUE),
+    ("mediumorchid", MEDIUMORCHID),
+    ("mediumorchid1", MEDIUMORCHID1),
+    ("mediumorchid2", MEDIUMORCHID2),
+    ("mediumorchid3", MEDIUMORCHID3),
+    ("mediumorchid4", MEDIUMORCHID4),
+    ("mediumpurple", MEDIUMPURPLE),
+    ("mediumpurple1", MEDIUMPURPLE1),
+    ("mediumpurple2", MEDIUMPURPLE2),
+    ("mediumpurple3", MEDIUMPURPLE3),
+    ("mediumpurple4", MEDIUMPURPLE4),
+    ("mediumseagreen", MEDIUMSEAGREEN),
+    ("mediumslateblue", MEDIUMSLATEBLUE),
+    ("mediumspringgreen", MEDIUMSPRINGGREEN),
+    ("mediumturquoise", MEDIUMTURQUOISE),
+    ("mediumvioletred", MEDIUMVIOLETRED),
+    ("midnightblue", MIDNIGHTBLUE),
+    ("mintcream", MINTCREAM),
+    ("mistyrose", MISTYROSE),
+    ("mistyrose1", MISTYROSE1),
+    ("mistyrose2", MISTYROSE2),
+    ("mistyrose3", MISTYROSE3),
+    ("mistyrose4", MISTYROSE4),
+    ("moccasin", MOCCASIN),
+    ("navajowhite", NAVAJOWHITE),
+    ("navajowhite1", NAVAJOWHITE1),
+    ("navajowhite2", NAVAJOWHITE2),
+    ("navajowhite3", NAVAJOWHITE3),
+    ("navajowhite4", NAVAJOWHITE4),
+    ("navy", NAVY),
+    ("navyblue", NAVYBLUE),
+    ("oldlace", OLDLACE),
+    ("olive", OLIVE),
+    ("olivedrab", OLIVEDRAB),
+    ("olivedrab1", OLIVEDRAB1),
+    ("olivedrab2", OLIVEDRAB2),
+    ("olivedrab3", OLIVEDRAB3),
+    ("olivedrab4", OLIVEDRAB4),
+    ("orange", ORANGE),
+    ("orange1", ORANGE1),
+    ("orange2", ORANGE2),
+    ("orange3", ORANGE3),
+    ("orange4", ORANGE4),
+    ("orangered", ORANGERED),
+    ("orangered1", ORANGERED1),
+    ("orangered2", ORANGERED2),
+    ("orangered3", ORANGERED3),
+    ("orangered4", ORANGERED4),
+    ("orchid", ORCHID),
+    ("orchid1", ORCHID1),
+    ("orchid2", ORCHID2),
+    ("orchid3", ORCHID3),
+    ("orchid4", ORCHID4),
+    ("palegoldenrod", PALEGOLDENROD),
+    ("palegreen", PALEGREEN),
+    ("palegreen1", PALEGREEN1),
+    ("palegreen2", PALEGREEN2),
+    ("palegreen3", PALEGREEN3),
+    ("palegreen4", PALEGREEN4),
+    ("paleturquoise", PALETURQUOISE),
+    ("paleturquoise1", PALETURQUOISE1),
+    ("paleturquoise2", PALETURQUOISE2),
+    ("paleturquoise3", PALETURQUOISE3),
+    ("paleturquoise4", PALETURQUOISE4),
+    ("palevioletred", PALEVIOLETRED),
+    ("palevioletred1", PALEVIOLETRED1),
+    ("palevioletred2", PALEVIOLETRED2),
+    ("palevioletred3", PALEVIOLETRED3),
+    ("palevioletred4", PALEVIOLETRED4),
+    ("papayawhip", PAPAYAWHIP),
+    ("peachpuff", PEACHPUFF),
+    ("peachpuff1", PEACHPUFF1),
+    ("peachpuff2", PEACHPUFF2),
+    ("peachpuff3", PEACHPUFF3),
+    ("peachpuff4", PEACHPUFF4),
+    ("peru", PERU),
+    ("pink", PINK),
+    ("pink1", PINK1),
+    ("pink2", PINK2),
+    ("pink3", PINK3),
+    ("pink4", PINK4),
+    ("plum", PLUM),
+    ("plum1", PLUM1),
+    ("plum2", PLUM2),
+    ("plum3", PLUM3),
+    ("plum4", PLUM4),
+    ("powderblue", POWDERBLUE),
+    ("purple", PURPLE),
+    ("purple1", PURPLE1),
+    ("purple2", PURPLE2),
+    ("purple3", PURPLE3),
+    ("purple4", PURPLE4),
+    ("rebeccapurple", REBECCAPURPLE),
+    ("red", RED),
+    ("red1", RED1),
+    ("red2", RED2),
+    ("red3", RED3),
+    ("red4", RED4),
+    ("rosybrown", ROSYBROWN),
+    ("rosybrown1", ROSYBROWN1),
+    ("rosybrown2", ROSYBROWN2),
+    ("rosybrown3", ROSYBROWN3),
+    ("rosybrown4", ROSYBROWN4),
+    ("royalblue", ROYALBLUE),
+    ("royalblue1", ROYALBLUE1),
+    ("royalblue2", ROYALBLUE2),
+    ("royalblue3", ROYALBLUE3),
+    ("royalblue4", ROYALBLUE4),
+    ("saddlebrown", SADDLEBROWN),
+    ("salmon", SALMON),
+    ("salmon1", SALMON1),
+    ("salmon2", SALMON2),
+    ("salmon3", SALMON3),
+    ("salmon4", SALMON4),
+    ("sandybrown", SANDYBROWN),
+    ("seagreen", SEAGREEN),
+    ("seagreen1", SEAGREEN1),
+    ("seagreen2", SEAGREEN2),
+    ("seagreen3", SEAGREEN3),
+    ("seagreen4", SEAGREEN4),
+    ("seashell", SEASHELL),
+    ("seashell1", SEASHELL1),
+    ("seashell2", SEASHELL2),
+    ("seashell3", SEASHELL3),
+    ("seashell4", SEASHELL4),
+    ("sienna", SIENNA),
+    ("sienna1", SIENNA1),
+    ("sienna2", SIENNA2),
+    ("sienna3", SIENNA3),
+    ("sienna4", SIENNA4),
+    ("silver", SILVER),
+    ("skyblue", SKYBLUE),
+    ("skyblue1", SKYBLUE1),
+    ("skyblue2", SKYBLUE2),
+    ("skyblue3", SKYBLUE3),
+    ("skyblue4", SKYBLUE4),
+    ("slateblue", SLATEBLUE),
+    ("slateblue1", SLATEBLUE1),
+    ("slateblue2", SLATEBLUE2),
+    ("slateblue3", SLATEBLUE3),
+    ("slateblue4", SLATEBLUE4),
+    ("slategray", SLATEGRAY),
+    ("slategray1", SLATEGRAY1),
+    ("slategray2", SLATEGRAY2),
+    ("slategray3", SLATEGRAY3),
+    ("slategray4", SLATEGRAY4),
+    ("slategrey", SLATEGREY),
+    ("snow", SNOW),
+    ("snow1", SNOW1),
+    ("snow2", SNOW2),
+    ("snow3", SNOW3),
+    ("snow4", SNOW4),
+    ("springgreen", SPRINGGREEN),
+    ("springgreen1", SPRINGGREEN1),
+    ("springgreen2", SPRINGGREEN2),
+    ("springgreen3", SPRINGGREEN3),
+    ("springgreen4", SPRINGGREEN4),
+    ("steelblue", STEELBLUE),
+    ("steelblue1", STEELBLUE1),
+    ("steelblue2", STEELBLUE2),
+    ("steelblue3", STEELBLUE3),
+    ("steelblue4", STEELBLUE4),
+    ("tan", TAN),
+    ("tan1", TAN1),
+    ("tan2", TAN2),
+    ("tan3", TAN3),
+    ("tan4", TAN4),
+    ("teal", TEAL),
+    ("thistle", THISTLE),
+    ("thistle1", THISTLE1),
+    ("thistle2", THISTLE2),
+    ("thistle3", THISTLE3),
+    ("thistle4", THISTLE4),
+    ("tomato", TOMATO),
+    ("tomato1", TOMATO1),
+    ("tomato2", TOMATO2),
+    ("tomato3", TOMATO3),
+    ("tomato4", TOMATO4),
+    ("turquoise", TURQUOISE),
+    ("turquoise1", TURQUOISE1),
+    ("turquoise2", TURQUOISE2),
+    ("turquoise3", TURQUOISE3),
+    ("turquoise4", TURQUOISE4),
+    ("violet", VIOLET),
+    ("violetred", VIOLETRED),
+    ("violetred1", VIOLETRED1),
+    ("violetred2", VIOLETRED2),
+    ("violetred3", VIOLETRED3),
+    ("violetred4", VIOLETRED4),
+    ("webgray", WEBGRAY),
+    ("webgreen", WEBGREEN),
+    ("webgrey", WEBGREY),
+    ("webmaroon", WEBMAROON),
+    ("webpurple", WEBPURPLE),
+    ("wheat", WHEAT),
+    ("wheat1", WHEAT1),
+    ("wheat2", WHEAT2),
+    ("wheat3", WHEAT3),
+    ("wheat4", WHEAT4),
+    ("white", WHITE),
+    ("whitesmoke", WHITESMOKE),
+    ("x11gray", X11GRAY),
+    ("x11green", X11GREEN),
+    ("x11grey", X11GREY),
+    ("x11maroon", X11MAROON),
+    ("x11purple", X11PURPLE),
+    ("yellow", YELLOW),
+    ("yellow1", YELLOW1),
+    ("yellow2", YELLOW2),
+    ("yellow3", YELLOW3),
+    ("yellow4", YELLOW4),
+    ("yellowgreen", YELLOWGREEN),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use named;
+
+    #[test]
+    fn test_tables() {
+        assert_eq!(COLORS.len(), 676);
+        assert!(COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(GRAY, Rgb::from_channels(190, 190, 190));
+        assert_eq!(WEBGRAY, named::GRAY);
+        assert_eq!(X11GREEN, GREEN);
+        assert_eq!(GRAY100, named::WHITE);
+
+        // Every CSS name is also an X11 name, with the same value unless X11 redefines it.
+        for &(name, color) in named::COLORS {
+            let x11 = from_name(name).unwrap();
+            let redefined = ["gray", "grey", "green", "maroon", "purple"].contains(&name);
+            assert_eq!(x11 == color, !redefined, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(from_name("ghost white"), Some(GHOSTWHITE));
+        assert_eq!(from_name("GhostWhite"), Some(GHOSTWHITE));
+        assert_eq!(from_name("Light Goldenrod"), Some(rgb(238, 221, 130)));
+        assert_eq!(from_name("antiquewhite4"), Some(ANTIQUEWHITE4));
+        assert_eq!(from_name("grey50"), Some(rgb(127, 127, 127)));
+        assert_eq!(from_name("gray101"), None);
+    }
+
+    #[test]
+    fn test_nearest() {
+        assert_eq!(nearest(&DARKORCHID3), ("darkorchid3", DARKORCHID3));
+        assert_eq!(nearest(&Rgb::from_channels(0.49, 0.49, 0.49)).0, "gray49");
+    }
+}
@@ -0,0 +1,427 @@
+use std::fmt;
+use std::mem;
+use std::slice;
+use approx;
+use num;
+use channel::{PosNormalBoundedChannel, AngularChannel, ChannelCast, PosNormalChannelScalar,
+              AngularChannelScalar, FreeChannelScalar, ColorChannel};
+use color::{Color, PolarColor, Lerp, Bounded, FromTuple};
+use color;
+use convert;
+use convert::FromColor;
+use angle;
+use angle::{Angle, FromAngle, IntoAngle, Deg, Rad};
+use channel::cast::ChannelFormatCast;
+use alpha::Alpha;
+use encoding::{EncodableColor, EncodedColor, LinearColor, SrgbEncoding};
+use oklab::Oklab;
+use rgb::Rgb;
+
+pub struct OkhslTag;
+
+/// Björn Ottosson's Okhsl, an HSL-like model built on Oklab.
+///
+/// The lightness is Oklab's `L` through the `toe` estimate, so colors with the same lightness
+/// look equally light, and a saturation of 1 reaches the edge of the sRGB gamut for every hue
+/// and lightness.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Hash)]
+pub struct Okhsl<T, A = Deg<T>> {
+    pub hue: AngularChannel<A>,
+    pub saturation: PosNormalBoundedChannel<T>,
+    pub lightness: PosNormalBoundedChannel<T>,
+}
+
+pub type Okhsla<T, A> = Alpha<T, Okhsl<T, A>>;
+
+impl<T, A> Okhsl<T, A>
+    where T: PosNormalChannelScalar,
+          A: AngularChannelScalar
+{
+    pub fn from_channels(hue: A, saturation: T, lightness: T) -> Self {
+        Okhsl {
+            hue: AngularChannel::new(hue),
+            saturation: PosNormalBoundedChannel::new(saturation),
+            lightness: PosNormalBoundedChannel::new(lightness),
+        }
+    }
+
+    impl_color_color_cast_angular!(Okhsl {hue, saturation, lightness}, 
+        chan_traits={PosNormalChannelScalar});
+
+    pub fn hue(&self) -> A {
+        self.hue.0.clone()
+    }
+    pub fn saturation(&self) -> T {
+        self.saturation.0.clone()
+    }
+    pub fn lightness(&self) -> T {
+        self.lightness.0.clone()
+    }
+    pub fn hue_mut(&mut self) -> &mut A {
+        &mut self.hue.0
+    }
+    pub fn saturation_mut(&mut self) -> &mut T {
+        &mut self.saturation.0
+    }
+    pub fn lightness_mut(&mut self) -> &mut T {
+        &mut self.lightness.0
+    }
+    pub fn set_hue(&mut self, val: A) {
+        self.hue.0 = val;
+    }
+    pub fn set_saturation(&mut self, val: T) {
+        self.saturation.0 = val;
+    }
+    pub fn set_lightness(&mut self, val: T) {
+        self.lightness.0 = val;
+    }
+}
+
+impl<T, A> PolarColor for Okhsl<T, A>
+    where T: PosNormalChannelScalar,
+          A: AngularChannelScalar
+{
+    type Angular = A;
+    type Cartesian = T;
+}
+
+impl<T, A> Color for Okhsl<T, A>
+    where T: PosNormalChannelScalar,
+          A: AngularChannelScalar
+{
+    type Tag = OkhslTag;
+    type ChannelsTuple = (A, T, T);
+
+    fn num_channels() -> u32 {
+        3
+    }
+    fn to_tuple(self) -> Self::ChannelsTuple {
+        (self.hue.0, self.saturation.0, self.lightness.0)
+    }
+}
+
+impl<T, A> FromTuple for Okhsl<T, A>
+    where T: PosNormalChannelScalar,
+          A: AngularChannelScalar
+{
+    fn from_tuple(values: Self::ChannelsTuple) -> Self {
+        Okhsl::from_channels(values.0, values.1, values.2)
+    }
+}
+
+impl<T, A> Lerp for Okhsl<T, A>
+    where T: PosNormalChannelScalar + color::Lerp,
+          A: AngularChannelScalar + color::Lerp
+{
+    type Position = A::Position;
+
+    impl_color_lerp_angular!(Okhsl<T> {hue, saturation, lightness});
+}
+
+impl<T, A> Bounded for Okhsl<T, A>
+    where T: PosNormalChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_bounded!(Okhsl {hue, saturation, lightness});
+}
+
+impl<T, A> color::Flatten for Okhsl<T, A>
+    where T: PosNormalChannelScalar + num::Float,
+          A: AngularChannelScalar + Angle<Scalar = T> + FromAngle<angle::Turns<T>>
+{
+    type ScalarFormat = T;
+
+    impl_color_as_slice!(T);
+    impl_color_from_slice_angular!(Okhsl<T, A> {hue:AngularChannel - 0, 
+        saturation:PosNormalBoundedChannel - 1, lightness:PosNormalBoundedChannel - 2});
+}
+
+impl<T, A> approx::ApproxEq for Okhsl<T, A>
+    where T: PosNormalChannelScalar + approx::ApproxEq<Epsilon = A::Epsilon>,
+          A: AngularChannelScalar + approx::ApproxEq,
+          A::Epsilon: Clone + num::Float
+{
+    impl_approx_eq!({hue, saturation, lightness});
+}
+
+impl<T, A> Default for Okhsl<T, A>
+    where T: PosNormalChannelScalar + num::Zero,
+          A: AngularChannelScalar + num::Zero
+{
+    impl_color_default!(Okhsl {hue: AngularChannel, 
+        saturation: PosNormalBoundedChannel, lightness: PosNormalBoundedChannel});
+}
+
+impl<T, A> fmt::Display for Okhsl<T, A>
+    where T: PosNormalChannelScalar + fmt::Display,
+          A: AngularChannelScalar + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Okhsl({}, {}, {})", self.hue, self.saturation, self.lightness)
+    }
+}
+
+impl<T, A> convert::GetHue for Okhsl<T, A>
+    where T: PosNormalChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_get_hue_angular!(Okhsl);
+}
+
+impl<T, A> FromColor<Oklab<T>> for Okhsl<T, A>
+    where T: PosNormalChannelScalar + FreeChannelScalar,
+          A: AngularChannelScalar + FromAngle<Rad<T>> + Angle
+{
+    fn from_color(from: &Oklab<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let hue_angle = Rad::atan2(from.b(), from.a());
+        let hue = <A as Angle>::normalize(A::from_angle(hue_angle));
+        if is_achromatic(from.L()) {
+            return Okhsl::from_channels(hue, zero, Oklab::toe(from.L().max(zero).min(one)));
+        }
+
+        let chroma = from.a().hypot(from.b());
+        let (c_0, c_mid, c_max) = chroma_stops(from.L(), hue_angle.cos(), hue_angle.sin());
+        let (mid, mid_inv) = midpoint();
+
+        // Saturation is piecewise, reaching `mid` at `c_mid` and 1 at the gamut boundary.
+        let saturation = if chroma < c_mid {
+            let k_1 = mid * c_0;
+            let k_2 = one - k_1 / c_mid;
+            mid * chroma / (k_1 + k_2 * chroma)
+        } else {
+            let k_1 = (one - mid) * c_mid * c_mid * mid_inv * mid_inv / c_0;
+            let k_2 = one - k_1 / (c_max - c_mid);
+            let t = (chroma - c_mid) / (k_1 + k_2 * (chroma - c_mid));
+            mid + (one - mid) * t
+        };
+        Okhsl::from_channels(hue, saturation, Oklab::toe(from.L()))
+    }
+}
+
+impl<T, A> FromColor<Okhsl<T, A>> for Oklab<T>
+    where T: PosNormalChannelScalar + FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn from_color(from: &Okhsl<T, A>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        if is_achromatic(from.lightness()) {
+            return Oklab::from_channels(from.lightness().max(zero).min(one), zero, zero);
+        }
+
+        let (a_, b_) = (from.hue().cos(), from.hue().sin());
+        let lightness = Oklab::toe_inv(from.lightness());
+        let (c_0, c_mid, c_max) = chroma_stops(lightness, a_, b_);
+        let (mid, mid_inv) = midpoint();
+
+        let s = from.saturation();
+        let chroma = if s < mid {
+            let t = mid_inv * s;
+            let k_1 = mid * c_0;
+            let k_2 = one - k_1 / c_mid;
+            t * k_1 / (one - k_2 * t)
+        } else {
+            let t = (s - mid) / (one - mid);
+            let k_1 = (one - mid) * c_mid * c_mid * mid_inv * mid_inv / c_0;
+            let k_2 = one - k_1 / (c_max - c_mid);
+            c_mid + t * k_1 / (one - k_2 * t)
+        };
+        Oklab::from_channels(lightness, chroma * a_, chroma * b_)
+    }
+}
+
+// The chroma stops vanish at black and white, so any residual chroma there would blow up to a
+// large saturation. sRGB white only reaches L = 1 to within the rounding of Oklab's matrices.
+fn is_achromatic<T: FreeChannelScalar>(lightness: T) -> bool {
+    lightness < num::cast(1e-7).unwrap() || lightness > num::cast(1.0 - 1e-7).unwrap()
+}
+
+fn midpoint<T: FreeChannelScalar>() -> (T, T) {
+    (num::cast(0.8).unwrap(), num::cast(1.25).unwrap())
+}
+
+// The chroma at saturation 0+, `mid` and 1 for a lightness strictly between 0 and 1.
+fn chroma_stops<T>(lightness: T, a_: T, b_: T) -> (T, T, T)
+    where T: FreeChannelScalar
+{
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    let one = T::one();
+    let cusp = Oklab::srgb_cusp(a_, b_);
+    let c_max = Oklab::srgb_gamut_intersection(a_, b_, lightness, one, lightness, cusp);
+    let (s_max, t_max) = (cusp.1 / cusp.0, cusp.1 / (one - cusp.0));
+    let k = c_max / (lightness * s_max).min((one - lightness) * t_max);
+
+    // A smoother approximation of the gamut triangle, fitted to keep c_mid inside sRGB.
+    let s_mid = c(0.11516993) +
+                one /
+                (c(7.44778970) + c(4.15901240) * b_ +
+                 a_ *
+                 (c(-2.19557347) + c(1.75198401) * b_ +
+                  a_ *
+                  (c(-2.13704948) - c(10.02301043) * b_ +
+                   a_ * (c(-4.24894561) + c(5.38770819) * b_ + c(4.69891013) * a_))));
+    let t_mid = c(0.11239642) +
+                one /
+                (c(1.61320320) - c(0.68124379) * b_ +
+                 a_ *
+                 (c(0.40370612) + c(0.90148123) * b_ +
+                  a_ *
+                  (c(-0.27087943) + c(0.61223990) * b_ +
+                   a_ * (c(0.00299215) - c(0.45399568) * b_ - c(0.14661872) * a_))));
+    let c_a = lightness * s_mid;
+    let c_b = (one - lightness) * t_mid;
+    let c_mid = c(0.9) * k * (one / (one / c_a.powi(4) + one / c_b.powi(4))).sqrt().sqrt();
+
+    let c_a = lightness * c(0.4);
+    let c_b = (one - lightness) * c(0.8);
+    let c_0 = (one / (one / c_a.powi(2) + one / c_b.powi(2))).sqrt();
+
+    (c_0, c_mid, c_max)
+}
+
+impl<T, A> FromColor<EncodedColor<Rgb<T>, SrgbEncoding>> for Okhsl<T, A>
+    where T: PosNormalChannelScalar + FreeChannelScalar,
+          A: AngularChannelScalar + FromAngle<Rad<T>> + Angle,
+          Rgb<T>: EncodableColor
+{
+    fn from_color(from: &EncodedColor<Rgb<T>, SrgbEncoding>) -> Self {
+        Okhsl::from_color(&Oklab::from_color(&from.clone().decode()))
+    }
+}
+
+impl<T, A> FromColor<Okhsl<T, A>> for EncodedColor<Rgb<T>, SrgbEncoding>
+    where T: PosNormalChannelScalar + FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>,
+          Rgb<T>: EncodableColor
+{
+    fn from_color(from: &Okhsl<T, A>) -> Self {
+        LinearColor::from_color(&Oklab::from_color(from)).encode(SrgbEncoding::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use angle::*;
+    use color::Flatten;
+    use encoding::{EncodedColor, SrgbEncoding};
+    use oklab::Oklab;
+    use rgb::Rgb;
+
+    fn srgb(r: f64, g: f64, b: f64) -> EncodedColor<Rgb<f64>, SrgbEncoding> {
+        EncodedColor::new(Rgb::from_channels(r, g, b), SrgbEncoding::new())
+    }
+
+    #[test]
+    fn test_construct() {
+        let c1 = Okhsl::from_channels(Deg(50.0), 0.5, 0.3);
+        assert_ulps_eq!(c1.hue(), Deg(50.0));
+        assert_ulps_eq!(c1.saturation(), 0.5);
+        assert_ulps_eq!(c1.lightness(), 0.3);
+
+        let mut c2 = Okhsl::from_channels(Turns(0.9), 0.5, 0.75);
+        c2.set_lightness(0.33);
+        assert_ulps_eq!(c2, Okhsl::from_channels(Turns(0.9), 0.5, 0.33));
+        assert_eq!(c2.to_tuple(), (Turns(0.9), 0.5, 0.33));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let c1 = Okhsl::from_channels(Deg(20.0), 0.0, 1.0);
+        let c2 = Okhsl::from_channels(Deg(100.0), 1.0, 0.0);
+        assert_ulps_eq!(c1.lerp(&c2, 0.0), c1);
+        assert_ulps_eq!(c1.lerp(&c2, 1.0), c2);
+        assert_ulps_eq!(c1.lerp(&c2, 0.5), Okhsl::from_channels(Deg(60.0), 0.5, 0.5));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let c1 = Okhsl::from_channels(Deg(400.0), 1.25, 0.75);
+        assert!(!c1.is_normalized());
+        assert_ulps_eq!(c1.normalize(), Okhsl::from_channels(Deg(40.0), 1.0, 0.75));
+    }
+
+    #[test]
+    fn test_flatten() {
+        let c1 = Okhsl::from_channels(Turns(0.25), 0.3, 0.2);
+        assert_eq!(c1.as_slice(), &[0.25, 0.3, 0.2]);
+        assert_eq!(c1, Okhsl::from_slice(c1.as_slice()));
+    }
+
+    #[test]
+    fn test_from_srgb() {
+        let red: Okhsl<f64> = Okhsl::from_color(&srgb(1.0, 0.0, 0.0));
+        assert_relative_eq!(red, Okhsl::from_channels(Deg(29.233885), 1.0, 0.568085),
+            epsilon=1e-6);
+        let blue: Okhsl<f64> = Okhsl::from_color(&srgb(0.0, 0.0, 1.0));
+        assert_relative_eq!(blue, Okhsl::from_channels(Deg(264.052021), 1.0, 0.366565),
+            epsilon=1e-6);
+        let c1: Okhsl<f64> = Okhsl::from_color(&srgb(0.5, 0.25, 0.75));
+        assert_relative_eq!(c1, Okhsl::from_channels(Deg(303.194798), 0.766539, 0.438531),
+            epsilon=1e-6);
+        let c2: Okhsl<f64> = Okhsl::from_color(&srgb(0.2, 0.6, 0.4));
+        assert_relative_eq!(c2, Okhsl::from_channels(Deg(157.557151), 0.855839, 0.548398),
+            epsilon=1e-6);
+
+        let gray: Okhsl<f64> = Okhsl::from_color(&srgb(0.5, 0.5, 0.5));
+        assert_relative_eq!(gray.saturation(), 0.0, epsilon=1e-6);
+        assert_relative_eq!(gray.lightness(), 0.533760, epsilon=1e-6);
+        let white: Okhsl<f64> = Okhsl::from_color(&srgb(1.0, 1.0, 1.0));
+        assert_relative_eq!(white.saturation(), 0.0, epsilon=1e-6);
+        assert_relative_eq!(white.lightness(), 1.0, epsilon=1e-6);
+        assert_relative_eq!(EncodedColor::from_color(&white), srgb(1.0, 1.0, 1.0), epsilon=1e-6);
+        let black: Okhsl<f64> = Okhsl::from_color(&srgb(0.0, 0.0, 0.0));
+        assert_relative_eq!(black, Okhsl::from_channels(Deg(0.0), 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_to_srgb() {
+        let c1 = EncodedColor::from_color(&Okhsl::from_channels(Deg(0.0), 0.5, 0.5));
+        assert_relative_eq!(c1, srgb(0.667034, 0.351390, 0.456152), epsilon=1e-6);
+        let c2 = EncodedColor::from_color(&Okhsl::from_channels(Deg(200.0), 0.3, 0.8));
+        assert_relative_eq!(c2, srgb(0.664154, 0.812071, 0.819143), epsilon=1e-6);
+        let c3 = EncodedColor::from_color(&Okhsl::from_channels(Deg(90.0), 1.0, 1.0));
+        assert_relative_eq!(c3, srgb(1.0, 1.0, 1.0), epsilon=1e-6);
+
+        for &color in &[(0.5, 0.25, 0.75), (0.2, 0.6, 0.4), (1.0, 1.0, 0.0), (0.1, 0.1, 0.1)] {
+            let c = srgb(color.0, color.1, color.2);
+            let okhsl: Okhsl<f64> = Okhsl::from_color(&c);
+            assert_relative_eq!(EncodedColor::from_color(&okhsl), c, epsilon=1e-5);
+        }
+    }
+
+    #[test]
+    fn test_gamut() {
+        for hue in (0..12).map(|i| Deg(f64::from(i) * 30.0)) {
+            let color = Okhsl::from_channels(hue, 1.0, 0.6);
+            assert_relative_eq!(Oklab::from_color(&color).L(), Oklab::toe_inv(0.6),
+                epsilon=1e-12);
+
+            let rgb = EncodedColor::from_color(&color);
+            let rgb = rgb.color();
+            let max = rgb.red().max(rgb.green()).max(rgb.blue());
+            let min = rgb.red().min(rgb.green()).min(rgb.blue());
+            assert!((max - 1.0).abs() < 1e-4 || min.abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_oklab_round_trip() {
+        let c1 = Oklab::from_channels(0.6, 0.1, -0.05);
+        let t1: Okhsl<f64> = Okhsl::from_color(&c1);
+        assert_relative_eq!(Oklab::from_color(&t1), c1, epsilon=1e-9);
+
+        let c2 = Okhsl::from_channels(Deg(90.0), 1.0, 0.7);
+        let t2 = Oklab::from_color(&c2);
+        assert_relative_eq!(t2, Oklab::from_channels(0.741982, 0.0, 0.151629), epsilon=1e-6);
+        assert_relative_eq!(Okhsl::from_color(&t2), c2, epsilon=1e-9);
+    }
+
+    #[test]
+    fn test_color_cast() {
+        let c1 = Okhsl::from_channels(Deg(120.0), 0.25, 0.5);
+        assert_relative_eq!(c1.color_cast(), c1);
+        assert_relative_eq!(c1.color_cast::<f32, Turns<f32>>().color_cast(), c1, epsilon=1e-6);
+    }
+}
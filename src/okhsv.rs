@@ -0,0 +1,392 @@
+use std::fmt;
+use std::mem;
+use std::slice;
+use approx;
+use num;
+use channel::{PosNormalBoundedChannel, AngularChannel, ChannelCast, PosNormalChannelScalar,
+              AngularChannelScalar, FreeChannelScalar, ColorChannel};
+use color::{Color, PolarColor, Lerp, Bounded, FromTuple};
+use color;
+use convert;
+use convert::FromColor;
+use angle;
+use angle::{Angle, FromAngle, IntoAngle, Deg, Rad};
+use channel::cast::ChannelFormatCast;
+use alpha::Alpha;
+use encoding::{EncodableColor, EncodedColor, LinearColor, SrgbEncoding};
+use oklab::Oklab;
+use rgb::Rgb;
+
+pub struct OkhsvTag;
+
+/// Björn Ottosson's Okhsv, an HSV-like model built on Oklab.
+///
+/// The hue is Oklab's hue, and a saturation and value of 1 reach the edge of the sRGB gamut
+/// for every hue, so the model is a drop-in replacement for `Hsv` in sRGB color pickers.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Hash)]
+pub struct Okhsv<T, A = Deg<T>> {
+    pub hue: AngularChannel<A>,
+    pub saturation: PosNormalBoundedChannel<T>,
+    pub value: PosNormalBoundedChannel<T>,
+}
+
+pub type Okhsva<T, A> = Alpha<T, Okhsv<T, A>>;
+
+impl<T, A> Okhsv<T, A>
+    where T: PosNormalChannelScalar,
+          A: AngularChannelScalar
+{
+    pub fn from_channels(hue: A, saturation: T, value: T) -> Self {
+        Okhsv {
+            hue: AngularChannel::new(hue),
+            saturation: PosNormalBoundedChannel::new(saturation),
+            value: PosNormalBoundedChannel::new(value),
+        }
+    }
+
+    impl_color_color_cast_angular!(Okhsv {hue, saturation, value}, 
+        chan_traits={PosNormalChannelScalar});
+
+    pub fn hue(&self) -> A {
+        self.hue.0.clone()
+    }
+    pub fn saturation(&self) -> T {
+        self.saturation.0.clone()
+    }
+    pub fn value(&self) -> T {
+        self.value.0.clone()
+    }
+    pub fn hue_mut(&mut self) -> &mut A {
+        &mut self.hue.0
+    }
+    pub fn saturation_mut(&mut self) -> &mut T {
+        &mut self.saturation.0
+    }
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.value.0
+    }
+    pub fn set_hue(&mut self, val: A) {
+        self.hue.0 = val;
+    }
+    pub fn set_saturation(&mut self, val: T) {
+        self.saturation.0 = val;
+    }
+    pub fn set_value(&mut self, val: T) {
+        self.value.0 = val;
+    }
+}
+
+impl<T, A> PolarColor for Okhsv<T, A>
+    where T: PosNormalChannelScalar,
+          A: AngularChannelScalar
+{
+    type Angular = A;
+    type Cartesian = T;
+}
+
+impl<T, A> Color for Okhsv<T, A>
+    where T: PosNormalChannelScalar,
+          A: AngularChannelScalar
+{
+    type Tag = OkhsvTag;
+    type ChannelsTuple = (A, T, T);
+
+    fn num_channels() -> u32 {
+        3
+    }
+    fn to_tuple(self) -> Self::ChannelsTuple {
+        (self.hue.0, self.saturation.0, self.value.0)
+    }
+}
+
+impl<T, A> FromTuple for Okhsv<T, A>
+    where T: PosNormalChannelScalar,
+          A: AngularChannelScalar
+{
+    fn from_tuple(values: Self::ChannelsTuple) -> Self {
+        Okhsv::from_channels(values.0, values.1, values.2)
+    }
+}
+
+impl<T, A> Lerp for Okhsv<T, A>
+    where T: PosNormalChannelScalar + color::Lerp,
+          A: AngularChannelScalar + color::Lerp
+{
+    type Position = A::Position;
+
+    impl_color_lerp_angular!(Okhsv<T> {hue, saturation, value});
+}
+
+impl<T, A> Bounded for Okhsv<T, A>
+    where T: PosNormalChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_bounded!(Okhsv {hue, saturation, value});
+}
+
+impl<T, A> color::Flatten for Okhsv<T, A>
+    where T: PosNormalChannelScalar + num::Float,
+          A: AngularChannelScalar + Angle<Scalar = T> + FromAngle<angle::Turns<T>>
+{
+    type ScalarFormat = T;
+
+    impl_color_as_slice!(T);
+    impl_color_from_slice_angular!(Okhsv<T, A> {hue:AngularChannel - 0, 
+        saturation:PosNormalBoundedChannel - 1, value:PosNormalBoundedChannel - 2});
+}
+
+impl<T, A> approx::ApproxEq for Okhsv<T, A>
+    where T: PosNormalChannelScalar + approx::ApproxEq<Epsilon = A::Epsilon>,
+          A: AngularChannelScalar + approx::ApproxEq,
+          A::Epsilon: Clone + num::Float
+{
+    impl_approx_eq!({hue, saturation, value});
+}
+
+impl<T, A> Default for Okhsv<T, A>
+    where T: PosNormalChannelScalar + num::Zero,
+          A: AngularChannelScalar + num::Zero
+{
+    impl_color_default!(Okhsv {hue: AngularChannel, 
+        saturation: PosNormalBoundedChannel, value: PosNormalBoundedChannel});
+}
+
+impl<T, A> fmt::Display for Okhsv<T, A>
+    where T: PosNormalChannelScalar + fmt::Display,
+          A: AngularChannelScalar + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Okhsv({}, {}, {})", self.hue, self.saturation, self.value)
+    }
+}
+
+impl<T, A> convert::GetHue for Okhsv<T, A>
+    where T: PosNormalChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_get_hue_angular!(Okhsv);
+}
+
+impl<T, A> FromColor<Oklab<T>> for Okhsv<T, A>
+    where T: PosNormalChannelScalar + FreeChannelScalar,
+          A: AngularChannelScalar + FromAngle<Rad<T>> + Angle
+{
+    fn from_color(from: &Oklab<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let half: T = num::cast(0.5).unwrap();
+        let hue_angle = Rad::atan2(from.b(), from.a());
+        let hue = <A as Angle>::normalize(A::from_angle(hue_angle));
+        if from.L() <= zero {
+            return Okhsv::from_channels(hue, zero, zero);
+        }
+
+        let chroma = from.a().hypot(from.b());
+        let (a_, b_) = (hue_angle.cos(), hue_angle.sin());
+        let (cusp_l, cusp_c) = Oklab::srgb_cusp(a_, b_);
+        let (s_max, t_max) = (cusp_c / cusp_l, cusp_c / (one - cusp_l));
+        let k = one - half / s_max;
+
+        // Project onto the triangle between black, white and the cusp, then undo the toe.
+        let t = t_max / (chroma + from.L() * t_max);
+        let l_v = t * from.L();
+        let c_v = t * chroma;
+        let scale = srgb_scale(a_, b_, l_v, c_v);
+
+        let lightness = from.L() / scale;
+        let value = Oklab::toe(lightness) / l_v;
+        let saturation = (half + t_max) * c_v / (t_max * half + t_max * k * c_v);
+        Okhsv::from_channels(hue, saturation, value)
+    }
+}
+
+impl<T, A> FromColor<Okhsv<T, A>> for Oklab<T>
+    where T: PosNormalChannelScalar + FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn from_color(from: &Okhsv<T, A>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let half: T = num::cast(0.5).unwrap();
+        if from.value() <= zero {
+            return Oklab::from_channels(zero, zero, zero);
+        }
+
+        let (a_, b_) = (from.hue().cos(), from.hue().sin());
+        let (cusp_l, cusp_c) = Oklab::srgb_cusp(a_, b_);
+        let (s_max, t_max) = (cusp_c / cusp_l, cusp_c / (one - cusp_l));
+        let k = one - half / s_max;
+
+        let s = from.saturation();
+        let denominator = half + t_max - t_max * k * s;
+        let l_v = one - s * half / denominator;
+        let c_v = s * t_max * half / denominator;
+
+        let lightness = from.value() * l_v;
+        let toe_lightness = Oklab::toe_inv(lightness);
+        let chroma = from.value() * c_v * toe_lightness / lightness;
+        let scale = srgb_scale(a_, b_, l_v, c_v);
+
+        Oklab::from_channels(toe_lightness * scale,
+                             chroma * scale * a_,
+                             chroma * scale * b_)
+    }
+}
+
+// How much a color on the triangle's value = 1 edge must be scaled to land on the curved
+// sRGB boundary.
+fn srgb_scale<T>(a_: T, b_: T, l_v: T, c_v: T) -> T
+    where T: FreeChannelScalar
+{
+    let l_vt = Oklab::toe_inv(l_v);
+    let c_vt = c_v * l_vt / l_v;
+    let (r, g, b) = Oklab::from_channels(l_vt, a_ * c_vt, b_ * c_vt).linear_srgb_channels();
+    (T::one() / r.max(g).max(b).max(T::zero())).cbrt()
+}
+
+impl<T, A> FromColor<EncodedColor<Rgb<T>, SrgbEncoding>> for Okhsv<T, A>
+    where T: PosNormalChannelScalar + FreeChannelScalar,
+          A: AngularChannelScalar + FromAngle<Rad<T>> + Angle,
+          Rgb<T>: EncodableColor
+{
+    fn from_color(from: &EncodedColor<Rgb<T>, SrgbEncoding>) -> Self {
+        Okhsv::from_color(&Oklab::from_color(&from.clone().decode()))
+    }
+}
+
+impl<T, A> FromColor<Okhsv<T, A>> for EncodedColor<Rgb<T>, SrgbEncoding>
+    where T: PosNormalChannelScalar + FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>,
+          Rgb<T>: EncodableColor
+{
+    fn from_color(from: &Okhsv<T, A>) -> Self {
+        LinearColor::from_color(&Oklab::from_color(from)).encode(SrgbEncoding::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use angle::*;
+    use color::Flatten;
+    use encoding::{EncodedColor, SrgbEncoding};
+    use oklab::Oklab;
+    use rgb::Rgb;
+
+    fn srgb(r: f64, g: f64, b: f64) -> EncodedColor<Rgb<f64>, SrgbEncoding> {
+        EncodedColor::new(Rgb::from_channels(r, g, b), SrgbEncoding::new())
+    }
+
+    #[test]
+    fn test_construct() {
+        let c1 = Okhsv::from_channels(Deg(50.0), 0.5, 0.3);
+        assert_ulps_eq!(c1.hue(), Deg(50.0));
+        assert_ulps_eq!(c1.saturation(), 0.5);
+        assert_ulps_eq!(c1.value(), 0.3);
+
+        let mut c2 = Okhsv::from_channels(Turns(0.9), 0.5, 0.75);
+        c2.set_saturation(0.33);
+        assert_ulps_eq!(c2, Okhsv::from_channels(Turns(0.9), 0.33, 0.75));
+
+        let c3 = Okhsv::from_tuple((Deg(50.0), 0.33, 0.66));
+        assert_eq!(c3.to_tuple(), (Deg(50.0), 0.33, 0.66));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let c1 = Okhsv::from_channels(Deg(320.0), 0.0, 1.0);
+        let c2 = Okhsv::from_channels(Deg(100.0), 1.0, 0.0);
+        assert_ulps_eq!(c1.lerp(&c2, 0.0), c1);
+        assert_ulps_eq!(c1.lerp(&c2, 1.0).normalize(), c2);
+        assert_ulps_eq!(c1.lerp(&c2, 0.5).normalize(), Okhsv::from_channels(Deg(30.0), 0.5, 0.5));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let c1 = Okhsv::from_channels(Deg(-120.0), 0.25, 0.75);
+        assert!(!c1.is_normalized());
+        assert_ulps_eq!(c1.normalize(), Okhsv::from_channels(Deg(240.0), 0.25, 0.75));
+
+        let c2 = Okhsv::from_channels(Turns(11.25), -1.11, 1.11);
+        assert_ulps_eq!(c2.normalize(), Okhsv::from_channels(Turns(0.25), 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_flatten() {
+        let c1 = Okhsv::from_channels(Turns(0.5), 0.3, 0.2);
+        assert_eq!(c1.as_slice(), &[0.5, 0.3, 0.2]);
+        assert_eq!(c1, Okhsv::from_slice(c1.as_slice()));
+    }
+
+    #[test]
+    fn test_from_srgb() {
+        let red: Okhsv<f64> = Okhsv::from_color(&srgb(1.0, 0.0, 0.0));
+        assert_relative_eq!(red, Okhsv::from_channels(Deg(29.233885), 0.999522, 1.0),
+            epsilon=1e-6);
+        let blue: Okhsv<f64> = Okhsv::from_color(&srgb(0.0, 0.0, 1.0));
+        assert_relative_eq!(blue, Okhsv::from_channels(Deg(264.052021), 0.999991, 1.0),
+            epsilon=1e-6);
+        let c1: Okhsv<f64> = Okhsv::from_color(&srgb(0.5, 0.25, 0.75));
+        assert_relative_eq!(c1, Okhsv::from_channels(Deg(303.194798), 0.816566, 0.758461),
+            epsilon=1e-6);
+        let c2: Okhsv<f64> = Okhsv::from_color(&srgb(0.2, 0.6, 0.4));
+        assert_relative_eq!(c2, Okhsv::from_channels(Deg(157.557151), 0.771557, 0.626342),
+            epsilon=1e-6);
+
+        let gray: Okhsv<f64> = Okhsv::from_color(&srgb(0.5, 0.5, 0.5));
+        assert_relative_eq!(gray.saturation(), 0.0, epsilon=1e-6);
+        assert_relative_eq!(gray.value(), 0.533760, epsilon=1e-6);
+        let white: Okhsv<f64> = Okhsv::from_color(&srgb(1.0, 1.0, 1.0));
+        assert_relative_eq!(white.saturation(), 0.0, epsilon=1e-6);
+        assert_relative_eq!(white.value(), 1.0, epsilon=1e-6);
+        let black: Okhsv<f64> = Okhsv::from_color(&srgb(0.0, 0.0, 0.0));
+        assert_relative_eq!(black, Okhsv::from_channels(Deg(0.0), 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_to_srgb() {
+        let c1 = EncodedColor::from_color(&Okhsv::from_channels(Deg(0.0), 0.5, 0.5));
+        assert_relative_eq!(c1, srgb(0.479537, 0.264323, 0.333765), epsilon=1e-6);
+        let c2 = EncodedColor::from_color(&Okhsv::from_channels(Deg(90.0), 1.0, 1.0));
+        assert_relative_eq!(c2, srgb(1.0, 0.796807, 0.0), epsilon=1e-6);
+        let c3 = EncodedColor::from_color(&Okhsv::from_channels(Deg(0.0), 1.0, 0.0));
+        assert_relative_eq!(c3, srgb(0.0, 0.0, 0.0));
+
+        for &color in &[(0.5, 0.25, 0.75), (0.2, 0.6, 0.4), (1.0, 1.0, 0.0), (0.1, 0.1, 0.1)] {
+            let c = srgb(color.0, color.1, color.2);
+            let okhsv: Okhsv<f64> = Okhsv::from_color(&c);
+            assert_relative_eq!(EncodedColor::from_color(&okhsv), c, epsilon=1e-5);
+        }
+    }
+
+    #[test]
+    fn test_gamut() {
+        for hue in (0..12).map(|i| Deg(f64::from(i) * 30.0)) {
+            let rgb = EncodedColor::from_color(&Okhsv::from_channels(hue, 1.0, 1.0));
+            let rgb = rgb.color();
+            let max = rgb.red().max(rgb.green()).max(rgb.blue());
+            let min = rgb.red().min(rgb.green()).min(rgb.blue());
+            assert_relative_eq!(max, 1.0, epsilon=1e-6);
+            assert!(min.abs() < 5e-3);
+        }
+    }
+
+    #[test]
+    fn test_oklab_round_trip() {
+        let c1 = Oklab::from_channels(0.6, 0.1, -0.05);
+        let t1: Okhsv<f64> = Okhsv::from_color(&c1);
+        assert_relative_eq!(Oklab::from_color(&t1), c1, epsilon=1e-9);
+
+        let c2 = Okhsv::from_channels(Deg(200.0), 0.3, 0.8);
+        let t2 = Oklab::from_color(&c2);
+        assert_relative_eq!(t2, Oklab::from_channels(0.779209, -0.059135, -0.021523),
+            epsilon=1e-6);
+        assert_relative_eq!(Okhsv::from_color(&t2), c2, epsilon=1e-9);
+    }
+
+    #[test]
+    fn test_color_cast() {
+        let c1 = Okhsv::from_channels(Deg(120.0), 0.25, 0.5);
+        assert_relative_eq!(c1.color_cast(), c1);
+        assert_relative_eq!(c1.color_cast::<f32, Turns<f32>>().color_cast(), c1, epsilon=1e-6);
+    }
+}
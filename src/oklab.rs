@@ -0,0 +1,497 @@
+#![allow(non_snake_case)]
+use std::slice;
+use std::mem;
+use std::fmt;
+use num;
+use approx;
+use channel::{PosFreeChannel, FreeChannel, FreeChannelScalar, PosNormalChannelScalar,
+              ChannelFormatCast, ChannelCast, ColorChannel};
+use color::{Color, Bounded, Lerp, Flatten, FromTuple};
+use convert::FromColor;
+use encoding::{EncodableColor, LinearColor, LinearEncoding};
+use linalg::Matrix3;
+use rgb::Rgb;
+use xyz::Xyz;
+
+pub struct OklabTag;
+
+/// Björn Ottosson's perceptual Oklab space, defined relative to a D65 white.
+///
+/// `L` is 1 for the white point, while `a` and `b` stay within roughly [-0.4, 0.4] for
+/// colors inside the common RGB gamuts.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Oklab<T> {
+    pub L: PosFreeChannel<T>,
+    pub a: FreeChannel<T>,
+    pub b: FreeChannel<T>,
+}
+
+impl<T> Oklab<T>
+    where T: FreeChannelScalar
+{
+    pub fn from_channels(L: T, a: T, b: T) -> Self {
+        Oklab {
+            L: PosFreeChannel::new(L),
+            a: FreeChannel::new(a),
+            b: FreeChannel::new(b),
+        }
+    }
+
+    impl_color_color_cast_square!(Oklab {L, a, b}, chan_traits={FreeChannelScalar});
+
+    pub fn L(&self) -> T {
+        self.L.0
+    }
+    pub fn a(&self) -> T {
+        self.a.0
+    }
+    pub fn b(&self) -> T {
+        self.b.0
+    }
+    pub fn L_mut(&mut self) -> &mut T {
+        &mut self.L.0
+    }
+    pub fn a_mut(&mut self) -> &mut T {
+        &mut self.a.0
+    }
+    pub fn b_mut(&mut self) -> &mut T {
+        &mut self.b.0
+    }
+    pub fn set_L(&mut self, val: T) {
+        self.L.0 = val;
+    }
+    pub fn set_a(&mut self, val: T) {
+        self.a.0 = val;
+    }
+    pub fn set_b(&mut self, val: T) {
+        self.b.0 = val;
+    }
+}
+
+impl<T> Color for Oklab<T>
+    where T: FreeChannelScalar
+{
+    type Tag = OklabTag;
+    type ChannelsTuple = (T, T, T);
+
+    #[inline]
+    fn num_channels() -> u32 {
+        3
+    }
+    fn to_tuple(self) -> Self::ChannelsTuple {
+        (self.L.0, self.a.0, self.b.0)
+    }
+}
+
+impl<T> FromTuple for Oklab<T>
+    where T: FreeChannelScalar
+{
+    fn from_tuple(values: (T, T, T)) -> Self {
+        Oklab::from_channels(values.0, values.1, values.2)
+    }
+}
+
+impl<T> Bounded for Oklab<T>
+    where T: FreeChannelScalar
+{
+    fn normalize(self) -> Self {
+        Oklab::from_channels(self.L.normalize().0, self.a(), self.b())
+    }
+    fn is_normalized(&self) -> bool {
+        self.L.is_normalized()
+    }
+}
+
+impl<T> Lerp for Oklab<T>
+    where T: FreeChannelScalar + Lerp
+{
+    type Position = <FreeChannel<T> as Lerp>::Position;
+    impl_color_lerp_square!(Oklab {L, a, b});
+}
+
+impl<T> Flatten for Oklab<T>
+    where T: FreeChannelScalar
+{
+    type ScalarFormat = T;
+
+    impl_color_as_slice!(T);
+    impl_color_from_slice_square!(Oklab<T> {L:PosFreeChannel - 0, a:FreeChannel - 1,
+        b:FreeChannel - 2});
+}
+
+impl<T> approx::ApproxEq for Oklab<T>
+    where T: FreeChannelScalar + approx::ApproxEq,
+          T::Epsilon: Clone
+{
+    impl_approx_eq!({L, a, b});
+}
+
+impl<T> Default for Oklab<T>
+    where T: FreeChannelScalar
+{
+    impl_color_default!(Oklab {L:PosFreeChannel, a:FreeChannel, b:FreeChannel});
+}
+
+impl<T> fmt::Display for Oklab<T>
+    where T: FreeChannelScalar + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Oklab({}, {}, {})", self.L, self.a, self.b)
+    }
+}
+
+impl<T> Oklab<T>
+    where T: FreeChannelScalar
+{
+    /// Build an Oklab color from cone responses that have not yet been compressed.
+    pub fn from_lms(lms: (T, T, T)) -> Self {
+        let (L, a, b) = Self::lab_from_lms().transform_vector((lms.0.cbrt(),
+                                                              lms.1.cbrt(),
+                                                              lms.2.cbrt()));
+        Oklab::from_channels(L, a, b)
+    }
+
+    /// The linear cone responses of the color, before the cube root compression.
+    pub fn to_lms(&self) -> (T, T, T) {
+        let (l, m, s) = Self::lms_from_lab().transform_vector(self.to_tuple());
+        (l * l * l, m * m * m, s * s * s)
+    }
+
+    /// The unclamped linear sRGB channels of the color, which fall outside [0, 1] when the
+    /// color is out of gamut.
+    pub fn linear_srgb_channels(&self) -> (T, T, T) {
+        Self::srgb_from_lms().transform_vector(self.to_lms())
+    }
+
+    fn lab_from_lms() -> Matrix3<T> {
+        Matrix3::new([num::cast(0.2104542553).unwrap(),
+                      num::cast(0.7936177850).unwrap(),
+                      num::cast(-0.0040720468).unwrap(),
+                      num::cast(1.9779984951).unwrap(),
+                      num::cast(-2.4285922050).unwrap(),
+                      num::cast(0.4505937099).unwrap(),
+                      num::cast(0.0259040371).unwrap(),
+                      num::cast(0.7827717662).unwrap(),
+                      num::cast(-0.8086757660).unwrap()])
+    }
+    fn lms_from_lab() -> Matrix3<T> {
+        Matrix3::new([num::cast(1.0).unwrap(),
+                      num::cast(0.3963377774).unwrap(),
+                      num::cast(0.2158037573).unwrap(),
+                      num::cast(1.0).unwrap(),
+                      num::cast(-0.1055613458).unwrap(),
+                      num::cast(-0.0638541728).unwrap(),
+                      num::cast(1.0).unwrap(),
+                      num::cast(-0.0894841775).unwrap(),
+                      num::cast(-1.2914855480).unwrap()])
+    }
+    fn lms_from_xyz() -> Matrix3<T> {
+        Matrix3::new([num::cast(0.8189330101).unwrap(),
+                      num::cast(0.3618667424).unwrap(),
+                      num::cast(-0.1288597137).unwrap(),
+                      num::cast(0.0329845436).unwrap(),
+                      num::cast(0.9293118715).unwrap(),
+                      num::cast(0.0361456387).unwrap(),
+                      num::cast(0.0482003018).unwrap(),
+                      num::cast(0.2643662691).unwrap(),
+                      num::cast(0.6338517070).unwrap()])
+    }
+    fn xyz_from_lms() -> Matrix3<T> {
+        Matrix3::new([num::cast(1.2270138511).unwrap(),
+                      num::cast(-0.5577999807).unwrap(),
+                      num::cast(0.2812561490).unwrap(),
+                      num::cast(-0.0405801784).unwrap(),
+                      num::cast(1.1122568696).unwrap(),
+                      num::cast(-0.0716766787).unwrap(),
+                      num::cast(-0.0763812845).unwrap(),
+                      num::cast(-0.4214819784).unwrap(),
+                      num::cast(1.5861632204).unwrap()])
+    }
+    fn lms_from_srgb() -> Matrix3<T> {
+        Matrix3::new([num::cast(0.4122214708).unwrap(),
+                      num::cast(0.5363325363).unwrap(),
+                      num::cast(0.0514459929).unwrap(),
+                      num::cast(0.2119034982).unwrap(),
+                      num::cast(0.6806995451).unwrap(),
+                      num::cast(0.1073969566).unwrap(),
+                      num::cast(0.0883024619).unwrap(),
+                      num::cast(0.2817188376).unwrap(),
+                      num::cast(0.6299787005).unwrap()])
+    }
+    fn srgb_from_lms() -> Matrix3<T> {
+        Matrix3::new([num::cast(4.0767416621).unwrap(),
+                      num::cast(-3.3077115913).unwrap(),
+                      num::cast(0.2309699292).unwrap(),
+                      num::cast(-1.2684380046).unwrap(),
+                      num::cast(2.6097574011).unwrap(),
+                      num::cast(-0.3413193965).unwrap(),
+                      num::cast(-0.0041960863).unwrap(),
+                      num::cast(-0.7034186147).unwrap(),
+                      num::cast(1.7076147010).unwrap()])
+    }
+}
+
+// The sRGB gamut in Oklab, after Björn Ottosson's "sRGB gamut clipping". Hue directions are
+// given as `(a, b)` with `a * a + b * b == 1`, and `cusp` is the `(L, C)` of the most
+// saturated color along the direction.
+impl<T> Oklab<T>
+    where T: FreeChannelScalar
+{
+    /// The largest saturation `C / L` along a hue direction that stays within sRGB.
+    pub fn srgb_max_saturation(a: T, b: T) -> T {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        let one = T::one();
+
+        // Pick the channel that clips first, with a polynomial fit of its saturation limit.
+        let (k, weights) = if c(-1.88170328) * a - c(0.80936493) * b > one {
+            ([1.19086277, 1.76576728, 0.59662641, 0.75515197, 0.56771245], 0)
+        } else if c(1.81444104) * a - c(1.19445276) * b > one {
+            ([0.73956515, -0.45954404, 0.08285427, 0.12541070, 0.14503204], 1)
+        } else {
+            ([1.35733652, -0.00915799, -1.15130210, -0.50559606, 0.00692167], 2)
+        };
+        let w = Self::srgb_from_lms();
+        let (w_l, w_m, w_s) = (w.as_slice()[3 * weights],
+                               w.as_slice()[3 * weights + 1],
+                               w.as_slice()[3 * weights + 2]);
+        let saturation = c(k[0]) + c(k[1]) * a + c(k[2]) * b + c(k[3]) * a * a + c(k[4]) * a * b;
+
+        // Refine the fit with one step of Halley's method on the clipping channel.
+        let (k_l, k_m, k_s) = Self::lms_slopes(a, b);
+        let l_ = one + saturation * k_l;
+        let m_ = one + saturation * k_m;
+        let s_ = one + saturation * k_s;
+
+        let f = w_l * l_.powi(3) + w_m * m_.powi(3) + w_s * s_.powi(3);
+        let f1 = c(3.0) * (w_l * k_l * l_ * l_ + w_m * k_m * m_ * m_ + w_s * k_s * s_ * s_);
+        let f2 = c(6.0) * (w_l * k_l * k_l * l_ + w_m * k_m * k_m * m_ + w_s * k_s * k_s * s_);
+
+        saturation - f * f1 / (f1 * f1 - c(0.5) * f * f2)
+    }
+
+    /// The `(L, C)` of the most saturated sRGB color along a hue direction.
+    pub fn srgb_cusp(a: T, b: T) -> (T, T) {
+        let saturation = Self::srgb_max_saturation(a, b);
+        let (r, g, b) = Oklab::from_channels(T::one(), saturation * a, saturation * b)
+            .linear_srgb_channels();
+        let lightness = (T::one() / r.max(g).max(b)).cbrt();
+        (lightness, lightness * saturation)
+    }
+
+    /// Where the line from `(l0, 0)` to `(l1, c1)` leaves the sRGB gamut, as the fraction
+    /// `t` of the way along it.
+    pub fn srgb_gamut_intersection(a: T, b: T, l1: T, c1: T, l0: T, cusp: (T, T)) -> T {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        let one = T::one();
+        let (cusp_l, cusp_c) = cusp;
+
+        if (l1 - l0) * cusp_c - (cusp_l - l0) * c1 <= T::zero() {
+            // Below the cusp the gamut boundary is the straight line to black.
+            return cusp_c * l0 / (c1 * cusp_l + cusp_c * (l0 - l1));
+        }
+
+        // Above the cusp, intersect with the line to white and refine the result with one
+        // step of Halley's method against each of the channels reaching 1.
+        let t = cusp_c * (l0 - one) / (c1 * (cusp_l - one) + cusp_c * (l0 - l1));
+        let (k_l, k_m, k_s) = Self::lms_slopes(a, b);
+        let l_dt = l1 - l0 + c1 * k_l;
+        let m_dt = l1 - l0 + c1 * k_m;
+        let s_dt = l1 - l0 + c1 * k_s;
+
+        let lightness = l0 * (one - t) + t * l1;
+        let chroma = t * c1;
+        let l_ = lightness + chroma * k_l;
+        let m_ = lightness + chroma * k_m;
+        let s_ = lightness + chroma * k_s;
+
+        let lms = (l_.powi(3), m_.powi(3), s_.powi(3));
+        let lms_dt = (c(3.0) * l_dt * l_ * l_, c(3.0) * m_dt * m_ * m_, c(3.0) * s_dt * s_ * s_);
+        let lms_dt2 = (c(6.0) * l_dt * l_dt * l_, c(6.0) * m_dt * m_dt * m_,
+                       c(6.0) * s_dt * s_dt * s_);
+
+        let to_rgb = Self::srgb_from_lms();
+        let (r, g, b) = to_rgb.transform_vector(lms);
+        let (r1, g1, b1) = to_rgb.transform_vector(lms_dt);
+        let (r2, g2, b2) = to_rgb.transform_vector(lms_dt2);
+        let step = |f: T, f1: T, f2: T| {
+            let f = f - one;
+            let u = f1 / (f1 * f1 - c(0.5) * f * f2);
+            if u >= T::zero() { -f * u } else { T::max_value() }
+        };
+
+        t + step(r, r1, r2).min(step(g, g1, g2)).min(step(b, b1, b2))
+    }
+
+    /// The lightness estimate `Lr` used by Okhsv and Okhsl, which matches CIE L* more
+    /// closely than Oklab's `L` does for dark colors.
+    pub fn toe(lightness: T) -> T {
+        let (k1, k2, k3) = Self::toe_constants();
+        let x = k3 * lightness - k1;
+        let four: T = num::cast(4.0).unwrap();
+        (x + (x * x + four * k2 * k3 * lightness).sqrt()) / (T::one() + T::one())
+    }
+
+    /// The inverse of `toe`.
+    pub fn toe_inv(lightness: T) -> T {
+        let (k1, k2, k3) = Self::toe_constants();
+        (lightness * lightness + k1 * lightness) / (k3 * (lightness + k2))
+    }
+
+    fn toe_constants() -> (T, T, T) {
+        let k1: T = num::cast(0.206).unwrap();
+        let k2: T = num::cast(0.03).unwrap();
+        (k1, k2, (T::one() + k1) / (T::one() + k2))
+    }
+
+    // How each nonlinear cone response changes with chroma along a hue direction.
+    fn lms_slopes(a: T, b: T) -> (T, T, T) {
+        let m = Self::lms_from_lab().to_tuple();
+        (m.1 * a + m.2 * b, m.4 * a + m.5 * b, m.7 * a + m.8 * b)
+    }
+}
+
+impl<T> FromColor<Xyz<T>> for Oklab<T>
+    where T: FreeChannelScalar
+{
+    fn from_color(from: &Xyz<T>) -> Self {
+        Oklab::from_lms(Self::lms_from_xyz().transform_vector(from.to_tuple()))
+    }
+}
+
+impl<T> FromColor<Oklab<T>> for Xyz<T>
+    where T: FreeChannelScalar
+{
+    fn from_color(from: &Oklab<T>) -> Self {
+        let (x, y, z) = Oklab::<T>::xyz_from_lms().transform_vector(from.to_lms());
+        Xyz::from_channels(x, y, z)
+    }
+}
+
+impl<T> FromColor<LinearColor<Rgb<T>>> for Oklab<T>
+    where T: FreeChannelScalar + PosNormalChannelScalar,
+          Rgb<T>: EncodableColor
+{
+    fn from_color(from: &LinearColor<Rgb<T>>) -> Self {
+        let rgb = from.color();
+        let lms = Self::lms_from_srgb().transform_vector((rgb.red(), rgb.green(), rgb.blue()));
+        Oklab::from_lms(lms)
+    }
+}
+
+impl<T> FromColor<Oklab<T>> for LinearColor<Rgb<T>>
+    where T: FreeChannelScalar + PosNormalChannelScalar,
+          Rgb<T>: EncodableColor
+{
+    fn from_color(from: &Oklab<T>) -> Self {
+        let (r, g, b) = from.linear_srgb_channels();
+        LinearColor::new(Rgb::from_channels(r, g, b), LinearEncoding::new())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use encoding::{EncodedColor, LinearColor, LinearEncoding, SrgbEncoding};
+    use rgb::Rgb;
+    use white_point::*;
+    use xyz::Xyz;
+
+    #[test]
+    fn test_construct() {
+        let c1 = Oklab::from_channels(0.82, -0.12, 0.077);
+        assert_relative_eq!(c1.L(), 0.82);
+        assert_relative_eq!(c1.a(), -0.12);
+        assert_relative_eq!(c1.b(), 0.077);
+        assert_eq!(c1.to_tuple(), (0.82, -0.12, 0.077));
+        assert_relative_eq!(Oklab::from_tuple(c1.to_tuple()), c1);
+
+        let mut c2 = Oklab::from_channels(0.0, 0.0, 0.0);
+        c2.set_L(0.5);
+        *c2.b_mut() = -0.25;
+        assert_eq!(c2.to_tuple(), (0.5, 0.0, -0.25));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let c1 = Oklab::from_channels(0.5, 0.1, 0.3);
+        let c2 = Oklab::from_channels(1.0, -0.1, 0.1);
+        assert_relative_eq!(c1.lerp(&c2, 0.0), c1);
+        assert_relative_eq!(c1.lerp(&c2, 1.0), c2);
+        assert_relative_eq!(c1.lerp(&c2, 0.5), Oklab::from_channels(0.75, 0.0, 0.2));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let c1 = Oklab::from_channels(0.7, -0.5, 0.5);
+        assert!(c1.is_normalized());
+        assert_relative_eq!(c1.normalize(), c1);
+        let c2 = Oklab::from_channels(-0.25, 0.1, 0.0);
+        assert!(!c2.is_normalized());
+        assert_relative_eq!(c2.normalize(), Oklab::from_channels(0.0, 0.1, 0.0));
+    }
+
+    #[test]
+    fn test_flatten() {
+        let c1 = Oklab::from_channels(0.5, 0.2, -0.15);
+        assert_eq!(c1.as_slice(), &[0.5, 0.2, -0.15]);
+        assert_relative_eq!(Oklab::from_slice(c1.as_slice()), c1);
+    }
+
+    #[test]
+    fn test_from_xyz() {
+        let c1 = Oklab::from_color(&D65::get_xyz());
+        assert_relative_eq!(c1, Oklab::from_channels(1.0, 0.0, 0.0), epsilon=1e-4);
+        assert_relative_eq!(Xyz::from_color(&c1), D65::get_xyz(), epsilon=1e-6);
+
+        let c2 = Oklab::from_color(&Xyz::from_channels(1.0, 0.0, 0.0));
+        assert_relative_eq!(c2, Oklab::from_channels(0.449932, 1.235710, -0.019028),
+            epsilon=1e-6);
+        let c3 = Oklab::from_color(&Xyz::from_channels(0.0, 1.0, 0.0));
+        assert_relative_eq!(c3, Oklab::from_channels(0.921817, -0.671238, 0.263324),
+            epsilon=1e-6);
+        let c4 = Oklab::from_color(&Xyz::from_channels(0.0, 0.0, 1.0));
+        assert_relative_eq!(c4, Oklab::from_channels(0.152603, -1.414997, -0.448927),
+            epsilon=1e-6);
+
+        let c5 = Xyz::from_channels(0.3, 0.22, 0.5);
+        let t5 = Oklab::from_color(&c5);
+        assert_relative_eq!(t5, Oklab::from_channels(0.619438, 0.099759, -0.092777),
+            epsilon=1e-6);
+        assert_relative_eq!(Xyz::from_color(&t5), c5, epsilon=1e-6);
+    }
+
+    #[test]
+    fn test_from_linear_srgb() {
+        let white = LinearColor::new(Rgb::from_channels(1.0, 1.0, 1.0), LinearEncoding::new());
+        assert_relative_eq!(Oklab::from_color(&white), Oklab::from_channels(1.0, 0.0, 0.0),
+            epsilon=1e-6);
+
+        let red = LinearColor::new(Rgb::from_channels(1.0, 0.0, 0.0), LinearEncoding::new());
+        let t1 = Oklab::from_color(&red);
+        assert_relative_eq!(t1, Oklab::from_channels(0.627955, 0.224863, 0.125846),
+            epsilon=1e-6);
+        assert_relative_eq!(LinearColor::from_color(&t1), red, epsilon=1e-6);
+
+        let c2 = LinearColor::new(Rgb::from_channels(0.2, 0.5, 0.8), LinearEncoding::new());
+        let t2 = Oklab::from_color(&c2);
+        assert_relative_eq!(t2, Oklab::from_channels(0.766887, -0.046271, -0.077996),
+            epsilon=1e-6);
+        assert_relative_eq!(LinearColor::from_color(&t2), c2, epsilon=1e-6);
+
+        let c3 = LinearColor::from_color(&Oklab::from_channels(0.7, 0.3, 0.1));
+        assert!(c3.color().red() > 1.0 && c3.color().green() < 0.0);
+
+        let c4 = EncodedColor::new(Rgb::from_channels(1.0, 1.0, 0.0), SrgbEncoding::new());
+        let t4 = Oklab::from_color(&c4.decode());
+        assert_relative_eq!(t4.L(), 0.967983, epsilon=1e-5);
+    }
+
+    #[test]
+    fn test_color_cast() {
+        let c1 = Oklab::from_channels(0.5, -0.125, 0.0625);
+        assert_relative_eq!(c1.color_cast(), c1);
+        assert_relative_eq!(c1.color_cast(), Oklab::from_channels(0.5f32, -0.125, 0.0625));
+        assert_relative_eq!(c1.color_cast::<f32>().color_cast(), c1);
+    }
+}
@@ -0,0 +1,344 @@
+#![allow(non_snake_case)]
+
+use std::fmt;
+use std::mem;
+use std::slice;
+use num;
+use approx;
+use angle::{Deg, Angle, FromAngle, IntoAngle, Turns, Rad};
+use angle;
+use channel::{PosFreeChannel, FreeChannelScalar, PosNormalChannelScalar, AngularChannel,
+              AngularChannelScalar, ChannelFormatCast, ChannelCast, ColorChannel};
+use color::{Color, PolarColor, FromTuple, Lerp, Bounded, Flatten};
+use convert::{GetChroma, GetHue, FromColor};
+use encoding::{EncodableColor, LinearColor};
+use oklab::Oklab;
+use rgb::Rgb;
+use xyz::Xyz;
+
+pub struct OklchTag;
+
+/// The polar form of `Oklab`, with lightness in [0, 1] and an unbounded chroma.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct Oklch<T, A = Deg<T>> {
+    pub L: PosFreeChannel<T>,
+    pub chroma: PosFreeChannel<T>,
+    pub hue: AngularChannel<A>,
+}
+
+impl<T, A> Oklch<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    pub fn from_channels(L: T, chroma: T, hue: A) -> Self {
+        Oklch {
+            L: PosFreeChannel::new(L),
+            chroma: PosFreeChannel::new(chroma),
+            hue: AngularChannel::new(hue),
+        }
+    }
+
+    impl_color_color_cast_angular!(Oklch {L, chroma, hue}, 
+        chan_traits={FreeChannelScalar});
+
+    pub fn L(&self) -> T {
+        self.L.0
+    }
+    pub fn chroma(&self) -> T {
+        self.chroma.0
+    }
+    pub fn hue(&self) -> A {
+        self.hue.0.clone()
+    }
+    pub fn L_mut(&mut self) -> &mut T {
+        &mut self.L.0
+    }
+    pub fn chroma_mut(&mut self) -> &mut T {
+        &mut self.chroma.0
+    }
+    pub fn hue_mut(&mut self) -> &mut A {
+        &mut self.hue.0
+    }
+    pub fn set_L(&mut self, val: T) {
+        self.L.0 = val;
+    }
+    pub fn set_chroma(&mut self, val: T) {
+        self.chroma.0 = val;
+    }
+    pub fn set_hue(&mut self, val: A) {
+        self.hue.0 = val;
+    }
+}
+
+impl<T, A> Color for Oklch<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type Tag = OklchTag;
+    type ChannelsTuple = (T, T, A);
+
+    fn num_channels() -> u32 {
+        3
+    }
+    fn to_tuple(self) -> Self::ChannelsTuple {
+        (self.L.0, self.chroma.0, self.hue.0)
+    }
+}
+
+impl<T, A> PolarColor for Oklch<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type Angular = A;
+    type Cartesian = T;
+}
+
+impl<T, A> FromTuple for Oklch<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    fn from_tuple(values: Self::ChannelsTuple) -> Self {
+        Oklch::from_channels(values.0, values.1, values.2)
+    }
+}
+
+impl<T, A> Lerp for Oklch<T, A>
+    where T: FreeChannelScalar + Lerp,
+          A: AngularChannelScalar + Lerp
+{
+    type Position = A::Position;
+
+    impl_color_lerp_angular!(Oklch<T> {hue, L, chroma});
+}
+
+impl<T, A> Bounded for Oklch<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_bounded!(Oklch {L, chroma, hue});
+}
+
+impl<T, A> Flatten for Oklch<T, A>
+    where T: FreeChannelScalar + num::Float,
+          A: AngularChannelScalar + Angle<Scalar = T> + FromAngle<Turns<T>>
+{
+    type ScalarFormat = T;
+
+    impl_color_as_slice!(T);
+
+    fn from_slice(vals: &[T]) -> Self {
+        Oklch::from_channels(vals[0],
+                             vals[1],
+                             A::from_angle(angle::Turns(vals[2])))
+    }
+}
+
+impl<T, A> approx::ApproxEq for Oklch<T, A>
+    where T: FreeChannelScalar + approx::ApproxEq<Epsilon = A::Epsilon>,
+          A: AngularChannelScalar + approx::ApproxEq,
+          A::Epsilon: Clone + num::Float
+{
+    impl_approx_eq!({L, chroma, hue});
+}
+
+impl<T, A> Default for Oklch<T, A>
+    where T: FreeChannelScalar + num::Zero,
+          A: AngularChannelScalar + num::Zero
+{
+    impl_color_default!(Oklch {hue: AngularChannel, 
+        L: PosFreeChannel, chroma: PosFreeChannel});
+}
+
+impl<T, A> fmt::Display for Oklch<T, A>
+    where T: FreeChannelScalar + fmt::Display,
+          A: AngularChannelScalar + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Oklch({}, {}, {})", self.L, self.chroma, self.hue)
+    }
+}
+
+impl<T, A> GetChroma for Oklch<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    type ChromaType = T;
+    fn get_chroma(&self) -> T {
+        self.chroma()
+    }
+}
+
+impl<T, A> GetHue for Oklch<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar
+{
+    impl_color_get_hue_angular!(Oklch);
+}
+
+impl<T, A> FromColor<Oklab<T>> for Oklch<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + FromAngle<Rad<T>> + Angle
+{
+    fn from_color(from: &Oklab<T>) -> Self {
+        let L = from.L();
+        let chroma = (from.a() * from.a() + from.b() * from.b()).sqrt();
+        let hue = A::from_angle(Rad::atan2(from.b(), from.a()));
+
+        Oklch::from_channels(L, chroma, <A as Angle>::normalize(hue))
+    }
+}
+
+impl<T, A> FromColor<Oklch<T, A>> for Oklab<T>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn from_color(from: &Oklch<T, A>) -> Self {
+        let L = from.L();
+        let a = from.chroma() * from.hue().cos();
+        let b = from.chroma() * from.hue().sin();
+
+        Oklab::from_channels(L, a, b)
+    }
+}
+
+impl<T, A> FromColor<Xyz<T>> for Oklch<T, A>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + FromAngle<Rad<T>> + Angle
+{
+    fn from_color(from: &Xyz<T>) -> Self {
+        Oklch::from_color(&Oklab::from_color(from))
+    }
+}
+
+impl<T, A> FromColor<Oklch<T, A>> for Xyz<T>
+    where T: FreeChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>
+{
+    fn from_color(from: &Oklch<T, A>) -> Self {
+        Xyz::from_color(&Oklab::from_color(from))
+    }
+}
+
+impl<T, A> FromColor<LinearColor<Rgb<T>>> for Oklch<T, A>
+    where T: FreeChannelScalar + PosNormalChannelScalar,
+          A: AngularChannelScalar + FromAngle<Rad<T>> + Angle,
+          Rgb<T>: EncodableColor
+{
+    fn from_color(from: &LinearColor<Rgb<T>>) -> Self {
+        Oklch::from_color(&Oklab::from_color(from))
+    }
+}
+
+impl<T, A> FromColor<Oklch<T, A>> for LinearColor<Rgb<T>>
+    where T: FreeChannelScalar + PosNormalChannelScalar,
+          A: AngularChannelScalar + Angle<Scalar = T>,
+          Rgb<T>: EncodableColor
+{
+    fn from_color(from: &Oklch<T, A>) -> Self {
+        LinearColor::from_color(&Oklab::from_color(from))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use oklab::Oklab;
+    use encoding::{LinearColor, LinearEncoding};
+    use rgb::Rgb;
+    use white_point::*;
+    use xyz::Xyz;
+
+    #[test]
+    fn test_construct() {
+        let c1 = Oklch::from_channels(0.55, 0.129, Deg(90.0));
+        assert_relative_eq!(c1.L(), 0.55);
+        assert_relative_eq!(c1.chroma(), 0.129);
+        assert_relative_eq!(c1.hue(), Deg(90.0));
+        assert_eq!(c1.to_tuple(), (0.55, 0.129, Deg(90.0)));
+        assert_relative_eq!(Oklch::from_tuple(c1.to_tuple()), c1);
+
+        let c2 = Oklch::from_channels(0.92, 0.05, Turns(0.5));
+        assert_relative_eq!(c2.hue(), Turns(0.5));
+        assert_relative_eq!(Oklch::from_tuple(c2.to_tuple()), c2);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let c1 = Oklch::from_channels(0.25, 0.3, Deg(300.0));
+        let c2 = Oklch::from_channels(0.75, 0.1, Deg(50.0));
+        assert_relative_eq!(c1.lerp(&c2, 0.0), c1);
+        assert_relative_eq!(c1.lerp(&c2, 1.0), c2);
+        assert_relative_eq!(c1.lerp(&c2, 0.5), Oklch::from_channels(0.5, 0.2, Deg(355.0)));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let c1 = Oklch::from_channels(0.5, 0.2, Deg(300.0));
+        assert!(c1.is_normalized());
+        assert_relative_eq!(c1.normalize(), c1);
+
+        let c2 = Oklch::from_channels(-0.1, -0.1, Deg(-60.0));
+        assert!(!c2.is_normalized());
+        assert_relative_eq!(c2.normalize(), Oklch::from_channels(0.0, 0.0, Deg(300.0)));
+    }
+
+    #[test]
+    fn test_flatten() {
+        let c1 = Oklch::from_channels(0.85, 0.11, Turns(0.5));
+        assert_eq!(c1.as_slice(), &[0.85, 0.11, 0.5]);
+        assert_relative_eq!(Oklch::from_slice(c1.as_slice()), c1);
+    }
+
+    #[test]
+    fn test_get_chroma_hue() {
+        let c1 = Oklch::from_channels(0.4, 0.15, Deg(180.0));
+        assert_eq!(c1.get_chroma(), 0.15);
+        assert_eq!(c1.get_hue::<Deg<_>>(), Deg(180.0));
+        assert_eq!(c1.get_hue::<Turns<_>>(), Turns(0.5));
+    }
+
+    #[test]
+    fn test_from_oklab() {
+        let c1 = Oklab::from_channels(0.5, 0.1, 0.1);
+        let t1 = Oklch::from_color(&c1);
+        assert_relative_eq!(t1, Oklch::from_channels(0.5, 0.141421, Deg(45.0)), epsilon=1e-6);
+        assert_relative_eq!(Oklab::from_color(&t1), c1, epsilon=1e-6);
+
+        let c2 = Oklab::from_channels(0.7, -0.2, 0.0);
+        let t2 = Oklch::from_color(&c2);
+        assert_relative_eq!(t2, Oklch::from_channels(0.7, 0.2, Deg(180.0)), epsilon=1e-6);
+        assert_relative_eq!(Oklab::from_color(&t2), c2, epsilon=1e-6);
+
+        let c3 = Oklch::from_channels(0.6, 0.25, Deg(330.0));
+        let t3 = Oklab::from_color(&c3);
+        assert_relative_eq!(t3, Oklab::from_channels(0.6, 0.216506, -0.125), epsilon=1e-6);
+        assert_relative_eq!(Oklch::from_color(&t3), c3, epsilon=1e-6);
+    }
+
+    #[test]
+    fn test_from_xyz_and_linear_srgb() {
+        let white = Oklch::<f64, Deg<f64>>::from_color(&D65::get_xyz());
+        assert_relative_eq!(white.L(), 1.0, epsilon=1e-4);
+        assert_relative_eq!(white.chroma(), 0.0, epsilon=1e-3);
+
+        let c1 = Xyz::from_channels(0.3, 0.22, 0.5);
+        let t1: Oklch<f64> = Oklch::from_color(&c1);
+        assert_relative_eq!(Xyz::from_color(&t1), c1, epsilon=1e-6);
+
+        let blue = LinearColor::new(Rgb::from_channels(0.0, 0.0, 1.0), LinearEncoding::new());
+        let t2 = Oklch::from_color(&blue);
+        assert_relative_eq!(t2, Oklch::from_channels(0.452014, 0.313214, Deg(264.052021)),
+            epsilon=1e-6);
+        assert_relative_eq!(LinearColor::from_color(&t2), blue, epsilon=1e-6);
+    }
+
+    #[test]
+    fn test_color_cast() {
+        let c1 = Oklch::from_channels(0.5f32, 0.25f32, Deg(120.0f32));
+        assert_relative_eq!(c1.color_cast(), c1);
+        assert_relative_eq!(c1.color_cast::<f32, Rad<f32>>().color_cast(), c1);
+        assert_relative_eq!(c1.color_cast(), Oklch::from_channels(0.5, 0.25, Turns(1.0 / 3.0)),
+            epsilon=1e-6);
+    }
+}
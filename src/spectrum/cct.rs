@@ -0,0 +1,306 @@
+//! Blackbody radiators and correlated color temperature.
+//!
+//! The correlated color temperature (CCT) of a chromaticity is the temperature of the
+//! blackbody whose chromaticity is nearest to it in the CIE 1960 uv diagram, and `Duv` is the
+//! signed distance to that point, positive above the Planckian locus. Both are defined for the
+//! CIE 1931 observer.
+
+use num;
+use channel::{FreeChannelScalar, PosNormalChannelScalar};
+use convert::FromColor;
+use xyy::XyY;
+use xyz::Xyz;
+use super::Spectrum;
+use super::observer::{StandardObserver, Cie1931};
+
+/// The second radiation constant, in nm·K.
+pub const SECOND_RADIATION_CONSTANT: f64 = 1.4388e7;
+
+/// The relative spectral power distribution of a blackbody at `temperature` in K.
+///
+/// The spectrum is sampled every 5 nm from 380 to 780 nm and normalized to 100 at 560 nm.
+pub fn blackbody<T: FreeChannelScalar>(temperature: T) -> Spectrum<T> {
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    let c2 = c(SECOND_RADIATION_CONSTANT);
+    let reference = c(560.0);
+    Spectrum::from_fn(c(380.0), c(5.0), 81, |wavelength| {
+        c(100.0) * (reference / wavelength).powi(5) * (c2 / (reference * temperature)).exp_m1() /
+        (c2 / (wavelength * temperature)).exp_m1()
+    })
+}
+
+/// The chromaticity of a blackbody at `temperature` in K, with a luminance `Y` of 1.
+pub fn planckian_chromaticity<T, O>(temperature: T) -> XyY<T>
+    where T: FreeChannelScalar + PosNormalChannelScalar,
+          O: StandardObserver
+{
+    XyY::from_color(&blackbody(temperature).white_point::<O>())
+}
+
+/// A correlated color temperature in K, with the distance `duv` from the Planckian locus.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cct<T> {
+    pub temperature: T,
+    pub duv: T,
+}
+
+impl<T> Cct<T>
+    where T: FreeChannelScalar
+{
+    pub fn new(temperature: T, duv: T) -> Self {
+        Cct { temperature, duv }
+    }
+}
+
+impl<T> Cct<T>
+    where T: FreeChannelScalar + PosNormalChannelScalar
+{
+    /// The chromaticity `duv` away from the Planckian locus at `temperature`, with a
+    /// luminance `Y` of 1.
+    ///
+    /// This inverts `cct_robertson` and `cct_ohno`, and with a `duv` of 0 gives the
+    /// chromaticity of a blackbody.
+    pub fn chromaticity(&self) -> XyY<T> {
+        let c = |val: f64| -> T { num::cast(val).unwrap() };
+        let (u0, v0) = planckian_uv(self.temperature);
+        let (u1, v1) = planckian_uv(self.temperature * c(1.0001));
+        let (du, dv) = (u1 - u0, v1 - v0);
+        let length = du.hypot(dv);
+        // The locus runs towards smaller v as temperature increases, so turning its
+        // direction a quarter turn clockwise points above it.
+        let u = u0 + self.duv * dv / length;
+        let v = v0 - self.duv * du / length;
+
+        let denom = c(2.0) + u - c(4.0) * v;
+        let x = c(1.5) * u / denom;
+        let y = v / denom;
+        XyY::from_channels(x, y, T::one())
+    }
+}
+
+/// Colors with a chromaticity that a correlated color temperature can be computed for.
+pub trait CorrelatedColorTemperature<T> {
+    /// The CCT by Robertson's method, interpolating between isotemperature lines.
+    ///
+    /// The method is fast but only covers 1667 K and up, and returns `None` for
+    /// chromaticities outside of its table.
+    fn cct_robertson(&self) -> Option<Cct<T>>;
+
+    /// The CCT by Ohno's method, searching the Planckian locus between 1000 K and 100000 K.
+    ///
+    /// This is more accurate than Robertson's method, especially away from the locus, but
+    /// integrates many blackbody spectra.
+    fn cct_ohno(&self) -> Cct<T>;
+}
+
+impl<T> CorrelatedColorTemperature<T> for XyY<T>
+    where T: FreeChannelScalar + PosNormalChannelScalar
+{
+    fn cct_robertson(&self) -> Option<Cct<T>> {
+        let (u, v) = uv_from_xy(self.x(), self.y());
+        robertson(u, v)
+    }
+
+    fn cct_ohno(&self) -> Cct<T> {
+        let (u, v) = uv_from_xy(self.x(), self.y());
+        ohno(u, v)
+    }
+}
+
+impl<T> CorrelatedColorTemperature<T> for Xyz<T>
+    where T: FreeChannelScalar + PosNormalChannelScalar
+{
+    fn cct_robertson(&self) -> Option<Cct<T>> {
+        XyY::from_color(self).cct_robertson()
+    }
+
+    fn cct_ohno(&self) -> Cct<T> {
+        XyY::from_color(self).cct_ohno()
+    }
+}
+
+fn uv_from_xy<T: FreeChannelScalar>(x: T, y: T) -> (T, T) {
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    let denom = c(-2.0) * x + c(12.0) * y + c(3.0);
+    (c(4.0) * x / denom, c(6.0) * y / denom)
+}
+
+fn planckian_uv<T: FreeChannelScalar>(temperature: T) -> (T, T) {
+    let white = blackbody(temperature).white_point::<Cie1931>();
+    let denom = white.x() + num::cast::<_, T>(15.0).unwrap() + num::cast::<_, T>(3.0).unwrap() *
+                white.z();
+    (num::cast::<_, T>(4.0).unwrap() * white.x() / denom,
+     num::cast::<_, T>(6.0).unwrap() / denom)
+}
+
+fn robertson<T: FreeChannelScalar>(u: T, v: T) -> Option<Cct<T>> {
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    let distance = |i: usize| {
+        let (_, ui, vi, ti) = ROBERTSON_ISOTEMPERATURE_LINES[i];
+        ((v - c(vi)) - c(ti) * (u - c(ui))) / (T::one() + c(ti) * c(ti)).sqrt()
+    };
+
+    // Find the pair of isotemperature lines the chromaticity lies between.
+    let mut previous = distance(0);
+    for i in 1..ROBERTSON_ISOTEMPERATURE_LINES.len() {
+        let current = distance(i);
+        if (current < T::zero()) != (previous < T::zero()) {
+            let (r0, u0, v0, _) = ROBERTSON_ISOTEMPERATURE_LINES[i - 1];
+            let (r1, u1, v1, _) = ROBERTSON_ISOTEMPERATURE_LINES[i];
+            let p = previous / (previous - current);
+            let reciprocal = c(r0) + (c(r1) - c(r0)) * p;
+            let locus_u = c(u0) + (c(u1) - c(u0)) * p;
+            let locus_v = c(v0) + (c(v1) - c(v0)) * p;
+
+            let duv = (u - locus_u).hypot(v - locus_v);
+            let duv = if v < locus_v { -duv } else { duv };
+            return Some(Cct::new(c(1e6) / reciprocal, duv));
+        }
+        previous = current;
+    }
+    None
+}
+
+fn ohno<T: FreeChannelScalar>(u: T, v: T) -> Cct<T> {
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    let count = 16;
+    let distance = |temperature: T| {
+        let (ui, vi) = planckian_uv(temperature);
+        (u - ui).hypot(v - vi)
+    };
+
+    // Cascade towards the nearest point of the locus, narrowing the range each time.
+    let (mut low, mut high) = (c(1000.0), c(100000.0));
+    let mut samples = Vec::with_capacity(count);
+    let nearest = loop {
+        let ratio = (high / low).powf(T::one() / c((count - 1) as f64));
+        samples.clear();
+        samples.extend((0..count).map(|i| {
+            let temperature = low * ratio.powi(i as i32);
+            (temperature, distance(temperature))
+        }));
+        let nearest = (1..count - 1)
+            .min_by(|&a, &b| samples[a].1.partial_cmp(&samples[b].1).unwrap())
+            .unwrap();
+        if ratio < c(1.001) {
+            break nearest;
+        }
+        low = samples[nearest - 1].0;
+        high = samples[nearest + 1].0;
+    };
+
+    let (t0, d0) = samples[nearest - 1];
+    let (t1, d1) = samples[nearest];
+    let (t2, d2) = samples[nearest + 1];
+
+    // The triangular solution, accurate near the locus.
+    let (u0, v0) = planckian_uv(t0);
+    let (u2, v2) = planckian_uv(t2);
+    let l = (u2 - u0).hypot(v2 - v0);
+    let x = (d0 * d0 - d2 * d2 + l * l) / (c(2.0) * l);
+    let temperature = t0 + (t2 - t0) * x / l;
+    let locus_v = v0 + (v2 - v0) * x / l;
+    let sign = if v < locus_v { -T::one() } else { T::one() };
+    let duv = sign * (d0 * d0 - x * x).max(T::zero()).sqrt();
+    if duv.abs() < c(0.002) {
+        return Cct::new(temperature, duv);
+    }
+
+    // The parabolic solution, for chromaticities further from the locus.
+    let denom = (t2 - t1) * (t0 - t2) * (t1 - t0);
+    let a = (t0 * (d2 - d1) + t1 * (d0 - d2) + t2 * (d1 - d0)) / denom;
+    let b = -(t0 * t0 * (d2 - d1) + t1 * t1 * (d0 - d2) + t2 * t2 * (d1 - d0)) / denom;
+    let k = -(d0 * (t2 - t1) * t1 * t2 + d1 * (t0 - t2) * t0 * t2 + d2 * (t1 - t0) * t0 * t1) /
+            denom;
+    let temperature = -b / (c(2.0) * a);
+    Cct::new(temperature, sign * (a * temperature * temperature + b * temperature + k))
+}
+
+/// Robertson's isotemperature lines: reciprocal temperature in MK⁻¹, the uv of the
+/// Planckian locus and the slope of the line.
+static ROBERTSON_ISOTEMPERATURE_LINES: [(f64, f64, f64, f64); 31] = [
+    (0.0, 0.18006, 0.26352, -0.24341),
+    (10.0, 0.18066, 0.26589, -0.25479),
+    (20.0, 0.18133, 0.26846, -0.26876),
+    (30.0, 0.18208, 0.27119, -0.28539),
+    (40.0, 0.18293, 0.27407, -0.30470),
+    (50.0, 0.18388, 0.27709, -0.32675),
+    (60.0, 0.18494, 0.28021, -0.35156),
+    (70.0, 0.18611, 0.28342, -0.37915),
+    (80.0, 0.18740, 0.28668, -0.40955),
+    (90.0, 0.18880, 0.28997, -0.44278),
+    (100.0, 0.19032, 0.29326, -0.47888),
+    (125.0, 0.19462, 0.30141, -0.58204),
+    (150.0, 0.19962, 0.30921, -0.70471),
+    (175.0, 0.20525, 0.31647, -0.84901),
+    (200.0, 0.21142, 0.32312, -1.0182),
+    (225.0, 0.21807, 0.32909, -1.2168),
+    (250.0, 0.22511, 0.33439, -1.4512),
+    (275.0, 0.23247, 0.33904, -1.7298),
+    (300.0, 0.24010, 0.34308, -2.0637),
+    (325.0, 0.24792, 0.34655, -2.4681),
+    (350.0, 0.25591, 0.34951, -2.9641),
+    (375.0, 0.26400, 0.35200, -3.5814),
+    (400.0, 0.27218, 0.35407, -4.3633),
+    (425.0, 0.28039, 0.35577, -5.3762),
+    (450.0, 0.28863, 0.35714, -6.7262),
+    (475.0, 0.29685, 0.35823, -8.5955),
+    (500.0, 0.30505, 0.35907, -11.324),
+    (525.0, 0.31320, 0.35968, -15.628),
+    (550.0, 0.32129, 0.36011, -23.325),
+    (575.0, 0.32931, 0.36038, -40.770),
+    (600.0, 0.33724, 0.36051, -116.45),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_blackbody() {
+        let s1 = blackbody(2856.0);
+        assert_relative_eq!(s1.value_at(560.0), 100.0, epsilon=1e-9);
+        assert_relative_eq!(s1.value_at(380.0), 9.801799, epsilon=1e-6);
+        assert_relative_eq!(s1.value_at(780.0), 241.577349, epsilon=1e-6);
+
+        let c1: XyY<f64> = planckian_chromaticity::<_, Cie1931>(2856.0);
+        assert_relative_eq!(c1.x(), 0.44757, epsilon=1e-4);
+        assert_relative_eq!(c1.y(), 0.40745, epsilon=1e-4);
+    }
+
+    #[test]
+    fn test_cct() {
+        let d65 = XyY::from_channels(0.31271, 0.32902, 1.0);
+        let t1 = d65.cct_robertson().unwrap();
+        assert_relative_eq!(t1.temperature, 6503.01, epsilon=1e-2);
+        assert_relative_eq!(t1.duv, 0.003262, epsilon=1e-6);
+        let t2 = d65.cct_ohno();
+        assert_relative_eq!(t2.temperature, 6505.09, epsilon=1e-2);
+        assert_relative_eq!(t2.duv, 0.003199, epsilon=1e-6);
+
+        let a = Xyz::from_channels(1.09850, 1.0, 0.35585);
+        assert_relative_eq!(a.cct_robertson().unwrap().temperature, 2855.63, epsilon=1e-2);
+        assert_relative_eq!(a.cct_ohno().temperature, 2855.53, epsilon=1e-2);
+        assert_relative_eq!(a.cct_ohno().duv, 0.0, epsilon=1e-5);
+
+        // Away from the locus the interpolated isotemperature lines differ more from the search.
+        let c1 = XyY::from_channels(0.3, 0.33, 1.0);
+        assert_relative_eq!(c1.cct_robertson().unwrap().duv, 0.010350, epsilon=1e-6);
+        assert_relative_eq!(c1.cct_ohno().duv, 0.010240, epsilon=1e-6);
+
+        assert!(XyY::from_channels(0.6, 0.38, 1.0).cct_robertson().is_none());
+    }
+
+    #[test]
+    fn test_chromaticity() {
+        let white = Cct::new(3200.0, 0.0).chromaticity();
+        assert_relative_eq!(white, planckian_chromaticity::<_, Cie1931>(3200.0), epsilon=1e-6);
+
+        for &(temperature, duv) in [(2000.0, 0.01), (3200.0, -0.004), (5000.0, 0.0015),
+                                    (9000.0, -0.02), (20000.0, 0.03)].iter() {
+            let t1 = Cct::new(temperature, duv).chromaticity().cct_ohno();
+            assert_relative_eq!(t1.temperature, temperature, max_relative=1e-4);
+            assert_relative_eq!(t1.duv, duv, epsilon=1e-5);
+        }
+    }
+}
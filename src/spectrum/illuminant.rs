@@ -0,0 +1,334 @@
+//! Relative spectral power distributions of the CIE illuminants.
+//!
+//! Every illuminant in `white_point::deg_2` and `white_point::deg_10` except `Dci` and `Aces`
+//! implements `Illuminant`, so its white point can be derived from its spectrum rather than
+//! taken from the tabulated constants. The tables are the CIE 5 nm data from 380 to 780 nm.
+
+use num;
+use channel::FreeChannelScalar;
+use white_point::{deg_2, deg_10};
+use xyz::Xyz;
+use super::Spectrum;
+use super::observer::{StandardObserver, Cie1931, Cie1964};
+
+/// A standard illuminant with a tabulated relative spectral power distribution.
+pub trait Illuminant {
+    /// The observer the illuminant's `NamedWhitePoint` constants were computed for.
+    type Observer: StandardObserver;
+
+    /// The relative spectral power distribution, normalized to 100 at 560 nm.
+    fn spectrum<T: FreeChannelScalar>() -> Spectrum<T>;
+
+    /// The white point derived from `spectrum` and `Observer`.
+    fn white_point<T: FreeChannelScalar>() -> Xyz<T> {
+        Self::spectrum().white_point::<Self::Observer>()
+    }
+}
+
+/// The CIE daylight illuminant with correlated color temperature `cct` in K.
+///
+/// The CIE defines the daylight series between 4000 K and 25000 K. The D illuminants use a
+/// `cct` of their nominal temperature times 1.4388 / 1.438, after a revision of the second
+/// radiation constant.
+pub fn daylight<T: FreeChannelScalar>(cct: T) -> Spectrum<T> {
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    let x = if cct <= c(7000.0) {
+        c(0.244063) + c(0.09911e3) / cct + c(2.9678e6) / cct.powi(2) - c(4.6070e9) / cct.powi(3)
+    } else {
+        c(0.237040) + c(0.24748e3) / cct + c(1.9018e6) / cct.powi(2) - c(2.0064e9) / cct.powi(3)
+    };
+    let y = c(-3.0) * x * x + c(2.870) * x - c(0.275);
+
+    // The CIE rounds the weights to three decimals before tabulating.
+    let m = c(0.0241) + c(0.2562) * x - c(0.7341) * y;
+    let round = |val: T| (val * c(1000.0)).round() / c(1000.0);
+    let m1 = round((c(-1.3515) - c(1.7703) * x + c(5.9114) * y) / m);
+    let m2 = round((c(0.0300) - c(31.4424) * x + c(30.0717) * y) / m);
+
+    Spectrum::from_fn(c(380.0), c(5.0), DAYLIGHT_S0.len(), |wavelength| {
+        let i = ((wavelength - c(380.0)) / c(5.0)).round().to_usize().unwrap();
+        c(DAYLIGHT_S0[i]) + m1 * c(DAYLIGHT_S1[i]) + m2 * c(DAYLIGHT_S2[i])
+    })
+}
+
+fn tabulated<T: FreeChannelScalar>(table: &[f64]) -> Spectrum<T> {
+    let values = table.iter().map(|&val| num::cast(val).unwrap()).collect();
+    Spectrum::new(num::cast(380.0).unwrap(), num::cast(5.0).unwrap(), values)
+}
+
+fn daylight_at_nominal<T: FreeChannelScalar>(nominal: f64) -> Spectrum<T> {
+    daylight(num::cast(nominal * 1.4388 / 1.438).unwrap())
+}
+
+fn equal_energy<T: FreeChannelScalar>() -> Spectrum<T> {
+    let c = |val: f64| -> T { num::cast(val).unwrap() };
+    Spectrum::new(c(380.0), c(400.0), vec![c(100.0), c(100.0)])
+}
+
+macro_rules! impl_illuminants {
+    ($module:ident, $observer:ident) => {
+        impl_illuminants!($module, $observer, {
+            A => tabulated(&ILLUMINANT_A),
+            B => tabulated(&ILLUMINANT_B),
+            C => tabulated(&ILLUMINANT_C),
+            D50 => daylight_at_nominal(5000.0),
+            D55 => daylight_at_nominal(5500.0),
+            D65 => daylight_at_nominal(6500.0),
+            D75 => daylight_at_nominal(7500.0),
+            E => equal_energy(),
+            F1 => tabulated(&ILLUMINANT_F1),
+            F2 => tabulated(&ILLUMINANT_F2),
+            F3 => tabulated(&ILLUMINANT_F3),
+            F4 => tabulated(&ILLUMINANT_F4),
+            F5 => tabulated(&ILLUMINANT_F5),
+            F6 => tabulated(&ILLUMINANT_F6),
+            F7 => tabulated(&ILLUMINANT_F7),
+            F8 => tabulated(&ILLUMINANT_F8),
+            F9 => tabulated(&ILLUMINANT_F9),
+            F10 => tabulated(&ILLUMINANT_F10),
+            F11 => tabulated(&ILLUMINANT_F11),
+            F12 => tabulated(&ILLUMINANT_F12)
+        });
+    };
+    ($module:ident, $observer:ident, {$($name:ident => $spectrum:expr),*}) => {
+        $(
+            impl Illuminant for $module::$name {
+                type Observer = $observer;
+                #[inline]
+                fn spectrum<T: FreeChannelScalar>() -> Spectrum<T> {
+                    $spectrum
+                }
+            }
+        )*
+    };
+}
+
+impl_illuminants!(deg_2, Cie1931);
+impl_illuminants!(deg_10, Cie1964);
+
+static ILLUMINANT_A: [f64; 81] = [
+    9.7951, 10.8996, 12.0853, 13.3543, 14.708, 16.148, 17.6753, 19.2907, 20.995, 22.7883,
+    24.6709, 26.6425, 28.7027, 30.8508, 33.0859, 35.4068, 37.8121, 40.3002, 42.8693, 45.5174,
+    48.2423, 51.0418, 53.9132, 56.8539, 59.8611, 62.932, 66.0635, 69.2525, 72.4959, 75.7903,
+    79.1326, 82.5193, 85.947, 89.4124, 92.912, 96.4423, 100.0, 103.582, 107.184, 110.803,
+    114.436, 118.08, 121.731, 125.386, 129.043, 132.697, 136.346, 139.988, 143.618, 147.235,
+    150.836, 154.418, 157.979, 161.516, 165.028, 168.51, 171.963, 175.383, 178.769, 182.118,
+    185.429, 188.701, 191.931, 195.118, 198.261, 201.359, 204.409, 207.411, 210.365, 213.268,
+    216.12, 218.92, 221.667, 224.361, 227.0, 229.585, 232.115, 234.589, 237.008, 239.37,
+    241.675,
+];
+
+static ILLUMINANT_B: [f64; 81] = [
+    22.4, 26.85, 31.3, 36.18, 41.3, 46.62, 52.1, 57.7, 63.2, 68.37, 73.1, 77.31, 80.8, 83.44,
+    85.4, 86.88, 88.3, 90.08, 92.0, 93.75, 95.2, 96.23, 96.5, 95.71, 94.2, 92.37, 90.7, 89.65,
+    89.5, 90.43, 92.2, 94.46, 96.9, 99.16, 101.0, 102.2, 102.8, 102.92, 102.6, 101.9, 101.0,
+    100.07, 99.2, 98.44, 98.0, 98.08, 98.5, 99.06, 99.7, 100.36, 101.0, 101.56, 102.2, 103.05,
+    103.9, 104.59, 105.0, 105.08, 104.9, 104.55, 103.9, 102.84, 101.6, 100.38, 99.1, 97.7, 96.2,
+    94.6, 92.9, 91.1, 89.4, 88.0, 86.9, 85.9, 85.2, 84.8, 84.7, 84.9, 85.4, 86.1, 87.0,
+];
+
+static ILLUMINANT_C: [f64; 81] = [
+    33.0, 39.92, 47.4, 55.17, 63.3, 71.81, 80.6, 89.53, 98.1, 105.8, 112.4, 117.75, 121.5,
+    123.45, 124.0, 123.6, 123.1, 123.3, 123.8, 124.09, 123.9, 122.92, 120.7, 116.9, 112.1,
+    106.98, 102.3, 98.81, 96.9, 96.78, 98.0, 99.94, 102.1, 103.95, 105.2, 105.67, 105.3, 104.11,
+    102.3, 100.15, 97.8, 95.43, 93.2, 91.22, 89.7, 88.83, 88.4, 88.19, 88.1, 88.06, 88.0, 87.86,
+    87.8, 87.99, 88.2, 88.2, 87.9, 87.22, 86.3, 85.3, 84.0, 82.21, 80.2, 78.24, 76.3, 74.36,
+    72.4, 70.4, 68.3, 66.3, 64.4, 62.8, 61.5, 60.2, 59.2, 58.5, 58.1, 58.0, 58.2, 58.5, 59.1,
+];
+
+static ILLUMINANT_F1: [f64; 81] = [
+    1.87, 2.36, 2.94, 3.47, 5.17, 19.49, 6.13, 6.24, 7.01, 7.79, 8.56, 43.67, 16.94, 10.72,
+    11.35, 11.89, 12.37, 12.75, 13.0, 13.15, 13.23, 13.17, 13.13, 12.85, 12.52, 12.2, 11.83,
+    11.5, 11.22, 11.05, 11.03, 11.18, 11.53, 27.74, 17.05, 13.55, 14.33, 15.01, 15.52, 18.29,
+    19.55, 15.48, 14.91, 14.15, 13.22, 12.19, 11.12, 10.03, 8.95, 7.96, 7.02, 6.2, 5.42, 4.73,
+    4.15, 3.64, 3.2, 2.81, 2.47, 2.18, 1.93, 1.72, 1.67, 1.43, 1.29, 1.19, 1.08, 0.96, 0.88,
+    0.81, 0.77, 0.75, 0.73, 0.68, 0.69, 0.64, 0.68, 0.69, 0.61, 0.52, 0.43,
+];
+
+static ILLUMINANT_F2: [f64; 81] = [
+    1.18, 1.48, 1.84, 2.15, 3.44, 15.69, 3.85, 3.74, 4.19, 4.62, 5.06, 34.98, 11.81, 6.27, 6.63,
+    6.93, 7.19, 7.4, 7.54, 7.62, 7.65, 7.62, 7.62, 7.45, 7.28, 7.15, 7.05, 7.04, 7.16, 7.47,
+    8.04, 8.88, 10.01, 24.88, 16.64, 14.59, 16.16, 17.56, 18.62, 21.47, 22.79, 19.29, 18.66,
+    17.73, 16.54, 15.21, 13.8, 12.36, 10.95, 9.65, 8.4, 7.32, 6.31, 5.43, 4.68, 4.02, 3.45,
+    2.96, 2.55, 2.19, 1.89, 1.64, 1.53, 1.27, 1.1, 0.99, 0.88, 0.76, 0.68, 0.61, 0.56, 0.54,
+    0.51, 0.47, 0.47, 0.43, 0.46, 0.47, 0.4, 0.33, 0.27,
+];
+
+static ILLUMINANT_F3: [f64; 81] = [
+    0.82, 1.02, 1.26, 1.44, 2.57, 14.36, 2.7, 2.45, 2.73, 3.0, 3.28, 31.85, 9.47, 4.02, 4.25,
+    4.44, 4.59, 4.72, 4.8, 4.86, 4.87, 4.85, 4.88, 4.77, 4.67, 4.62, 4.62, 4.73, 4.99, 5.48,
+    6.25, 7.34, 8.78, 23.82, 16.14, 14.59, 16.63, 18.49, 19.95, 23.11, 24.69, 21.41, 20.85,
+    19.93, 18.67, 17.22, 15.65, 14.04, 12.45, 10.95, 9.51, 8.27, 7.11, 6.09, 5.22, 4.45, 3.8,
+    3.23, 2.75, 2.33, 1.99, 1.7, 1.55, 1.27, 1.09, 0.96, 0.83, 0.71, 0.62, 0.54, 0.49, 0.46,
+    0.43, 0.39, 0.39, 0.35, 0.38, 0.39, 0.33, 0.28, 0.21,
+];
+
+#[allow(clippy::approx_constant)]
+static ILLUMINANT_F4: [f64; 81] = [
+    0.57, 0.7, 0.87, 0.98, 2.01, 13.75, 1.95, 1.59, 1.76, 1.93, 2.1, 30.28, 8.03, 2.55, 2.7,
+    2.82, 2.91, 2.99, 3.04, 3.08, 3.09, 3.09, 3.14, 3.06, 3.0, 2.98, 3.01, 3.14, 3.41, 3.9,
+    4.69, 5.81, 7.32, 22.59, 15.11, 13.88, 16.33, 18.68, 20.64, 24.28, 26.26, 23.28, 22.94,
+    22.14, 20.91, 19.43, 17.74, 16.0, 14.42, 12.56, 10.93, 9.52, 8.18, 7.01, 6.0, 5.11, 4.36,
+    3.69, 3.13, 2.64, 2.24, 1.91, 1.7, 1.39, 1.18, 1.03, 0.88, 0.74, 0.64, 0.54, 0.49, 0.46,
+    0.42, 0.37, 0.37, 0.33, 0.35, 0.36, 0.31, 0.26, 0.19,
+];
+
+static ILLUMINANT_F5: [f64; 81] = [
+    1.87, 2.35, 2.92, 3.45, 5.1, 18.91, 6.0, 6.11, 6.85, 7.58, 8.31, 40.76, 16.06, 10.32, 10.91,
+    11.4, 11.83, 12.17, 12.4, 12.54, 12.58, 12.52, 12.47, 12.2, 11.89, 11.61, 11.33, 11.1,
+    10.96, 10.97, 11.16, 11.54, 12.12, 27.78, 17.73, 14.47, 15.2, 15.77, 16.1, 18.54, 19.5,
+    15.39, 14.64, 13.72, 12.69, 11.57, 10.45, 9.35, 8.29, 7.32, 6.41, 5.63, 4.9, 4.26, 3.72,
+    3.25, 2.83, 2.49, 2.19, 1.93, 1.71, 1.52, 1.48, 1.26, 1.13, 1.05, 0.96, 0.85, 0.78, 0.72,
+    0.68, 0.67, 0.65, 0.61, 0.62, 0.59, 0.62, 0.64, 0.55, 0.47, 0.4,
+];
+
+#[allow(clippy::approx_constant)]
+static ILLUMINANT_F6: [f64; 81] = [
+    1.05, 1.31, 1.63, 1.9, 3.11, 14.8, 3.43, 3.3, 3.68, 4.07, 4.45, 32.61, 10.74, 5.48, 5.78,
+    6.03, 6.25, 6.41, 6.52, 6.58, 6.59, 6.56, 6.56, 6.42, 6.28, 6.2, 6.19, 6.3, 6.6, 7.12, 7.94,
+    9.07, 10.49, 25.22, 17.46, 15.63, 17.22, 18.53, 19.43, 21.97, 23.01, 19.41, 18.56, 17.42,
+    16.09, 14.64, 13.15, 11.68, 10.25, 8.95, 7.74, 6.69, 5.71, 4.87, 4.16, 3.55, 3.02, 2.57,
+    2.2, 1.87, 1.6, 1.37, 1.29, 1.05, 0.91, 0.81, 0.71, 0.61, 0.54, 0.48, 0.44, 0.43, 0.4, 0.37,
+    0.38, 0.35, 0.39, 0.41, 0.33, 0.26, 0.21,
+];
+
+static ILLUMINANT_F7: [f64; 81] = [
+    2.56, 3.18, 3.84, 4.53, 6.15, 19.37, 7.37, 7.05, 7.71, 8.41, 9.15, 44.14, 17.52, 11.35,
+    12.0, 12.58, 13.08, 13.45, 13.71, 13.88, 13.95, 13.93, 13.82, 13.64, 13.43, 13.25, 13.08,
+    12.93, 12.78, 12.6, 12.44, 12.33, 12.26, 29.52, 17.05, 12.44, 12.58, 12.72, 12.83, 15.46,
+    16.75, 12.83, 12.67, 12.45, 12.19, 11.89, 11.6, 11.35, 11.12, 10.95, 10.76, 10.42, 10.11,
+    10.04, 10.02, 10.11, 9.87, 8.65, 7.27, 6.44, 5.83, 5.41, 5.04, 4.57, 4.12, 3.77, 3.46, 3.08,
+    2.73, 2.47, 2.25, 2.06, 1.9, 1.75, 1.62, 1.54, 1.45, 1.32, 1.17, 0.99, 0.81,
+];
+
+static ILLUMINANT_F8: [f64; 81] = [
+    1.21, 1.5, 1.81, 2.13, 3.17, 13.08, 3.83, 3.45, 3.86, 4.42, 5.09, 34.1, 12.42, 7.68, 8.6,
+    9.46, 10.24, 10.84, 11.33, 11.71, 11.98, 12.17, 12.28, 12.32, 12.35, 12.44, 12.55, 12.68,
+    12.77, 12.72, 12.6, 12.43, 12.22, 28.96, 16.51, 11.79, 11.76, 11.77, 11.84, 14.61, 16.11,
+    12.34, 12.53, 12.72, 12.92, 13.12, 13.34, 13.61, 13.87, 14.07, 14.2, 14.16, 14.13, 14.34,
+    14.5, 14.46, 14.0, 12.58, 10.99, 9.98, 9.22, 8.62, 8.07, 7.39, 6.71, 6.16, 5.63, 5.03, 4.46,
+    4.02, 3.66, 3.36, 3.09, 2.85, 2.65, 2.51, 2.37, 2.15, 1.89, 1.61, 1.32,
+];
+
+static ILLUMINANT_F9: [f64; 81] = [
+    0.9, 1.12, 1.36, 1.6, 2.59, 12.8, 3.05, 2.56, 2.86, 3.3, 3.82, 32.62, 10.77, 5.84, 6.57,
+    7.25, 7.86, 8.35, 8.75, 9.06, 9.31, 9.48, 9.61, 9.68, 9.74, 9.88, 10.04, 10.26, 10.48,
+    10.63, 10.78, 10.96, 11.18, 27.71, 16.29, 12.28, 12.74, 13.21, 13.65, 16.57, 18.14, 14.55,
+    14.65, 14.66, 14.61, 14.5, 14.39, 14.4, 14.47, 14.62, 14.72, 14.55, 14.4, 14.58, 14.88,
+    15.51, 15.47, 13.2, 10.57, 9.18, 8.25, 7.57, 7.03, 6.35, 5.72, 5.25, 4.8, 4.29, 3.8, 3.43,
+    3.12, 2.86, 2.64, 2.43, 2.26, 2.14, 2.02, 1.83, 1.61, 1.38, 1.12,
+];
+
+static ILLUMINANT_F10: [f64; 81] = [
+    1.11, 0.8, 0.62, 0.57, 1.48, 12.16, 2.12, 2.7, 3.74, 5.14, 6.75, 34.39, 14.86, 10.4, 10.76,
+    10.67, 10.11, 9.27, 8.29, 7.29, 7.91, 16.64, 16.73, 10.44, 5.94, 3.34, 2.35, 1.88, 1.59,
+    1.47, 1.8, 5.71, 40.98, 73.69, 33.61, 8.24, 3.38, 2.47, 2.14, 4.86, 11.45, 14.79, 12.16,
+    8.97, 6.52, 8.31, 44.12, 34.55, 12.09, 12.15, 10.52, 4.43, 1.95, 2.19, 3.19, 2.77, 2.29,
+    2.0, 1.52, 1.35, 1.47, 1.79, 1.74, 1.02, 1.14, 3.32, 4.49, 2.05, 0.49, 0.24, 0.21, 0.21,
+    0.24, 0.24, 0.21, 0.17, 0.21, 0.22, 0.17, 0.12, 0.09,
+];
+
+static ILLUMINANT_F11: [f64; 81] = [
+    0.91, 0.63, 0.46, 0.37, 1.29, 12.68, 1.59, 1.79, 2.46, 3.33, 4.49, 33.94, 12.13, 6.95, 7.19,
+    7.12, 6.72, 6.13, 5.46, 4.79, 5.66, 14.29, 14.96, 8.97, 4.72, 2.33, 1.47, 1.1, 0.89, 0.83,
+    1.18, 4.9, 39.59, 72.84, 32.61, 7.52, 2.83, 1.96, 1.67, 4.43, 11.28, 14.76, 12.73, 9.74,
+    7.33, 9.72, 55.27, 42.58, 13.18, 13.16, 12.26, 5.11, 2.07, 2.34, 3.58, 3.01, 2.48, 2.14,
+    1.54, 1.33, 1.46, 1.94, 2.0, 1.2, 1.35, 4.1, 5.58, 2.51, 0.57, 0.27, 0.23, 0.21, 0.24, 0.24,
+    0.2, 0.24, 0.32, 0.26, 0.16, 0.12, 0.09,
+];
+
+static ILLUMINANT_F12: [f64; 81] = [
+    0.96, 0.64, 0.4, 0.33, 1.19, 12.48, 1.12, 0.94, 1.08, 1.37, 1.78, 29.05, 7.9, 2.65, 2.71, 2.65,
+    2.49, 2.33, 2.1, 1.91, 3.01, 10.83, 11.88, 6.88, 3.43, 1.49, 0.92, 0.71, 0.6, 0.63, 1.1, 4.56,
+    34.4, 65.4, 29.48, 7.16, 3.08, 2.47, 2.27, 5.09, 11.96, 15.32, 14.27, 11.86, 9.28, 12.31, 68.53,
+    53.02, 14.67, 14.38, 14.71, 6.46, 2.57, 2.75, 4.18, 3.44, 2.81, 2.42, 1.64, 1.36, 1.49, 2.14,
+    2.34, 1.42, 1.61, 5.04, 6.98, 3.19, 0.71, 0.3, 0.26, 0.23, 0.28, 0.28, 0.21, 0.17, 0.21, 0.19,
+    0.15, 0.1, 0.05,
+];
+
+static DAYLIGHT_S0: [f64; 81] = [
+    63.4, 64.6, 65.8, 80.3, 94.8, 99.8, 104.8, 105.35, 105.9, 101.35, 96.8, 105.35, 113.9,
+    119.75, 125.6, 125.55, 125.5, 123.4, 121.3, 121.3, 121.3, 117.4, 113.5, 113.3, 113.1,
+    111.95, 110.8, 108.65, 106.5, 107.65, 108.8, 107.05, 105.3, 104.85, 104.4, 102.2, 100.0,
+    98.0, 96.0, 95.55, 95.1, 92.1, 89.1, 89.8, 90.5, 90.4, 90.3, 89.35, 88.4, 86.2, 84.0, 84.55,
+    85.1, 83.5, 81.9, 82.25, 82.6, 83.75, 84.9, 83.1, 81.3, 76.6, 71.9, 73.1, 74.3, 75.35, 76.4,
+    69.85, 63.3, 67.5, 71.7, 74.35, 77.0, 71.1, 65.2, 56.45, 47.7, 58.15, 68.6, 66.8, 65.0,
+];
+
+static DAYLIGHT_S1: [f64; 81] = [
+    38.5, 36.75, 35.0, 39.2, 43.4, 44.85, 46.3, 45.1, 43.9, 40.5, 37.1, 36.9, 36.7, 36.3, 35.9,
+    34.25, 32.6, 30.25, 27.9, 26.1, 24.3, 22.2, 20.1, 18.15, 16.2, 14.7, 13.2, 10.9, 8.6, 7.35,
+    6.1, 5.15, 4.2, 3.05, 1.9, 0.95, 0.0, -0.8, -1.6, -2.55, -3.5, -3.5, -3.5, -4.65, -5.8,
+    -6.5, -7.2, -7.9, -8.6, -9.05, -9.5, -10.2, -10.9, -10.8, -10.7, -11.35, -12.0, -13.0,
+    -14.0, -13.8, -13.6, -12.8, -12.0, -12.65, -13.3, -13.1, -12.9, -11.75, -10.6, -11.1, -11.6,
+    -11.9, -12.2, -11.2, -10.2, -9.0, -7.8, -9.5, -11.2, -10.8, -10.4,
+];
+
+static DAYLIGHT_S2: [f64; 81] = [
+    3.0, 2.1, 1.2, 0.05, -1.1, -0.8, -0.5, -0.6, -0.7, -0.95, -1.2, -1.9, -2.6, -2.75, -2.9,
+    -2.85, -2.8, -2.7, -2.6, -2.6, -2.6, -2.2, -1.8, -1.65, -1.5, -1.4, -1.3, -1.25, -1.2, -1.1,
+    -1.0, -0.75, -0.5, -0.4, -0.3, -0.15, 0.0, 0.1, 0.2, 0.35, 0.5, 1.3, 2.1, 2.65, 3.2, 3.65,
+    4.1, 4.4, 4.7, 4.9, 5.1, 5.9, 6.7, 7.0, 7.3, 7.95, 8.6, 9.2, 9.8, 10.0, 10.2, 9.25, 8.3,
+    8.95, 9.6, 9.05, 8.5, 7.75, 7.0, 7.3, 7.6, 7.8, 8.0, 7.35, 6.7, 5.95, 5.2, 6.3, 7.4, 7.1,
+    6.8,
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use white_point::NamedWhitePoint;
+
+    macro_rules! assert_white_points {
+        ($module:ident, $epsilon:expr, {$($name:ident),*}) => {
+            $(
+                assert_relative_eq!($module::$name::white_point::<f64>(),
+                                    <$module::$name as NamedWhitePoint<f64>>::get_xyz(),
+                                    epsilon=$epsilon);
+            )*
+        }
+    }
+
+    #[test]
+    fn test_white_points() {
+        assert_white_points!(deg_2, 1.5e-3, {A, B, C, D50, D55, D65, D75, E, F1, F2, F3, F4, F5,
+            F6, F7, F8, F9, F11, F12});
+        assert_white_points!(deg_10, 1.5e-3, {A, B, C, D50, D55, D65, D75, E, F1, F2, F3, F4,
+            F5, F6, F7, F8, F9, F11, F12});
+        // The tabulated F10 constants are further from the CIE table than the others.
+        assert_white_points!(deg_2, 7e-3, {F10});
+        assert_white_points!(deg_10, 2e-3, {F10});
+
+        assert_relative_eq!(deg_2::D65::white_point(),
+            Xyz::from_channels(0.950429, 1.0, 1.088799), epsilon=1e-6);
+        assert_relative_eq!(deg_10::A::white_point(),
+            Xyz::from_channels(1.111439, 1.0, 0.351995), epsilon=1e-6);
+    }
+
+    #[test]
+    fn test_daylight() {
+        let d65 = <deg_2::D65 as Illuminant>::spectrum::<f64>();
+        assert_eq!(d65.len(), 81);
+        assert_relative_eq!(d65.value_at(380.0), 49.9755, epsilon=1e-4);
+        assert_relative_eq!(d65.value_at(460.0), 117.8122, epsilon=1e-4);
+        assert_relative_eq!(d65.value_at(560.0), 100.0, epsilon=1e-9);
+
+        let d50 = daylight(5002.78f32);
+        assert_relative_eq!(d50.value_at(380.0), 24.4875, epsilon=1e-3);
+        assert_relative_eq!(d50.value_at(780.0), 78.274, epsilon=1e-3);
+    }
+
+    #[test]
+    fn test_reflectance_under_illuminant() {
+        let gray = Spectrum::new(400.0, 10.0, vec![0.5; 31]);
+        let d50 = deg_2::D50::spectrum();
+        assert_relative_eq!(gray.reflective_xyz::<Cie1931>(&d50),
+            Xyz::from_channels(0.482099, 0.5, 0.412562), epsilon=1e-6);
+
+        let red = Spectrum::from_fn(380.0, 10.0, 41, |wavelength| {
+            if wavelength < 590.0 { 0.05 } else { 0.8 }
+        });
+        let under_a = red.reflective_xyz::<Cie1931>(&deg_2::A::spectrum());
+        let under_d65 = red.reflective_xyz::<Cie1931>(&deg_2::D65::spectrum());
+        assert!(under_a.y() > under_d65.y());
+    }
+}
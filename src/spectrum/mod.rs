@@ -0,0 +1,250 @@
+//! Spectral data and its integration to tristimulus values.
+//!
+//! A `Spectrum` holds values sampled at regular wavelength intervals, such as the readings of
+//! a spectrophotometer. Integrating it against the color-matching functions of a
+//! `StandardObserver` gives the `Xyz` of the stimulus, either of a light source directly or of
+//! a surface lit by an illuminant. The `illuminant` module provides the spectra of the CIE
+//! illuminants behind the `white_point` constants, and the `cct` module relates chromaticities
+//! to the Planckian locus.
+
+use num;
+use channel::FreeChannelScalar;
+use xyz::Xyz;
+
+pub mod observer;
+pub mod illuminant;
+pub mod cct;
+
+pub use self::observer::{StandardObserver, Cie1931, Cie1964};
+pub use self::illuminant::{Illuminant, daylight};
+pub use self::cct::{Cct, CorrelatedColorTemperature, blackbody, planckian_chromaticity};
+
+/// The maximum luminous efficacy of radiation for photopic vision, in lm/W.
+pub const MAX_LUMINOUS_EFFICACY: f64 = 683.0;
+
+/// Values sampled over wavelength at a regular interval.
+///
+/// Wavelengths are in nm. Between samples the spectrum is interpolated linearly; outside of
+/// the sampled range it is extended with the nearest sample, as CIE 15 recommends for
+/// measurements that don't cover the whole visible range.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spectrum<T> {
+    start: T,
+    interval: T,
+    values: Vec<T>,
+}
+
+impl<T> Spectrum<T>
+    where T: FreeChannelScalar
+{
+    /// Construct a spectrum from `values` sampled every `interval` nm from `start` nm.
+    ///
+    /// Panics if `values` is empty or `interval` is not positive.
+    pub fn new(start: T, interval: T, values: Vec<T>) -> Self {
+        assert!(!values.is_empty(), "A spectrum must have at least one sample");
+        assert!(interval > T::zero(), "A spectrum's sampling interval must be positive");
+        Spectrum {
+            start,
+            interval,
+            values,
+        }
+    }
+
+    /// Construct a spectrum of `len` samples by evaluating `f` at each wavelength.
+    pub fn from_fn<F>(start: T, interval: T, len: usize, f: F) -> Self
+        where F: Fn(T) -> T
+    {
+        let values = (0..len)
+            .map(|i| f(start + interval * num::cast(i).unwrap()))
+            .collect();
+        Spectrum::new(start, interval, values)
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+    /// The wavelength of the last sample.
+    pub fn end(&self) -> T {
+        self.wavelength(self.values.len() - 1)
+    }
+    pub fn interval(&self) -> T {
+        self.interval
+    }
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+    /// The wavelength of the sample at `index`.
+    pub fn wavelength(&self, index: usize) -> T {
+        self.start + self.interval * num::cast(index).unwrap()
+    }
+
+    /// The value at `wavelength`, interpolated between the nearest samples.
+    pub fn value_at(&self, wavelength: T) -> T {
+        let last = self.values.len() - 1;
+        let position = (wavelength - self.start) / self.interval;
+        if position <= T::zero() {
+            return self.values[0];
+        }
+        if position >= num::cast(last).unwrap() {
+            return self.values[last];
+        }
+        let index = position.floor().to_usize().unwrap();
+        let frac = position - num::cast(index).unwrap();
+        self.values[index] + (self.values[index + 1] - self.values[index]) * frac
+    }
+
+    /// Resample to `len` samples every `interval` nm from `start` nm.
+    pub fn resample(&self, start: T, interval: T, len: usize) -> Spectrum<T> {
+        Spectrum::from_fn(start, interval, len, |wavelength| self.value_at(wavelength))
+    }
+
+    /// The `Xyz` of a light source with this spectral radiance.
+    ///
+    /// The result is absolute: a spectrum in W/(sr·m²·nm) gives `Xyz` in cd/m². Only the
+    /// sampled range is integrated, since an emitter is not assumed to emit outside of it.
+    pub fn emissive_xyz<O: StandardObserver>(&self) -> Xyz<T> {
+        let (table_start, table_end) = table_range::<T, O>();
+        let step = self.interval.min(num::cast(observer::TABLE_INTERVAL).unwrap());
+        let (x, y, z) = integrate::<T, O, _>(self.start.max(table_start),
+                                             self.end().min(table_end),
+                                             step,
+                                             |wavelength| self.value_at(wavelength));
+        let scale = step * num::cast(MAX_LUMINOUS_EFFICACY).unwrap();
+        Xyz::from_channels(x * scale, y * scale, z * scale)
+    }
+
+    /// The white point of a light source with this relative spectral power distribution.
+    ///
+    /// The result is normalized so that `y` is 1, which is how the `white_point` constants are
+    /// tabulated.
+    pub fn white_point<O: StandardObserver>(&self) -> Xyz<T> {
+        let (table_start, table_end) = table_range::<T, O>();
+        let step = self.interval.min(num::cast(observer::TABLE_INTERVAL).unwrap());
+        let (x, y, z) = integrate::<T, O, _>(table_start, table_end, step, |wavelength| {
+            self.value_at(wavelength)
+        });
+        Xyz::from_channels(x / y, T::one(), z / y)
+    }
+
+    /// The `Xyz` of a surface with this spectral reflectance, lit by `illuminant`.
+    ///
+    /// The result is relative to the illuminant, so that a perfect reflector has a `y` of 1.
+    pub fn reflective_xyz<O: StandardObserver>(&self, illuminant: &Spectrum<T>) -> Xyz<T> {
+        let (table_start, table_end) = table_range::<T, O>();
+        let step = self.interval
+            .min(illuminant.interval)
+            .min(num::cast(observer::TABLE_INTERVAL).unwrap());
+        let (x, y, z) = integrate::<T, O, _>(table_start, table_end, step, |wavelength| {
+            self.value_at(wavelength) * illuminant.value_at(wavelength)
+        });
+        let (_, white_y, _) = integrate::<T, O, _>(table_start, table_end, step, |wavelength| {
+            illuminant.value_at(wavelength)
+        });
+        Xyz::from_channels(x / white_y, y / white_y, z / white_y)
+    }
+}
+
+/// The first and last wavelength of `O`'s color-matching function table.
+fn table_range<T, O>() -> (T, T)
+    where T: FreeChannelScalar,
+          O: StandardObserver
+{
+    let last = (O::table().len() - 1) as f64;
+    (num::cast(observer::TABLE_START).unwrap(),
+     num::cast(observer::TABLE_START + observer::TABLE_INTERVAL * last).unwrap())
+}
+
+/// Sum `f` weighted by the color-matching functions every `step` nm from `start` to `end`.
+fn integrate<T, O, F>(start: T, end: T, step: T, f: F) -> (T, T, T)
+    where T: FreeChannelScalar,
+          O: StandardObserver,
+          F: Fn(T) -> T
+{
+    if end < start {
+        return (T::zero(), T::zero(), T::zero());
+    }
+    let epsilon: T = num::cast(1e-6).unwrap();
+    let count = ((end - start) / step + epsilon).floor().to_usize().unwrap() + 1;
+    (0..count).fold((T::zero(), T::zero(), T::zero()), |(x, y, z), i| {
+        let wavelength = start + step * num::cast(i).unwrap();
+        let value = f(wavelength);
+        let (x_bar, y_bar, z_bar) = O::color_matching_functions(wavelength);
+        (x + value * x_bar, y + value * y_bar, z + value * z_bar)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_construct() {
+        let s1 = Spectrum::new(400.0, 10.0, vec![0.1, 0.2, 0.4, 0.3]);
+        assert_eq!(s1.len(), 4);
+        assert_relative_eq!(s1.start(), 400.0);
+        assert_relative_eq!(s1.end(), 430.0);
+        assert_relative_eq!(s1.wavelength(2), 420.0);
+        assert_eq!(s1.values(), &[0.1, 0.2, 0.4, 0.3]);
+
+        let s2 = Spectrum::from_fn(400.0, 10.0, 4, |wavelength| wavelength / 1000.0);
+        assert_eq!(s2.values(), &[0.4, 0.41, 0.42, 0.43]);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let s1 = Spectrum::new(400.0, 10.0, vec![0.1, 0.2, 0.4, 0.3]);
+        assert_relative_eq!(s1.value_at(410.0), 0.2);
+        assert_relative_eq!(s1.value_at(415.0), 0.3);
+        assert_relative_eq!(s1.value_at(427.5), 0.325);
+        assert_relative_eq!(s1.value_at(380.0), 0.1);
+        assert_relative_eq!(s1.value_at(700.0), 0.3);
+
+        let s2 = s1.resample(405.0, 5.0, 5);
+        assert_relative_eq!(s2.start(), 405.0);
+        assert_relative_eq!(s2.values()[0], 0.15);
+        assert_relative_eq!(s2.values()[2], 0.3);
+        assert_relative_eq!(s2.values()[4], 0.35);
+    }
+
+    #[test]
+    fn test_emissive_xyz() {
+        let flat = Spectrum::new(380.0, 5.0, vec![0.01; 81]);
+        let t1 = flat.emissive_xyz::<Cie1931>();
+        assert_relative_eq!(t1, Xyz::from_channels(729.837586, 729.830844, 729.838098),
+            epsilon=1e-5);
+
+        // A coarser ramp is interpolated onto the tables, which is exact for a linear ramp.
+        let ramp = Spectrum::from_fn(400.0, 20.0, 16, |wavelength| wavelength / 70000.0);
+        let t2 = ramp.emissive_xyz::<Cie1964>();
+        assert_relative_eq!(t2, Xyz::from_channels(645.592415, 631.247513, 512.263021),
+            epsilon=1e-5);
+    }
+
+    #[test]
+    fn test_reflective_xyz() {
+        let equal_energy = Spectrum::new(300.0, 10.0, vec![1.0; 51]);
+        let white = Spectrum::new(380.0, 5.0, vec![1.0; 81]).reflective_xyz::<Cie1931>(
+            &equal_energy);
+        assert_relative_eq!(white, Xyz::from_channels(1.000009, 1.0, 1.000010), epsilon=1e-6);
+
+        // Readings that stop short of the tables are extended with their end values.
+        let gray = Spectrum::new(400.0, 10.0, vec![0.5; 31]);
+        let t1 = gray.reflective_xyz::<Cie1931>(&equal_energy);
+        assert_relative_eq!(t1, Xyz::from_channels(0.500005, 0.5, 0.500005), epsilon=1e-6);
+
+        let reading = Spectrum::new(400.0, 20.0, vec![0.05, 0.06, 0.07, 0.1, 0.2, 0.35, 0.5,
+                                                      0.6, 0.62, 0.64, 0.65, 0.66, 0.66,
+                                                      0.67, 0.67, 0.68]);
+        let t2 = reading.reflective_xyz::<Cie1964>(&equal_energy);
+        assert_relative_eq!(t2, Xyz::from_channels(0.545538, 0.551674, 0.113322), epsilon=1e-6);
+    }
+}
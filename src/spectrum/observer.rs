@@ -0,0 +1,241 @@
+//! The CIE standard colorimetric observers.
+//!
+//! Data is the CIE 1 nm tables sampled every 5 nm, from www.cie.co.at.
+
+use num::{cast, Float};
+
+/// The first wavelength of the color-matching function tables, in nm.
+pub const TABLE_START: f64 = 380.0;
+/// The spacing of the color-matching function tables, in nm.
+pub const TABLE_INTERVAL: f64 = 5.0;
+
+/// A standard observer, defined by its color-matching functions.
+pub trait StandardObserver {
+    /// The x̄, ȳ and z̄ functions, sampled every `TABLE_INTERVAL` nm from `TABLE_START`.
+    fn table() -> &'static [[f64; 3]];
+
+    /// The x̄, ȳ and z̄ functions at `wavelength` in nm.
+    ///
+    /// The table is interpolated linearly and is zero outside of its range.
+    fn color_matching_functions<T: Float>(wavelength: T) -> (T, T, T) {
+        let table = Self::table();
+        let position = (wavelength.to_f64().unwrap() - TABLE_START) / TABLE_INTERVAL;
+        if position < 0.0 || position > (table.len() - 1) as f64 {
+            return (T::zero(), T::zero(), T::zero());
+        }
+        let index = (position.floor() as usize).min(table.len() - 2);
+        let frac = position - index as f64;
+        let lerp = |i: usize| table[index][i] + (table[index + 1][i] - table[index][i]) * frac;
+        (cast(lerp(0)).unwrap(), cast(lerp(1)).unwrap(), cast(lerp(2)).unwrap())
+    }
+}
+
+/// The CIE 1931 2° standard observer, which the `white_point::deg_2` values are for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cie1931;
+impl StandardObserver for Cie1931 {
+    #[inline]
+    fn table() -> &'static [[f64; 3]] {
+        &CIE_1931
+    }
+}
+
+/// The CIE 1964 10° standard observer, which the `white_point::deg_10` values are for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cie1964;
+impl StandardObserver for Cie1964 {
+    #[inline]
+    fn table() -> &'static [[f64; 3]] {
+        &CIE_1964
+    }
+}
+
+static CIE_1931: [[f64; 3]; 81] = [
+    [0.001368, 0.000039, 0.006450001],
+    [0.002236, 0.000064, 0.01054999],
+    [0.004243, 0.00012, 0.02005001],
+    [0.00765, 0.000217, 0.03621],
+    [0.01431, 0.000396, 0.06785001],
+    [0.02319, 0.00064, 0.1102],
+    [0.04351, 0.00121, 0.2074],
+    [0.07763, 0.00218, 0.3713],
+    [0.13438, 0.004, 0.6456],
+    [0.21477, 0.0073, 1.0390501],
+    [0.2839, 0.0116, 1.3856],
+    [0.3285, 0.01684, 1.62296],
+    [0.34828, 0.023, 1.74706],
+    [0.34806, 0.0298, 1.7826],
+    [0.3362, 0.038, 1.77211],
+    [0.3187, 0.048, 1.7441],
+    [0.2908, 0.06, 1.6692],
+    [0.2511, 0.0739, 1.5281],
+    [0.19536, 0.09098, 1.28764],
+    [0.1421, 0.1126, 1.0419],
+    [0.09564, 0.13902, 0.8129501],
+    [0.05795001, 0.1693, 0.6162],
+    [0.03201, 0.20802, 0.46518],
+    [0.0147, 0.2586, 0.3533],
+    [0.0049, 0.323, 0.272],
+    [0.0024, 0.4073, 0.2123],
+    [0.0093, 0.503, 0.1582],
+    [0.0291, 0.6082, 0.1117],
+    [0.06327, 0.71, 0.07824999],
+    [0.1096, 0.7932, 0.05725001],
+    [0.1655, 0.862, 0.04216],
+    [0.2257499, 0.9148501, 0.02984],
+    [0.2904, 0.954, 0.0203],
+    [0.3597, 0.9803, 0.0134],
+    [0.4334499, 0.9949501, 0.008749999],
+    [0.5120501, 1.0, 0.005749999],
+    [0.5945, 0.995, 0.0039],
+    [0.6784, 0.9786, 0.002749999],
+    [0.7621, 0.952, 0.0021],
+    [0.8425, 0.9154, 0.0018],
+    [0.9163, 0.87, 0.001650001],
+    [0.9786, 0.8163, 0.0014],
+    [1.0263, 0.757, 0.0011],
+    [1.0567, 0.6949, 0.001],
+    [1.0622, 0.631, 0.0008],
+    [1.0456, 0.5668, 0.0006],
+    [1.0026, 0.503, 0.00034],
+    [0.9384, 0.4412, 0.00024],
+    [0.8544499, 0.381, 0.00019],
+    [0.7514, 0.321, 0.0001],
+    [0.6424, 0.265, 0.00004999999],
+    [0.5419, 0.217, 0.00003],
+    [0.4479, 0.175, 0.00002],
+    [0.3608, 0.1382, 0.00001],
+    [0.2835, 0.107, 0.0],
+    [0.2187, 0.0816, 0.0],
+    [0.1649, 0.061, 0.0],
+    [0.1212, 0.04458, 0.0],
+    [0.0874, 0.032, 0.0],
+    [0.0636, 0.0232, 0.0],
+    [0.04677, 0.017, 0.0],
+    [0.0329, 0.01192, 0.0],
+    [0.0227, 0.00821, 0.0],
+    [0.01584, 0.005723, 0.0],
+    [0.01135916, 0.004102, 0.0],
+    [0.008110916, 0.002929, 0.0],
+    [0.005790346, 0.002091, 0.0],
+    [0.004109457, 0.001484, 0.0],
+    [0.002899327, 0.001047, 0.0],
+    [0.00204919, 0.00074, 0.0],
+    [0.001439971, 0.00052, 0.0],
+    [0.0009999493, 0.0003611, 0.0],
+    [0.0006900786, 0.0002492, 0.0],
+    [0.0004760213, 0.0001719, 0.0],
+    [0.0003323011, 0.00012, 0.0],
+    [0.0002348261, 0.0000848, 0.0],
+    [0.0001661505, 0.00006, 0.0],
+    [0.000117413, 0.0000424, 0.0],
+    [0.00008307527, 0.00003, 0.0],
+    [0.00005870652, 0.0000212, 0.0],
+    [0.00004150994, 0.00001499, 0.0],
+];
+
+static CIE_1964: [[f64; 3]; 81] = [
+    [0.000159952, 0.000017364, 0.000704776],
+    [0.00066244, 0.00007156, 0.0029278],
+    [0.0023616, 0.0002534, 0.0104822],
+    [0.0072423, 0.0007685, 0.032344],
+    [0.0191097, 0.0020044, 0.0860109],
+    [0.0434, 0.004509, 0.19712],
+    [0.084736, 0.008756, 0.389366],
+    [0.140638, 0.014456, 0.65676],
+    [0.204492, 0.021391, 0.972542],
+    [0.264737, 0.029497, 1.2825],
+    [0.314679, 0.038676, 1.55348],
+    [0.357719, 0.049602, 1.7985],
+    [0.383734, 0.062077, 1.96728],
+    [0.386726, 0.074704, 2.0273],
+    [0.370702, 0.089456, 1.9948],
+    [0.342957, 0.106256, 1.9007],
+    [0.302273, 0.128201, 1.74537],
+    [0.254085, 0.152761, 1.5549],
+    [0.195618, 0.18519, 1.31756],
+    [0.132349, 0.21994, 1.0302],
+    [0.080507, 0.253589, 0.772125],
+    [0.041072, 0.297665, 0.57006],
+    [0.016172, 0.339133, 0.415254],
+    [0.005132, 0.395379, 0.302356],
+    [0.003816, 0.460777, 0.218502],
+    [0.015444, 0.53136, 0.159249],
+    [0.037465, 0.606741, 0.112044],
+    [0.071358, 0.68566, 0.082248],
+    [0.117749, 0.761757, 0.060709],
+    [0.172953, 0.82333, 0.04305],
+    [0.236491, 0.875211, 0.030451],
+    [0.304213, 0.92381, 0.020584],
+    [0.376772, 0.961988, 0.013676],
+    [0.451584, 0.9822, 0.007918],
+    [0.529826, 0.991761, 0.003988],
+    [0.616053, 0.99911, 0.001091],
+    [0.705224, 0.99734, 0.0],
+    [0.793832, 0.98238, 0.0],
+    [0.878655, 0.955552, 0.0],
+    [0.951162, 0.915175, 0.0],
+    [1.01416, 0.868934, 0.0],
+    [1.0743, 0.825623, 0.0],
+    [1.11852, 0.777405, 0.0],
+    [1.1343, 0.720353, 0.0],
+    [1.12399, 0.658341, 0.0],
+    [1.0891, 0.593878, 0.0],
+    [1.03048, 0.527963, 0.0],
+    [0.95074, 0.461834, 0.0],
+    [0.856297, 0.398057, 0.0],
+    [0.75493, 0.339554, 0.0],
+    [0.647467, 0.283493, 0.0],
+    [0.53511, 0.228254, 0.0],
+    [0.431567, 0.179828, 0.0],
+    [0.34369, 0.140211, 0.0],
+    [0.268329, 0.107633, 0.0],
+    [0.2043, 0.081187, 0.0],
+    [0.152568, 0.060281, 0.0],
+    [0.11221, 0.044096, 0.0],
+    [0.0812606, 0.0318004, 0.0],
+    [0.05793, 0.0226017, 0.0],
+    [0.0408508, 0.0159051, 0.0],
+    [0.028623, 0.0111303, 0.0],
+    [0.0199413, 0.0077488, 0.0],
+    [0.013842, 0.0053751, 0.0],
+    [0.00957688, 0.00371774, 0.0],
+    [0.0066052, 0.00256456, 0.0],
+    [0.00455263, 0.00176847, 0.0],
+    [0.0031447, 0.00122239, 0.0],
+    [0.00217496, 0.00084619, 0.0],
+    [0.0015057, 0.00058644, 0.0],
+    [0.00104476, 0.00040741, 0.0],
+    [0.00072745, 0.000284041, 0.0],
+    [0.000508258, 0.00019873, 0.0],
+    [0.00035638, 0.00013955, 0.0],
+    [0.000250969, 0.000098428, 0.0],
+    [0.00017773, 0.000069819, 0.0],
+    [0.00012639, 0.000049737, 0.0],
+    [0.000090151, 0.0000355405, 0.0],
+    [0.0000645258, 0.000025486, 0.0],
+    [0.000046339, 0.0000183384, 0.0],
+    [0.0000334117, 0.000013249, 0.0],
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_color_matching_functions() {
+        let (x, y, z) = Cie1931::color_matching_functions(555.0);
+        assert_relative_eq!(x, 0.5120501);
+        assert_relative_eq!(y, 1.0);
+        assert_relative_eq!(z, 0.005749999);
+
+        let (x, y, z) = Cie1964::color_matching_functions(557.5f32);
+        assert_relative_eq!(x, 0.6606385, epsilon=1e-6);
+        assert_relative_eq!(y, 0.998225, epsilon=1e-6);
+        assert_relative_eq!(z, 0.0005455, epsilon=1e-6);
+
+        assert_eq!(Cie1931::color_matching_functions(780.0).1, 0.00001499);
+        assert_eq!(Cie1931::color_matching_functions(379.0), (0.0, 0.0, 0.0));
+        assert_eq!(Cie1964::color_matching_functions(781.0), (0.0, 0.0, 0.0));
+    }
+}
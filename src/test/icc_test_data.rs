@@ -0,0 +1,333 @@
+use chromatic_adaptation::BradfordAdaptation;
+use color_space::color_space::ColorSpace;
+use color_space::presets::{NamedColorSpace, sRgb};
+use encoding::{ChannelDecoder, SrgbEncoding};
+use linalg::Matrix3;
+use white_point::{NamedWhitePoint, D50, D65};
+
+const D50_XYZ: [f64; 3] = [0.9642, 1.0, 0.8249];
+
+fn s15_fixed16(val: f64) -> [u8; 4] {
+    be32((val * 65536.0).round() as i32 as u32)
+}
+
+fn be32(val: u32) -> [u8; 4] {
+    [(val >> 24) as u8, (val >> 16) as u8, (val >> 8) as u8, val as u8]
+}
+
+fn be16(val: u16) -> [u8; 2] {
+    [(val >> 8) as u8, val as u8]
+}
+
+fn pad4(data: &mut Vec<u8>) {
+    let len = (data.len() + 3) & !3;
+    data.resize(len, 0);
+}
+
+fn quantize(val: f64) -> f64 {
+    (val * 65536.0).round() / 65536.0
+}
+
+pub fn xyz_tag(xyz: [f64; 3]) -> Vec<u8> {
+    let mut data = b"XYZ \0\0\0\0".to_vec();
+    for &val in xyz.iter() {
+        data.extend_from_slice(&s15_fixed16(val));
+    }
+    data
+}
+
+pub fn sf32_tag(values: &[f64]) -> Vec<u8> {
+    let mut data = b"sf32\0\0\0\0".to_vec();
+    for &val in values {
+        data.extend_from_slice(&s15_fixed16(val));
+    }
+    data
+}
+
+pub fn para_tag(function_type: u16, params: &[f64]) -> Vec<u8> {
+    let mut data = b"para\0\0\0\0".to_vec();
+    data.extend_from_slice(&be16(function_type));
+    data.extend_from_slice(&[0, 0]);
+    for &val in params {
+        data.extend_from_slice(&s15_fixed16(val));
+    }
+    data
+}
+
+pub fn curv_tag(entries: &[u16]) -> Vec<u8> {
+    let mut data = b"curv\0\0\0\0".to_vec();
+    data.extend_from_slice(&be32(entries.len() as u32));
+    for &entry in entries {
+        data.extend_from_slice(&be16(entry));
+    }
+    data
+}
+
+pub fn text_tag(text: &str) -> Vec<u8> {
+    let mut data = b"text\0\0\0\0".to_vec();
+    data.extend_from_slice(text.as_bytes());
+    data.push(0);
+    data
+}
+
+pub fn desc_tag(text: &str) -> Vec<u8> {
+    let mut data = b"desc\0\0\0\0".to_vec();
+    data.extend_from_slice(&be32(text.len() as u32 + 1));
+    data.extend_from_slice(text.as_bytes());
+    data.push(0);
+    data.extend_from_slice(&[0; 12]);
+    data.extend_from_slice(&[0; 67]);
+    data
+}
+
+pub fn mluc_tag(text: &str) -> Vec<u8> {
+    let mut data = b"mluc\0\0\0\0".to_vec();
+    data.extend_from_slice(&be32(2));
+    data.extend_from_slice(&be32(12));
+
+    let units: Vec<u16> = text.encode_utf16().collect();
+    let german: Vec<u16> = "Testprofil".encode_utf16().collect();
+    let first_offset = 16 + 2 * 12;
+    let second_offset = first_offset + 2 * german.len();
+
+    data.extend_from_slice(b"deDE");
+    data.extend_from_slice(&be32(2 * german.len() as u32));
+    data.extend_from_slice(&be32(first_offset as u32));
+    data.extend_from_slice(b"enUS");
+    data.extend_from_slice(&be32(2 * units.len() as u32));
+    data.extend_from_slice(&be32(second_offset as u32));
+    for &unit in german.iter().chain(units.iter()) {
+        data.extend_from_slice(&be16(unit));
+    }
+    data
+}
+
+/// The elements of an `mAB ` or `mBA ` tag, each curve given as its encoded bytes.
+pub struct LutElements {
+    pub b_curves: Vec<Vec<u8>>,
+    pub matrix: Option<[f64; 12]>,
+    pub m_curves: Option<Vec<Vec<u8>>>,
+    pub clut: Option<(Vec<u8>, u8, Vec<f64>)>,
+    pub a_curves: Option<Vec<Vec<u8>>>,
+}
+
+pub fn lut_tag(type_sig: &[u8; 4], inputs: u8, outputs: u8, elements: LutElements) -> Vec<u8> {
+    let mut data = type_sig.to_vec();
+    data.extend_from_slice(&[0, 0, 0, 0, inputs, outputs, 0, 0]);
+    data.extend_from_slice(&[0; 20]);
+
+    fn push_curves(data: &mut Vec<u8>, curves: &[Vec<u8>]) -> u32 {
+        let offset = data.len() as u32;
+        for curve in curves {
+            data.extend_from_slice(curve);
+            pad4(data);
+        }
+        offset
+    }
+
+    let mut offsets = [0u32; 5];
+    offsets[0] = push_curves(&mut data, &elements.b_curves);
+    if let Some(matrix) = elements.matrix {
+        offsets[1] = data.len() as u32;
+        for &val in matrix.iter() {
+            data.extend_from_slice(&s15_fixed16(val));
+        }
+    }
+    if let Some(ref curves) = elements.m_curves {
+        offsets[2] = push_curves(&mut data, curves);
+    }
+    if let Some((ref grid, precision, ref values)) = elements.clut {
+        offsets[3] = data.len() as u32;
+        let mut points = [0u8; 16];
+        points[..grid.len()].copy_from_slice(grid);
+        data.extend_from_slice(&points);
+        data.extend_from_slice(&[precision, 0, 0, 0]);
+        for &val in values {
+            if precision == 1 {
+                data.push((val * 255.0).round() as u8);
+            } else {
+                data.extend_from_slice(&be16((val * 65535.0).round() as u16));
+            }
+        }
+        pad4(&mut data);
+    }
+    if let Some(ref curves) = elements.a_curves {
+        offsets[4] = push_curves(&mut data, curves);
+    }
+
+    for (i, offset) in offsets.iter().enumerate() {
+        data[12 + 4 * i..16 + 4 * i].copy_from_slice(&be32(*offset));
+    }
+    data
+}
+
+/// Assemble a profile from its header fields and tags.
+pub fn build_profile(version: [u8; 2],
+                     class: &[u8; 4],
+                     color_space: &[u8; 4],
+                     pcs: &[u8; 4],
+                     tags: Vec<(&[u8; 4], Vec<u8>)>)
+                     -> Vec<u8> {
+    let mut data = vec![0u8; 128];
+    data[8] = version[0];
+    data[9] = version[1];
+    data[12..16].copy_from_slice(class);
+    data[16..20].copy_from_slice(color_space);
+    data[20..24].copy_from_slice(pcs);
+    for (i, &field) in [2024u16, 5, 17, 12, 30, 0].iter().enumerate() {
+        data[24 + 2 * i..26 + 2 * i].copy_from_slice(&be16(field));
+    }
+    data[36..40].copy_from_slice(b"acsp");
+    data[40..44].copy_from_slice(b"APPL");
+    for (i, &val) in D50_XYZ.iter().enumerate() {
+        data[68 + 4 * i..72 + 4 * i].copy_from_slice(&s15_fixed16(val));
+    }
+    data[80..84].copy_from_slice(b"test");
+
+    data.extend_from_slice(&be32(tags.len() as u32));
+    let table_start = data.len();
+    data.extend(vec![0u8; 12 * tags.len()]);
+
+    for (i, (sig, tag)) in tags.into_iter().enumerate() {
+        pad4(&mut data);
+        let entry = table_start + 12 * i;
+        let offset = data.len() as u32;
+        data[entry..entry + 4].copy_from_slice(sig);
+        data[entry + 4..entry + 8].copy_from_slice(&be32(offset));
+        data[entry + 8..entry + 12].copy_from_slice(&be32(tag.len() as u32));
+        data.extend(tag);
+    }
+
+    let size = data.len() as u32;
+    data[0..4].copy_from_slice(&be32(size));
+    data
+}
+
+fn d50_srgb_colorants() -> (Matrix3<f64>, Matrix3<f64>) {
+    let chad = *BradfordAdaptation::<f64>::from_named::<D65, D50>().transform();
+    let colorants = chad * *sRgb::get_color_space().get_xyz_transform();
+    (chad, colorants)
+}
+
+fn colorant_tags(colorants: &Matrix3<f64>) -> Vec<(&'static [u8; 4], Vec<u8>)> {
+    let m = colorants.as_slice();
+    vec![(b"rXYZ", xyz_tag([m[0], m[3], m[6]])),
+         (b"gXYZ", xyz_tag([m[1], m[4], m[7]])),
+         (b"bXYZ", xyz_tag([m[2], m[5], m[8]]))]
+}
+
+/// The sRGB curve parameters as they are stored in the test profile.
+pub fn profile_srgb_params() -> Vec<f64> {
+    [2.4, 1.0 / 1.055, 0.055 / 1.055, 1.0 / 12.92, 0.04045].iter().map(|&v| quantize(v)).collect()
+}
+
+/// A version 4 matrix/TRC sRGB profile with a `chad` tag.
+pub fn build_srgb_profile() -> Vec<u8> {
+    let (chad, colorants) = d50_srgb_colorants();
+    let trc = para_tag(3, &profile_srgb_params());
+    let wp = D50::get_xyz();
+
+    let mut tags = vec![(b"desc", mluc_tag("sRGB test profile")),
+                        (b"cprt", text_tag("No copyright")),
+                        (b"wtpt", xyz_tag([wp.x(), wp.y(), wp.z()])),
+                        (b"chad", sf32_tag(chad.as_slice()))];
+    tags.extend(colorant_tags(&colorants));
+    tags.push((b"rTRC", trc.clone()));
+    tags.push((b"gTRC", trc.clone()));
+    tags.push((b"bTRC", trc));
+
+    build_profile([4, 0x30], b"mntr", b"RGB ", b"XYZ ", tags)
+}
+
+/// A version 2 matrix/TRC profile with sRGB colorants and a 2.2 gamma.
+pub fn build_v2_gamma_profile() -> Vec<u8> {
+    let (_, colorants) = d50_srgb_colorants();
+    let trc = curv_tag(&[563]);
+
+    let mut tags = vec![(b"desc", desc_tag("Gamma 2.2 test profile")),
+                        (b"wtpt", xyz_tag(D50_XYZ))];
+    tags.extend(colorant_tags(&colorants));
+    tags.push((b"rTRC", trc.clone()));
+    tags.push((b"gTRC", trc.clone()));
+    tags.push((b"bTRC", trc));
+
+    build_profile([2, 0x10], b"mntr", b"RGB ", b"XYZ ", tags)
+}
+
+/// A version 2 sRGB profile in the style of sRGB IEC61966-2.1, which gives its D65 white point
+/// in `wtpt` but has no `chad` tag for the adaptation of its colorants to D50.
+pub fn build_v2_srgb_profile() -> Vec<u8> {
+    let (_, colorants) = d50_srgb_colorants();
+    let entries: Vec<u16> = (0..1024)
+        .map(|i| SrgbEncoding::new().decode_channel(i as f64 / 1023.0))
+        .map(|v| (v * 65535.0).round() as u16)
+        .collect();
+    let trc = curv_tag(&entries);
+    let wp = D65::get_xyz();
+
+    let mut tags = vec![(b"desc", desc_tag("sRGB IEC61966-2.1")),
+                        (b"wtpt", xyz_tag([wp.x(), wp.y(), wp.z()]))];
+    tags.extend(colorant_tags(&colorants));
+    tags.push((b"rTRC", trc.clone()));
+    tags.push((b"gTRC", trc.clone()));
+    tags.push((b"bTRC", trc));
+
+    build_profile([2, 0x10], b"mntr", b"RGB ", b"XYZ ", tags)
+}
+
+/// A gray profile whose tone curve is the sRGB curve sampled into a table.
+pub fn build_gray_profile() -> Vec<u8> {
+    let entries: Vec<u16> = (0..256)
+        .map(|i| SrgbEncoding::new().decode_channel(i as f64 / 255.0))
+        .map(|v| (v * 65535.0).round() as u16)
+        .collect();
+    let tags = vec![(b"desc", mluc_tag("Gray test profile")),
+                    (b"wtpt", xyz_tag(D50_XYZ)),
+                    (b"kTRC", curv_tag(&entries))];
+
+    build_profile([4, 0x30], b"mntr", b"GRAY", b"XYZ ", tags)
+}
+
+/// An input profile with `mAB `/`mBA ` tables and an unknown tag.
+///
+/// The A-to-B table swaps the channels in its CLUT, squares them with its M curves, then
+/// halves them and adds 0.1 with its matrix. The B-to-A table takes the square root of each
+/// channel, then averages them in a 3 to 1 CLUT.
+pub fn build_lut_profile() -> Vec<u8> {
+    let identity = curv_tag(&[]);
+    let square = para_tag(0, &[2.0]);
+    let swap: Vec<f64> = (0..8)
+        .flat_map(|i| vec![(i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64])
+        .collect();
+    let average: Vec<f64> = (0..8)
+        .map(|i| ((i & 1) + ((i >> 1) & 1) + (i >> 2)) as f64 / 3.0)
+        .collect();
+
+    let a_to_b = lut_tag(b"mAB ",
+                         3,
+                         3,
+                         LutElements {
+                             b_curves: vec![identity.clone(); 3],
+                             matrix: Some([0.5, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.5, 0.1, 0.1,
+                                           0.1]),
+                             m_curves: Some(vec![square; 3]),
+                             clut: Some((vec![2, 2, 2], 2, swap)),
+                             a_curves: Some(vec![identity.clone(); 3]),
+                         });
+    let b_to_a = lut_tag(b"mBA ",
+                         3,
+                         1,
+                         LutElements {
+                             b_curves: vec![curv_tag(&[0x0080]); 3],
+                             matrix: None,
+                             m_curves: None,
+                             clut: Some((vec![2, 2, 2], 1, average)),
+                             a_curves: Some(vec![identity]),
+                         });
+    let tags = vec![(b"desc", text_tag("LUT test profile")),
+                    (b"A2B0", a_to_b),
+                    (b"B2A0", b_to_a),
+                    (b"zzzz", b"zzzz\0\0\0\0abcd".to_vec())];
+
+    build_profile([4, 0x20], b"scnr", b"RGB ", b"Lab ", tags)
+}
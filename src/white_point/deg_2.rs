@@ -409,3 +409,43 @@ impl<T> NamedWhitePoint<T> for F12
                            cast(1.0).unwrap())
     }
 }
+
+/// DCI-P3 theatrical projector white (SMPTE RP 431-2).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dci;
+impl<T> NamedWhitePoint<T> for Dci
+    where T: Float + FreeChannelScalar + PosNormalChannelScalar
+{
+    #[inline]
+    fn get_xyz() -> Xyz<T> {
+        Xyz::from_channels(cast(0.894587).unwrap(),
+                           cast(1.000000).unwrap(),
+                           cast(0.954416).unwrap())
+    }
+    #[inline]
+    fn get_xy_chromaticity() -> XyY<T> {
+        XyY::from_channels(cast(0.31400).unwrap(),
+                           cast(0.35100).unwrap(),
+                           cast(1.0).unwrap())
+    }
+}
+
+/// ACES white, close to D60 (SMPTE ST 2065-1).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Aces;
+impl<T> NamedWhitePoint<T> for Aces
+    where T: Float + FreeChannelScalar + PosNormalChannelScalar
+{
+    #[inline]
+    fn get_xyz() -> Xyz<T> {
+        Xyz::from_channels(cast(0.952646).unwrap(),
+                           cast(1.000000).unwrap(),
+                           cast(1.008825).unwrap())
+    }
+    #[inline]
+    fn get_xy_chromaticity() -> XyY<T> {
+        XyY::from_channels(cast(0.32168).unwrap(),
+                           cast(0.33767).unwrap(),
+                           cast(1.0).unwrap())
+    }
+}
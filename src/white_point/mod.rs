@@ -1,4 +1,5 @@
-// All data taken from www.brucelindbloom.com.
+// All data taken from www.brucelindbloom.com. The spectra these values are derived from are in
+// `spectrum::illuminant`.
 
 use xyz::Xyz;
 use xyy::XyY;